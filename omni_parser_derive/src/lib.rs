@@ -0,0 +1,368 @@
+use proc_macro::TokenStream;
+use proc_macro2::{ Span, TokenStream as TokenStream2 };
+use quote::{ format_ident, quote };
+use syn::{ ext::IdentExt, parse_macro_input, parse_quote, Attribute, Data, DeriveInput, ExprPath, Field, Fields, Generics, Ident, LitStr, Path, Type };
+
+
+
+const RENAME_CASES:[&str; 7] = ["lowercase", "UPPERCASE", "snake_case", "SCREAMING_SNAKE_CASE", "kebab-case", "camelCase", "PascalCase"];
+
+
+
+/// Derive ToJson. Structs become dicts, tuple structs arrays and enums are externally tagged, like `{"Variant": value}`, with unit variants as plain strings.
+#[proc_macro_derive(ToJson, attributes(json))]
+pub fn derive_to_json(input:TokenStream) -> TokenStream {
+	let input:DeriveInput = parse_macro_input!(input as DeriveInput);
+	to_json(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derive FromJson, reading the layout written by ToJson. Errors report the JSON Pointer of the offending field.
+#[proc_macro_derive(FromJson, attributes(json))]
+pub fn derive_from_json(input:TokenStream) -> TokenStream {
+	let input:DeriveInput = parse_macro_input!(input as DeriveInput);
+	from_json(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+
+
+/* ATTRIBUTES */
+
+/// Options of a struct or enum, from `#[json(rename_all = "camelCase")]`.
+#[derive(Default)]
+struct ContainerOptions {
+	rename_all:Option<String>
+}
+impl ContainerOptions {
+
+	/// Parse the options from attributes.
+	fn new(attributes:&[Attribute]) -> syn::Result<ContainerOptions> {
+		let mut options:ContainerOptions = ContainerOptions::default();
+		for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("json")) {
+			attribute.parse_nested_meta(|meta| {
+				if meta.path.is_ident("rename_all") {
+					let case:LitStr = meta.value()?.parse()?;
+					if !RENAME_CASES.contains(&case.value().as_str()) {
+						return Err(syn::Error::new(case.span(), format!("unknown case, expected one of {}", RENAME_CASES.join(", "))));
+					}
+					options.rename_all = Some(case.value());
+					Ok(())
+				} else {
+					Err(meta.error("unknown json attribute, expected 'rename_all'"))
+				}
+			})?;
+		}
+		Ok(options)
+	}
+
+	/// Get the json name of a field or variant.
+	fn name(&self, ident:&Ident, rename:&Option<String>) -> String {
+		match (rename, &self.rename_all) {
+			(Some(rename), _) => rename.clone(),
+			(None, Some(case)) => rename_case(&ident.unraw().to_string(), case),
+			(None, None) => ident.unraw().to_string()
+		}
+	}
+}
+
+/// Options of a field or variant, from `#[json(rename = "name", default, flatten, skip)]`.
+#[derive(Default)]
+struct FieldOptions {
+	rename:Option<String>,
+	default:Option<Option<ExprPath>>,
+	flatten:bool,
+	skip:bool
+}
+impl FieldOptions {
+
+	/// Parse the options from attributes.
+	fn new(attributes:&[Attribute]) -> syn::Result<FieldOptions> {
+		let mut options:FieldOptions = FieldOptions::default();
+		for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("json")) {
+			attribute.parse_nested_meta(|meta| {
+				if meta.path.is_ident("rename") {
+					options.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+				} else if meta.path.is_ident("default") {
+					options.default = Some(match meta.input.peek(syn::Token![=]) {
+						true => Some(meta.value()?.parse::<LitStr>()?.parse::<ExprPath>()?),
+						false => None
+					});
+				} else if meta.path.is_ident("flatten") {
+					options.flatten = true;
+				} else if meta.path.is_ident("skip") {
+					options.skip = true;
+				} else {
+					return Err(meta.error("unknown json attribute, expected 'rename', 'default', 'flatten' or 'skip'"));
+				}
+				Ok(())
+			})?;
+		}
+		Ok(options)
+	}
+
+	/// Get the expression for the default value of the field, if it has one.
+	fn default_value(&self) -> Option<TokenStream2> {
+		match &self.default {
+			Some(Some(function)) => Some(quote! { #function() }),
+			Some(None) => Some(quote! { ::core::default::Default::default() }),
+			None => None
+		}
+	}
+}
+
+
+
+/* TO JSON */
+
+/// Create the ToJson implementation.
+fn to_json(input:&DeriveInput) -> syn::Result<TokenStream2> {
+	let options:ContainerOptions = ContainerOptions::new(&input.attrs)?;
+	let body:TokenStream2 = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(_) => {
+				let fields:Vec<(&Field, FieldOptions)> = field_options(&data.fields)?;
+				let accessors:Vec<TokenStream2> = fields.iter().map(|(field, _)| { let ident = &field.ident; quote! { &self.#ident } }).collect();
+				named_to_json(&fields, &accessors, &options)
+			},
+			Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! { ::omni_parser::ToJson::to_json(&self.0) },
+			Fields::Unnamed(fields) => {
+				let indexes = (0..fields.unnamed.len()).map(syn::Index::from);
+				quote! { ::omni_parser::Json::Array(vec![#(::omni_parser::ToJson::to_json(&self.#indexes)),*]) }
+			},
+			Fields::Unit => quote! { ::omni_parser::Json::Null }
+		},
+		Data::Enum(data) => {
+			let mut arms:Vec<TokenStream2> = Vec::new();
+			for variant in &data.variants {
+				let variant_ident:&Ident = &variant.ident;
+				let name:String = options.name(variant_ident, &FieldOptions::new(&variant.attrs)?.rename);
+				arms.push(match &variant.fields {
+					Fields::Unit => quote! { Self::#variant_ident => ::omni_parser::Json::String(#name.to_string()) },
+					Fields::Unnamed(fields) => {
+						let bindings:Vec<Ident> = (0..fields.unnamed.len()).map(|index| format_ident!("field_{}", index)).collect();
+						let value:TokenStream2 = match bindings.len() {
+							1 => quote! { ::omni_parser::ToJson::to_json(field_0) },
+							_ => quote! { ::omni_parser::Json::Array(vec![#(::omni_parser::ToJson::to_json(#bindings)),*]) }
+						};
+						quote! { Self::#variant_ident(#(#bindings),*) => ::omni_parser::Json::Dict(vec![(::omni_parser::Json::String(#name.to_string()), #value)]) }
+					},
+					Fields::Named(_) => {
+						let fields:Vec<(&Field, FieldOptions)> = field_options(&variant.fields)?;
+						let accessors:Vec<TokenStream2> = fields.iter().map(|(field, _)| { let ident = &field.ident; quote! { #ident } }).collect();
+						let idents:Vec<&Option<Ident>> = fields.iter().filter(|(_, field_options)| !field_options.skip).map(|(field, _)| &field.ident).collect();
+						let value:TokenStream2 = named_to_json(&fields, &accessors, &ContainerOptions::default());
+						quote! { Self::#variant_ident { #(#idents,)* .. } => ::omni_parser::Json::Dict(vec![(::omni_parser::Json::String(#name.to_string()), #value)]) }
+					}
+				});
+			}
+			quote! {
+				match self {
+					#(#arms,)*
+				}
+			}
+		},
+		Data::Union(data) => return Err(syn::Error::new(data.union_token.span, "ToJson can not be derived for unions"))
+	};
+	let ident:&Ident = &input.ident;
+	let generics:Generics = bounded_generics(&input.generics, parse_quote!(::omni_parser::ToJson));
+	let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+	Ok(quote! {
+		impl #impl_generics ::omni_parser::ToJson for #ident #type_generics #where_clause {
+			fn to_json(&self) -> ::omni_parser::Json {
+				#body
+			}
+		}
+	})
+}
+
+/// Create the dict of named fields, using the given expressions to access the fields.
+fn named_to_json(fields:&[(&Field, FieldOptions)], accessors:&[TokenStream2], options:&ContainerOptions) -> TokenStream2 {
+	let entries = fields.iter().zip(accessors).filter(|((_, field_options), _)| !field_options.skip).map(|((field, field_options), accessor)| {
+		let name:String = options.name(field.ident.as_ref().unwrap(), &field_options.rename);
+		match field_options.flatten {
+			true => quote! {
+				if let ::omni_parser::Json::Dict(flattened) = ::omni_parser::ToJson::to_json(#accessor) {
+					entries.extend(flattened);
+				}
+			},
+			false => quote! {
+				entries.push((::omni_parser::Json::String(#name.to_string()), ::omni_parser::ToJson::to_json(#accessor)));
+			}
+		}
+	});
+	quote! {{
+		let mut entries:Vec<(::omni_parser::Json, ::omni_parser::Json)> = Vec::new();
+		#(#entries)*
+		::omni_parser::Json::Dict(entries)
+	}}
+}
+
+
+
+/* FROM JSON */
+
+/// Create the FromJson implementation.
+fn from_json(input:&DeriveInput) -> syn::Result<TokenStream2> {
+	let options:ContainerOptions = ContainerOptions::new(&input.attrs)?;
+	let json:Ident = Ident::new("json", Span::call_site());
+	let body:TokenStream2 = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(_) => {
+				let value:TokenStream2 = named_from_json(quote! { Self }, &field_options(&data.fields)?, &json, &options);
+				quote! { Ok(#value) }
+			},
+			Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! { Ok(Self(::omni_parser::FromJson::from_json(json)?)) },
+			Fields::Unnamed(fields) => {
+				let value:TokenStream2 = unnamed_from_json(quote! { Self }, fields.unnamed.len(), &json);
+				quote! { Ok(#value) }
+			},
+			Fields::Unit => quote! {
+				match json {
+					::omni_parser::Json::Null => Ok(Self),
+					json => Err(::omni_parser::FromJsonError::expected("null", json))
+				}
+			}
+		},
+		Data::Enum(data) => {
+			let mut unit_arms:Vec<TokenStream2> = Vec::new();
+			let mut tagged_arms:Vec<TokenStream2> = Vec::new();
+			let value:Ident = Ident::new("value", Span::call_site());
+			for variant in &data.variants {
+				let variant_ident:&Ident = &variant.ident;
+				let name:String = options.name(variant_ident, &FieldOptions::new(&variant.attrs)?.rename);
+				match &variant.fields {
+					Fields::Unit => unit_arms.push(quote! { #name => Ok(Self::#variant_ident) }),
+					Fields::Unnamed(fields) if fields.unnamed.len() == 1 => tagged_arms.push(quote! {
+						#name => Ok(Self::#variant_ident(::omni_parser::FromJson::from_json(value).map_err(|error| error.at(#name))?))
+					}),
+					Fields::Unnamed(fields) => {
+						let variant_value:TokenStream2 = unnamed_from_json(quote! { Self::#variant_ident }, fields.unnamed.len(), &value);
+						tagged_arms.push(quote! { #name => (|| -> Result<Self, ::omni_parser::FromJsonError> { Ok(#variant_value) })().map_err(|error| error.at(#name)) });
+					},
+					Fields::Named(_) => {
+						let variant_value:TokenStream2 = named_from_json(quote! { Self::#variant_ident }, &field_options(&variant.fields)?, &value, &ContainerOptions::default());
+						tagged_arms.push(quote! { #name => (|| -> Result<Self, ::omni_parser::FromJsonError> { Ok(#variant_value) })().map_err(|error| error.at(#name)) });
+					}
+				}
+			}
+			let tagged_branch:TokenStream2 = match tagged_arms.is_empty() {
+				true => TokenStream2::new(),
+				false => quote! {
+					::omni_parser::Json::Dict(entries) if entries.len() == 1 && entries[0].0.as_str().is_some() => {
+						let (name, value) = (entries[0].0.as_str().unwrap(), &entries[0].1);
+						match name {
+							#(#tagged_arms,)*
+							name => Err(::omni_parser::FromJsonError::new(&format!("unknown variant '{name}'")))
+						}
+					},
+				}
+			};
+			quote! {
+				match json {
+					::omni_parser::Json::String(name) => match name.as_str() {
+						#(#unit_arms,)*
+						name => Err(::omni_parser::FromJsonError::new(&format!("unknown variant '{name}'")))
+					},
+					#tagged_branch
+					json => Err(::omni_parser::FromJsonError::expected("a variant name or a dict with one variant", json))
+				}
+			}
+		},
+		Data::Union(data) => return Err(syn::Error::new(data.union_token.span, "FromJson can not be derived for unions"))
+	};
+	let ident:&Ident = &input.ident;
+	let generics:Generics = bounded_generics(&input.generics, parse_quote!(::omni_parser::FromJson));
+	let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+	Ok(quote! {
+		impl #impl_generics ::omni_parser::FromJson for #ident #type_generics #where_clause {
+			fn from_json(json:&::omni_parser::Json) -> Result<Self, ::omni_parser::FromJsonError> {
+				#body
+			}
+		}
+	})
+}
+
+/// Create the value of a struct or variant with named fields, read from the dict in the given variable.
+fn named_from_json(constructor:TokenStream2, fields:&[(&Field, FieldOptions)], json:&Ident, options:&ContainerOptions) -> TokenStream2 {
+	let initializers = fields.iter().map(|(field, field_options)| {
+		let ident:&Ident = field.ident.as_ref().unwrap();
+		let field_type:&Type = &field.ty;
+		let name:String = options.name(ident, &field_options.rename);
+		if field_options.skip {
+			let default:TokenStream2 = field_options.default_value().unwrap_or(quote! { ::core::default::Default::default() });
+			return quote! { #ident: #default };
+		}
+		if field_options.flatten {
+			return quote! { #ident: ::omni_parser::FromJson::from_json(#json)? };
+		}
+		let missing:TokenStream2 = field_options.default_value().unwrap_or(quote! {
+			<#field_type as ::omni_parser::FromJson>::from_missing().ok_or_else(|| ::omni_parser::FromJsonError::missing_field(#name))?
+		});
+		quote! {
+			#ident: match ::omni_parser::Json::get(#json, #name) {
+				Some(value) => ::omni_parser::FromJson::from_json(value).map_err(|error| error.at(#name))?,
+				None => #missing
+			}
+		}
+	});
+	quote! {{
+		if #json.as_object().is_none() {
+			return Err(::omni_parser::FromJsonError::expected("dict", #json));
+		}
+		#constructor { #(#initializers),* }
+	}}
+}
+
+/// Create the value of a tuple struct or variant, read from the array in the given variable.
+fn unnamed_from_json(constructor:TokenStream2, length:usize, json:&Ident) -> TokenStream2 {
+	let expected:String = format!("an array of {length} items");
+	let indexes = 0..length;
+	quote! {{
+		let items:&Vec<::omni_parser::Json> = #json.as_array().filter(|items| items.len() == #length).ok_or_else(|| ::omni_parser::FromJsonError::expected(#expected, #json))?;
+		#constructor(#(::omni_parser::FromJson::from_json(&items[#indexes]).map_err(|error| error.at(#indexes))?),*)
+	}}
+}
+
+
+
+/* HELPER FUNCTIONS */
+
+/// Get the fields with their parsed options.
+fn field_options(fields:&Fields) -> syn::Result<Vec<(&Field, FieldOptions)>> {
+	fields.iter().map(|field| Ok((field, FieldOptions::new(&field.attrs)?))).collect()
+}
+
+/// Add a trait bound to all type parameters.
+fn bounded_generics(generics:&Generics, bound:Path) -> Generics {
+	let mut generics:Generics = generics.clone();
+	for parameter in generics.type_params_mut() {
+		parameter.bounds.push(parse_quote!(#bound));
+	}
+	generics
+}
+
+/// Rename a snake_case or PascalCase name to another case.
+fn rename_case(name:&str, case:&str) -> String {
+	let mut words:Vec<String> = Vec::new();
+	for char in name.chars() {
+		if char == '_' || words.is_empty() || (char.is_uppercase() && !words.last().unwrap().is_empty()) {
+			words.push(String::new());
+		}
+		if char != '_' {
+			words.last_mut().unwrap().extend(char.to_lowercase());
+		}
+	}
+	words.retain(|word| !word.is_empty());
+	let capitalize = |word:&String| {
+		let mut chars = word.chars();
+		chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+	};
+	match case {
+		"lowercase" => words.concat(),
+		"UPPERCASE" => words.concat().to_uppercase(),
+		"SCREAMING_SNAKE_CASE" => words.join("_").to_uppercase(),
+		"kebab-case" => words.join("-"),
+		"camelCase" => words.iter().enumerate().map(|(index, word)| if index == 0 { word.clone() } else { capitalize(word) }).collect(),
+		"PascalCase" => words.iter().map(capitalize).collect(),
+		_ => words.join("_")
+	}
+}
@@ -0,0 +1,122 @@
+use omni_parser::{ json, FromJson, FromJsonError, Json, ToJson };
+use std::collections::BTreeMap;
+
+
+
+/* HELPER TYPES */
+
+#[derive(ToJson, FromJson, PartialEq, Debug)]
+#[json(rename_all = "camelCase")]
+struct ServiceConfig {
+	service_name:String,
+	#[json(rename = "listen_port")]
+	port:u16,
+	#[json(default)]
+	replicas:u32,
+	#[json(default = "default_hosts")]
+	hosts:Vec<String>,
+	tls:Option<Tls>,
+	#[json(flatten)]
+	limits:Limits,
+	#[json(skip)]
+	cache:Vec<u8>,
+	mode:Mode
+}
+
+#[derive(ToJson, FromJson, PartialEq, Debug)]
+struct Tls {
+	cert:String
+}
+
+#[derive(ToJson, FromJson, PartialEq, Default, Debug)]
+struct Limits {
+	cpu:f64,
+	#[json(default)]
+	labels:BTreeMap<String, String>
+}
+
+#[derive(ToJson, FromJson, PartialEq, Debug)]
+enum Mode {
+	#[json(rename = "dev")]
+	Development,
+	Production { region:String },
+	Canary(u8),
+	Pair(String, bool)
+}
+
+#[derive(ToJson, FromJson, PartialEq, Debug)]
+struct Wrapper<T>(T);
+
+#[derive(ToJson, FromJson, PartialEq, Debug)]
+struct Point(i32, i32);
+
+#[derive(ToJson, FromJson, PartialEq, Debug)]
+#[json(rename_all = "PascalCase")]
+struct Floor {
+	étage_number:u8
+}
+
+fn default_hosts() -> Vec<String> {
+	vec!["localhost".to_string()]
+}
+
+fn from_json<T:FromJson>(json:Json) -> Result<T, FromJsonError> {
+	T::from_json(&json)
+}
+
+
+
+/* TESTS */
+
+#[test]
+fn test_round_trip() {
+	let config:ServiceConfig = ServiceConfig {
+		service_name: "api".to_string(),
+		port: 8080,
+		replicas: 2,
+		hosts: vec!["a".to_string()],
+		tls: Some(Tls { cert: "x.pem".to_string() }),
+		limits: Limits { cpu: 0.5, labels: BTreeMap::new() },
+		cache: vec![1, 2],
+		mode: Mode::Production { region: "eu".to_string() }
+	};
+	let json:Json = config.to_json();
+	assert_eq!(json, json!({
+		"serviceName": "api",
+		"listen_port": 8080,
+		"replicas": 2,
+		"hosts": ["a"],
+		"tls": { "cert": "x.pem" },
+		"cpu": 0.5,
+		"labels": {},
+		"mode": { "Production": { "region": "eu" } }
+	}));
+	assert_eq!(from_json::<ServiceConfig>(json).unwrap(), ServiceConfig { cache: Vec::new(), ..config });
+}
+
+#[test]
+fn test_defaults_and_options() {
+	let config:ServiceConfig = from_json(json!({ "serviceName": "api", "listen_port": 80, "cpu": 1, "mode": "dev" })).unwrap();
+	assert_eq!((config.replicas, config.hosts, config.tls), (0, vec!["localhost".to_string()], None));
+	assert_eq!(config.mode, Mode::Development);
+	assert_eq!(from_json::<Mode>(json!({ "Canary": 5 })).unwrap(), Mode::Canary(5));
+	assert_eq!(from_json::<Mode>(json!({ "Pair": ["a", true] })).unwrap(), Mode::Pair("a".to_string(), true));
+	assert_eq!(Mode::Pair("a".to_string(), true).to_json(), json!({ "Pair": ["a", true] }));
+	assert_eq!(Wrapper(vec![1, 2]).to_json(), json!([1, 2]));
+	assert_eq!(from_json::<Wrapper<Option<bool>>>(Json::Null).unwrap(), Wrapper(None));
+	assert_eq!(from_json::<Point>(json!([1, -2])).unwrap(), Point(1, -2));
+	assert_eq!(Floor { étage_number: 3 }.to_json(), json!({ "ÉtageNumber": 3 }));
+}
+
+#[test]
+fn test_error_paths() {
+	let error = |json:Json| from_json::<ServiceConfig>(json).unwrap_err().to_string();
+	assert_eq!(error(json!({ "serviceName": "api", "cpu": 1, "mode": "dev" })), "Could not convert json at '', missing field 'listen_port'.");
+	assert_eq!(error(json!({ "serviceName": "api", "listen_port": 70000, "cpu": 1, "mode": "dev" })), "Could not convert json at '/listen_port', expected u16, found 70000.");
+	assert_eq!(error(json!({ "serviceName": "api", "listen_port": 1, "hosts": ["a", 2], "cpu": 1, "mode": "dev" })), "Could not convert json at '/hosts/1', expected string, found 2.");
+	assert_eq!(error(json!({ "serviceName": "api", "listen_port": 1, "cpu": 1, "mode": { "Production": { "region": [] } } })), "Could not convert json at '/mode/Production/region', expected string, found an array.");
+	assert_eq!(error(json!({ "serviceName": "api", "listen_port": 1, "cpu": 1, "mode": "test" })), "Could not convert json at '/mode', unknown variant 'test'.");
+	assert_eq!(error(json!({ "serviceName": "api", "listen_port": 1, "cpu": "high", "mode": "dev" })), "Could not convert json at '/cpu', expected f64, found \"high\".");
+	assert_eq!(error(json!([])), "Could not convert json at '', expected dict, found an array.");
+	assert_eq!(from_json::<Point>(json!([1])).unwrap_err().message, "expected an array of 2 items, found an array");
+}
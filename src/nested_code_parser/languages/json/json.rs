@@ -0,0 +1,688 @@
+use crate::{ FormatRule, FormatSpacing, JsonError, JsonNumber, JsonObject, JsonPosition, JsonWriter, NestedCodeFormatter, NestedCodeParser, NestedSegment, AUTO_CLOSE };
+use std::{ collections::{ hash_map::Entry, HashMap }, fmt::{ self, Display, Formatter }, sync::{ Mutex, MutexGuard, OnceLock } };
+
+
+
+// Definitions.
+pub(super) const DICT_NAME:&str = "dict";
+pub(super) const ARRAY_NAME:&str = "array";
+pub(super) const STRING_NAME:&str = "string";
+const INTEGER_NAME:&str = "integer";
+const FLOAT_NAME:&str = "float";
+const BOOL_NAME:&str = "bool";
+const NULL_NAME:&str = "null";
+const HEX_NAME:&str = "hex";
+const SPECIAL_NUMBER_NAME:&str = "special_number";
+const IDENTIFIER_NAME:&str = "identifier";
+pub(super) const COMMENT_NAME:&str = "comment";
+
+pub(super) const DICT_DIVIDER_NAME:&str = "dict_divider";
+pub(super) const LIST_DIVIDER_NAME:&str = "list_divider";
+
+const BOOL_CHECK:&'static dyn Fn(&str) -> Option<usize> = &|contents| {
+	if contents.get(..4).map(|start| start.eq_ignore_ascii_case("true")).unwrap_or(false) {
+		Some(4)
+	} else if contents.get(..5).map(|start| start.eq_ignore_ascii_case("false")).unwrap_or(false) {
+		Some(5)
+	} else {
+		None
+	}
+};
+const STRICT_WHITESPACE_CHARS:[char; 4] = [' ', '\t', '\n', '\r'];
+pub(super) const MAX_NESTING_DEPTH:usize = 512;
+
+
+
+// JSON NestedCodeParser.
+static mut JSON_PARSER_LOCK:Mutex<()> = Mutex::new(());
+static mut JSON_PARSER:Option<NestedCodeParser> = None;
+pub(crate) fn json_parser() -> &'static NestedCodeParser {
+	unsafe {
+		match JSON_PARSER.as_ref() {
+			Some(parser) => &parser,
+			None => {
+				let _lock:MutexGuard<'_, ()> = JSON_PARSER_LOCK.lock().unwrap();
+				JSON_PARSER = Some(
+					NestedCodeParser::new(vec![
+						&(DICT_NAME, true, "{", "}"),
+						&(ARRAY_NAME, true, "[", "]"),
+						&(STRING_NAME, false, "\"", None, "\"", Some("\\")),
+						&(STRING_NAME, false, "'", "'"),
+						&(STRING_NAME, false, "`", "`"),
+						&(FLOAT_NAME, r#"^-?\d+(\.\d+([eE][+-]?\d+)?|[eE][+-]?\d+)"#),
+						&(INTEGER_NAME, r#"^-?\d+"#),
+						&(BOOL_NAME, false, BOOL_CHECK, AUTO_CLOSE),
+						&(NULL_NAME, r#"^(?i)null"#),
+						
+						&(DICT_DIVIDER_NAME, false, ":", ""),
+						&(LIST_DIVIDER_NAME, false, ",", "")
+					])
+				);
+				json_parser()
+			}
+		}
+	}
+}
+
+// Strict RFC 8259 JSON NestedCodeParser.
+static JSON_STRICT_PARSER:OnceLock<StaticParser> = OnceLock::new();
+pub(crate) fn json_strict_parser() -> &'static NestedCodeParser {
+	&JSON_STRICT_PARSER.get_or_init(|| StaticParser(
+		NestedCodeParser::new(vec![
+			&(DICT_NAME, true, "{", "}"),
+			&(ARRAY_NAME, true, "[", "]"),
+			&(STRING_NAME, false, "\"", None, "\"", Some("\\")),
+			&(FLOAT_NAME, r#"^-?(0|[1-9]\d*)(\.\d+([eE][+-]?\d+)?|[eE][+-]?\d+)"#),
+			&(INTEGER_NAME, r#"^-?(0|[1-9]\d*)"#),
+			&(BOOL_NAME, r#"^(true|false)"#),
+			&(NULL_NAME, r#"^null"#),
+			
+			&(DICT_DIVIDER_NAME, false, ":", ""),
+			&(LIST_DIVIDER_NAME, false, ",", "")
+		])
+	)).0
+}
+
+/// A parser that can be stored in a static and shared between threads.
+struct StaticParser(NestedCodeParser);
+
+// SAFETY: A parser is immutable after creation. It is only not thread-safe because a `MatchMethod::Method` function is not required to be Sync. Static parsers are only created in this file, from regex and literal tag matches and the capture-less `AUTO_CLOSE` method, which are all thread-safe.
+unsafe impl Send for StaticParser {}
+unsafe impl Sync for StaticParser {}
+
+
+
+// JSON5 NestedCodeParser.
+static JSON5_PARSER:OnceLock<StaticParser> = OnceLock::new();
+pub(crate) fn json5_parser() -> &'static NestedCodeParser {
+	&JSON5_PARSER.get_or_init(|| StaticParser(
+		NestedCodeParser::new(vec![
+			&(DICT_NAME, true, "{", "}"),
+			&(ARRAY_NAME, true, "[", "]"),
+			&(COMMENT_NAME, false, "//", "\n"),
+			&(COMMENT_NAME, false, "/*", "*/"),
+			&(STRING_NAME, false, "\"", None, "\"", Some("\\")),
+			&(STRING_NAME, false, "'", None, "'", Some("\\")),
+			&(HEX_NAME, r#"^[+-]?0[xX][0-9a-fA-F]+"#),
+			&(FLOAT_NAME, r#"^[+-]?((0|[1-9]\d*)\.\d*([eE][+-]?\d+)?|\.\d+([eE][+-]?\d+)?|(0|[1-9]\d*)[eE][+-]?\d+)"#),
+			&(INTEGER_NAME, r#"^[+-]?(0|[1-9]\d*)"#),
+			&(SPECIAL_NUMBER_NAME, r#"^[+-]?(Infinity|NaN)\b"#),
+			&(BOOL_NAME, r#"^(true|false)\b"#),
+			&(NULL_NAME, r#"^null\b"#),
+			&(IDENTIFIER_NAME, r#"^[\p{L}\p{Nl}$_][\p{L}\p{Nl}\p{Mn}\p{Mc}\p{Nd}\p{Pc}$_\u200C\u200D]*"#),
+			
+			&(DICT_DIVIDER_NAME, false, ":", ""),
+			&(LIST_DIVIDER_NAME, false, ",", "")
+		])
+	)).0
+}
+
+
+
+/// Create a formatter that lays out parsed JSON with one entry per line and nested dicts and arrays indented.
+pub fn json_formatter() -> NestedCodeFormatter {
+	NestedCodeFormatter::new(json_parser())
+		.rule(DICT_NAME, FormatRule::new().indent())
+		.rule(ARRAY_NAME, FormatRule::new().indent())
+		.rule(DICT_DIVIDER_NAME, FormatRule::new().before(FormatSpacing::Nothing).after(FormatSpacing::Space))
+		.rule(LIST_DIVIDER_NAME, FormatRule::new().before(FormatSpacing::Nothing).after(FormatSpacing::Newline))
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum JsonFlavor {
+	/// Accepts some non-standard input, like single-quoted strings, unquoted keys and case-insensitive literals.
+	Lenient,
+	/// Accepts only RFC 8259 JSON.
+	Strict,
+	/// Accepts JSON5, a superset of JSONC. Adds comments, trailing commas, identifier keys, single-quoted and multi-line strings, hex numbers and Infinity and NaN.
+	Json5
+}
+impl JsonFlavor {
+
+	/// Get the parser for the flavor.
+	pub(super) fn parser(&self) -> &'static NestedCodeParser {
+		match self {
+			JsonFlavor::Lenient => json_parser(),
+			JsonFlavor::Strict => json_strict_parser(),
+			JsonFlavor::Json5 => json5_parser()
+		}
+	}
+
+	/// Validate dicts and arrays in contents are not nested deeper than `MAX_NESTING_DEPTH`. Parsing recurses once per nesting level, so deeper contents would overflow the stack.
+	/// Skips strings and comments the same way the parser of the flavor does, and like the parser only counts a closing tag when it closes the innermost open dict or array.
+	pub(super) fn validate_nesting_depth(&self, contents:&str) -> Result<(), JsonError> {
+		let bytes:&[u8] = contents.as_bytes();
+		let mut close_tags:Vec<u8> = Vec::new();
+		let mut index:usize = 0;
+		while index < bytes.len() {
+			let next:Option<u8> = bytes.get(index + 1).copied();
+			match bytes[index] {
+				open_tag @ (b'[' | b'{') => {
+					if close_tags.len() == MAX_NESTING_DEPTH {
+						return Err(JsonError::NestingTooDeep(JsonPosition::new(contents, index)));
+					}
+					close_tags.push(if open_tag == b'[' { b']' } else { b'}' });
+				},
+				close_tag @ (b']' | b'}') if close_tags.last() == Some(&close_tag) => { close_tags.pop(); },
+				b'"' => index = Self::string_end(bytes, index, true),
+				b'\'' if *self != JsonFlavor::Strict => index = Self::string_end(bytes, index, *self == JsonFlavor::Json5),
+				b'`' if *self == JsonFlavor::Lenient => index = Self::string_end(bytes, index, false),
+				b'/' if *self == JsonFlavor::Json5 && next == Some(b'/') => index = contents[index..].find('\n').map(|end| index + end).unwrap_or(bytes.len()),
+				b'/' if *self == JsonFlavor::Json5 && next == Some(b'*') => index = contents[index + 2..].find("*/").map(|end| index + end + 3).unwrap_or(bytes.len()),
+				_ => {}
+			}
+			index += 1;
+		}
+		Ok(())
+	}
+
+	/// Get the index of the closing quote of a string starting at an index, or the end of the contents for unterminated strings.
+	fn string_end(bytes:&[u8], start:usize, allow_escapes:bool) -> usize {
+		let mut index:usize = start + 1;
+		while index < bytes.len() && bytes[index] != bytes[start] {
+			index += if allow_escapes && bytes[index] == b'\\' { 2 } else { 1 };
+		}
+		index
+	}
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DuplicateKeys {
+	/// Keeps every entry in source order.
+	KeepAll,
+	/// Fails with a duplicate key error.
+	Error,
+	/// Keeps the value of the first entry.
+	FirstWins,
+	/// Keeps the value of the last entry, at the position of the first entry.
+	LastWins
+}
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct JsonReader {
+	flavor:JsonFlavor,
+	lossless_numbers:bool,
+	duplicate_keys:DuplicateKeys
+}
+impl JsonReader {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a reader for a flavor of json. Keeps all duplicate keys by default.
+	pub fn new(flavor:JsonFlavor) -> JsonReader {
+		JsonReader {
+			flavor,
+			lossless_numbers: false,
+			duplicate_keys: DuplicateKeys::KeepAll
+		}
+	}
+
+	/// Return self with valid numbers kept as the exact text they were written as.
+	pub fn lossless_numbers(mut self) -> Self {
+		self.lossless_numbers = true;
+		self
+	}
+
+	/// Return self with a policy for keys that occur more than once in a dict.
+	pub fn duplicate_keys(mut self, policy:DuplicateKeys) -> Self {
+		self.duplicate_keys = policy;
+		self
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Read json contents.
+	pub fn read(&self, contents:&str) -> Result<Json, JsonError> {
+		self.read_tree(contents, false)
+	}
+
+	/// Read json contents with a dict at the root into an object with a hash index, for constant time key lookup. Keys should be strings in all dicts, other keys fail with an invalid key error. An object can not hold duplicate keys, so the `KeepAll` policy keeps the last value like `LastWins`. Only the root dict is indexed, nested dicts stay `Json::Dict` values. Use `JsonObject::get_object` to index a nested dict.
+	pub fn read_object(&self, contents:&str) -> Result<JsonObject, JsonError> {
+		JsonObject::try_from(self.read_tree(contents, true)?).map_err(|_| JsonError::UnexpectedToken(JsonPosition::new(contents, contents.len() - contents.trim_start().len())))
+	}
+
+	/// Read a tree the parser of the flavor created from contents, like the tree a JsonDocument keeps. Validates the tree the same way as reading the contents would.
+	pub(super) fn read_parsed(&self, contents:&str, parsed_contents:&NestedSegment) -> Result<Json, JsonError> {
+		self.read_segment(contents, parsed_contents, 0, false)
+	}
+
+	/// Read json contents, optionally requiring a dict at the root and string keys in all dicts.
+	fn read_tree(&self, contents:&str, string_keys:bool) -> Result<Json, JsonError> {
+		self.flavor.validate_nesting_depth(contents)?;
+		let (offset, trimmed_contents) = if self.flavor == JsonFlavor::Strict { (0, contents) } else { (contents.len() - contents.trim_start().len(), contents.trim()) };
+		self.read_segment(contents, &self.flavor.parser().parse(trimmed_contents), offset, string_keys)
+	}
+
+	/// Read a parsed tree that starts at an offset in the contents.
+	fn read_segment(&self, contents:&str, parsed_contents:&NestedSegment, offset:usize, string_keys:bool) -> Result<Json, JsonError> {
+		let flavor:JsonFlavor = self.flavor;
+		let duplicate_keys:DuplicateKeys = if string_keys && self.duplicate_keys == DuplicateKeys::KeepAll { DuplicateKeys::LastWins } else { self.duplicate_keys };
+
+		// Validate contents.
+		let mut lengths:HashMap<*const NestedSegment, usize> = HashMap::new();
+		JsonTreeReader::measure(parsed_contents, &mut lengths);
+		let reader:JsonTreeReader = JsonTreeReader { source: contents, flavor, lossless_numbers: self.lossless_numbers, duplicate_keys, string_keys, lengths };
+		let sub_segments:Vec<(usize, &NestedSegment)> = reader.sub_segments(parsed_contents, offset)?;
+		let (value_offset, value) = *sub_segments.first().ok_or_else(|| reader.error(JsonError::Empty, offset))?;
+		if !value.is_code() {
+			return Err(reader.error(JsonError::UnexpectedToken, value_offset));
+		}
+		if let Some((trailing_offset, _)) = sub_segments.get(1) {
+			return Err(reader.error(JsonError::TrailingContents, *trailing_offset));
+		}
+		if string_keys && value.type_name() != DICT_NAME {
+			return Err(reader.error(JsonError::UnexpectedToken, value_offset));
+		}
+
+		// Return json nodes from contents.
+		reader.node(value, value_offset)
+	}
+}
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Json {
+	Dict(Vec<(Json, Json)>),
+	Array(Vec<Json>),
+	String(String),
+	Integer(i64),
+	Float(f64),
+	Number(JsonNumber),
+	Bool(bool),
+	Null
+}
+impl Json {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new json object from contents.
+	pub fn new(contents:&str) -> Result<Json, JsonError> {
+		Self::parse(contents, JsonFlavor::Lenient)
+	}
+
+	/// Create a new json object from contents, accepting only RFC 8259 JSON.
+	pub fn new_strict(contents:&str) -> Result<Json, JsonError> {
+		Self::parse(contents, JsonFlavor::Strict)
+	}
+
+	/// Create a new json object from JSON5 or JSONC contents, like VS Code settings files.
+	pub fn new_json5(contents:&str) -> Result<Json, JsonError> {
+		Self::parse(contents, JsonFlavor::Json5)
+	}
+
+	/// Create a new json object from contents in a specific flavor.
+	pub fn parse(contents:&str, flavor:JsonFlavor) -> Result<Json, JsonError> {
+		JsonReader::new(flavor).read(contents)
+	}
+
+	/// Create a new json object from contents in a specific flavor, keeping numbers as the exact text they were written as. Numbers that are not valid RFC 8259 numbers, like hex numbers, are parsed as usual.
+	/// The other constructors store numbers as i64 or f64, which loses precision on large integers and long decimals. JsonDocument, JsonFile and the serde helpers read through this lossless path.
+	pub fn parse_lossless(contents:&str, flavor:JsonFlavor) -> Result<Json, JsonError> {
+		JsonReader::new(flavor).lossless_numbers().read(contents)
+	}
+
+	/// Reformat JSON source text with the default json formatter. Unlike parsing and serializing, this keeps the source tokens as they are.
+	pub fn reformat(contents:&str) -> String {
+		json_formatter().format(&json_parser().parse(contents))
+	}
+
+	/// Decode the escape sequences in the inner contents of a string. The JSON5 escapes and line continuations are only decoded for the JSON5 flavor. Invalid escape sequences are kept as they are and lone surrogates become the replacement character.
+	pub(super) fn decode_string(inner_contents:&str, flavor:JsonFlavor) -> String {
+		let json5:bool = flavor == JsonFlavor::Json5;
+		let mut output:String = String::with_capacity(inner_contents.len());
+		let mut chars = inner_contents.chars().peekable();
+		while let Some(char) = chars.next() {
+			if char != '\\' {
+				output.push(char);
+				continue;
+			}
+			match chars.next() {
+				Some('"') => output.push('"'),
+				Some('\\') => output.push('\\'),
+				Some('/') => output.push('/'),
+				Some('b') => output.push('\u{8}'),
+				Some('f') => output.push('\u{c}'),
+				Some('n') => output.push('\n'),
+				Some('r') => output.push('\r'),
+				Some('t') => output.push('\t'),
+				Some('\'') if json5 => output.push('\''),
+				Some('v') if json5 => output.push('\u{b}'),
+				Some('0') if json5 && !chars.peek().map(|char| char.is_ascii_digit()).unwrap_or(false) => output.push('\0'),
+				Some('\n' | '\u{2028}' | '\u{2029}') if json5 => {},
+				Some('\r') if json5 => { chars.next_if_eq(&'\n'); },
+				Some('x') if json5 => {
+					let digits:String = chars.clone().take(2).collect();
+					match u8::from_str_radix(&digits, 16).ok().filter(|_| digits.len() == 2 && digits.chars().all(|char| char.is_ascii_hexdigit())) {
+						Some(code_point) => { chars.nth(1); output.push(code_point as char); },
+						None => output.push_str("\\x")
+					}
+				},
+				Some('u') => {
+					let Some(code_unit) = Self::take_code_unit(&mut chars) else {
+						output.push_str("\\u");
+						continue;
+					};
+					let code_point:u32 = match code_unit {
+						0xD800..=0xDBFF => {
+							let mut lookahead = chars.clone();
+							match (lookahead.next(), lookahead.next(), Self::take_code_unit(&mut lookahead)) {
+								(Some('\\'), Some('u'), Some(low @ 0xDC00..=0xDFFF)) => {
+									chars = lookahead;
+									0x10000 + ((code_unit - 0xD800) << 10) + (low - 0xDC00)
+								},
+								_ => 0xFFFD
+							}
+						},
+						code_unit => code_unit
+					};
+					output.push(char::from_u32(code_point).unwrap_or('\u{FFFD}'));
+				},
+				Some(other) => { output.push('\\'); output.push(other); },
+				None => output.push('\\')
+			}
+		}
+		output
+	}
+
+	/// Take the four hex digits of a unicode escape. Does not consume anything if there are no four hex digits.
+	fn take_code_unit(chars:&mut std::iter::Peekable<std::str::Chars>) -> Option<u32> {
+		let digits:String = chars.clone().take(4).collect();
+		if digits.len() != 4 || !digits.chars().all(|char| char.is_ascii_hexdigit()) {
+			return None;
+		}
+		chars.nth(3);
+		u32::from_str_radix(&digits, 16).ok()
+	}
+
+	/// Escape a string into a quoted JSON string.
+	pub fn escape_string(contents:&str) -> String {
+		let mut output:String = String::with_capacity(contents.len() + 2);
+		output.push('"');
+		for char in contents.chars() {
+			match char {
+				'"' => output.push_str("\\\""),
+				'\\' => output.push_str("\\\\"),
+				'\n' => output.push_str("\\n"),
+				'\r' => output.push_str("\\r"),
+				'\t' => output.push_str("\\t"),
+				'\u{8}' => output.push_str("\\b"),
+				'\u{c}' => output.push_str("\\f"),
+				char if (char as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", char as u32)),
+				char => output.push(char)
+			}
+		}
+		output.push('"');
+		output
+	}
+}
+impl Display for Json {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		JsonWriter::compact().write_fmt(self, f)
+	}
+}
+
+
+
+/// Turns a parsed tree into json nodes, keeping track of byte offsets in the source for errors.
+struct JsonTreeReader<'a> {
+	source:&'a str,
+	flavor:JsonFlavor,
+	lossless_numbers:bool,
+	duplicate_keys:DuplicateKeys,
+	string_keys:bool,
+	lengths:HashMap<*const NestedSegment, usize>
+}
+impl<'a> JsonTreeReader<'a> {
+
+	/// Measure the byte lengths of all code segments in a tree in a single pass. `NestedSegment::len` walks the whole sub-tree, so calling it for every child while reading would take quadratic time.
+	fn measure(segment:&NestedSegment, lengths:&mut HashMap<*const NestedSegment, usize>) -> usize {
+		let NestedSegment::Code(_, code) = segment else {
+			return segment.len();
+		};
+		let length:usize = code.open_tag.len() + code.sub_segments.iter().map(|sub_segment| Self::measure(sub_segment, lengths)).sum::<usize>() + code.close_tag.len();
+		lengths.insert(segment, length);
+		length
+	}
+
+	/// Get the byte length of a segment of the measured tree.
+	fn len(&self, segment:&NestedSegment) -> usize {
+		self.lengths.get(&(segment as *const NestedSegment)).copied().unwrap_or_else(|| segment.len())
+	}
+
+	/// Create an error of the given kind at an offset in the source.
+	fn error(&self, kind:fn(JsonPosition) -> JsonError, offset:usize) -> JsonError {
+		kind(JsonPosition::new(self.source, offset))
+	}
+
+	/// Get the sub-segments of a parsed node with their offsets, without white-space. White-space inside strings is kept, as strings are not stripped.
+	fn sub_segments<'b>(&self, parsed_code:&'b NestedSegment, offset:usize) -> Result<Vec<(usize, &'b NestedSegment)>, JsonError> {
+		let mut cursor:usize = offset + match parsed_code { NestedSegment::Code(_, code) => code.open_tag.len(), _ => 0 };
+		let mut sub_segments:Vec<(usize, &NestedSegment)> = Vec::new();
+		for sub_segment in parsed_code.sub_segments() {
+			if let NestedSegment::WhiteSpace(_, contents) = sub_segment {
+				if let Some(invalid_index) = contents.find(|char| !STRICT_WHITESPACE_CHARS.contains(&char)).filter(|_| self.flavor == JsonFlavor::Strict) {
+					return Err(self.error(JsonError::InvalidWhiteSpace, cursor + invalid_index));
+				}
+			} else if sub_segment.type_name() == COMMENT_NAME {
+				if matches!(sub_segment, NestedSegment::Code(_, code) if code.open_tag == "/*" && code.close_tag.is_empty()) {
+					return Err(self.error(JsonError::UnterminatedComment, cursor));
+				}
+			} else {
+				sub_segments.push((cursor, sub_segment));
+			}
+			cursor += self.len(sub_segment);
+		}
+		Ok(sub_segments)
+	}
+
+	/// Get the offset where the inner contents of a parsed node end.
+	fn inner_end(&self, parsed_code:&NestedSegment, offset:usize) -> usize {
+		offset + self.len(parsed_code) - match parsed_code { NestedSegment::Code(_, code) => code.close_tag.len(), _ => 0 }
+	}
+
+	/// Wether or not the node is a dict, array or string that is missing its closing tag.
+	fn is_unterminated(parsed_code:&NestedSegment) -> bool {
+		matches!(parsed_code, NestedSegment::Code(_, code) if code.close_tag.is_empty() && [DICT_NAME, ARRAY_NAME, STRING_NAME].contains(&code.type_name.as_str()))
+	}
+
+	/// Turn a parsed contents node into a json node.
+	fn node(&self, parsed_code:&NestedSegment, offset:usize) -> Result<Json, JsonError> {
+
+		// Validate the node is terminated. Reports the innermost unterminated node, as that is where the input ended.
+		if Self::is_unterminated(parsed_code) {
+			let (mut unterminated, mut unterminated_offset) = (parsed_code, offset);
+			while let Some(last_sub_segment) = unterminated.sub_segments().last().filter(|sub_segment| Self::is_unterminated(sub_segment)) {
+				unterminated_offset += self.len(unterminated) - self.len(last_sub_segment);
+				unterminated = last_sub_segment;
+			}
+			return Err(match unterminated.type_name() {
+				DICT_NAME => self.error(JsonError::UnterminatedDict, unterminated_offset),
+				ARRAY_NAME => self.error(JsonError::UnterminatedArray, unterminated_offset),
+				_ => self.error(JsonError::UnterminatedString, unterminated_offset)
+			});
+		}
+
+		// Keep valid numbers as written when parsing lossless.
+		if self.lossless_numbers && matches!(parsed_code.type_name(), FLOAT_NAME | INTEGER_NAME) {
+			if let Ok(number) = JsonNumber::new(&parsed_code.to_string()) {
+				return Ok(Json::Number(number));
+			}
+		}
+
+		// Parse by type.
+		match parsed_code.type_name() {
+			DICT_NAME => self.dict(parsed_code, offset),
+			ARRAY_NAME => self.array(parsed_code, offset),
+			_ => self.scalar(parsed_code, offset)
+		}
+	}
+
+	/// Turn a parsed contents node that is not a dict or array into a json node. Kept apart from `node`, so the recursion through nested dicts and arrays uses less stack.
+	fn scalar(&self, parsed_code:&NestedSegment, offset:usize) -> Result<Json, JsonError> {
+		match parsed_code.type_name() {
+			STRING_NAME => self.string(parsed_code, offset),
+			FLOAT_NAME => Ok(Json::Float(parsed_code.to_string().parse::<f64>().map_err(|_| self.error(JsonError::InvalidNumber, offset))?)),
+			INTEGER_NAME => {
+
+				// Integers too large for an i64 are stored as float.
+				let contents:String = parsed_code.to_string();
+				match contents.parse::<i64>() {
+					Ok(value) => Ok(Json::Integer(value)),
+					Err(_) => Ok(Json::Float(contents.parse::<f64>().map_err(|_| self.error(JsonError::InvalidNumber, offset))?))
+				}
+			},
+			HEX_NAME => {
+
+				// Hex numbers too large for an i64 are kept exact as number, or stored as float beyond 127 bits.
+				let contents:String = parsed_code.to_string();
+				let digits:&str = &contents.trim_start_matches(['+', '-'])[2..];
+				let sign:i128 = if contents.starts_with('-') { -1 } else { 1 };
+				Ok(match i128::from_str_radix(digits, 16) {
+					Ok(value) => i64::try_from(sign * value).map(Json::Integer).unwrap_or(Json::Number(JsonNumber::from(sign * value))),
+					Err(_) => Json::Float(sign as f64 * digits.chars().fold(0.0, |value, digit| value * 16.0 + digit.to_digit(16).unwrap_or(0) as f64))
+				})
+			},
+			SPECIAL_NUMBER_NAME => {
+				let contents:String = parsed_code.to_string();
+				Ok(Json::Float(match contents.trim_start_matches(['+', '-']) {
+					"NaN" => f64::NAN,
+					_ if contents.starts_with('-') => f64::NEG_INFINITY,
+					_ => f64::INFINITY
+				}))
+			},
+			BOOL_NAME => Ok(Json::Bool(parsed_code.to_string().to_lowercase() == "true")),
+			NULL_NAME => Ok(Json::Null),
+			_ => Err(self.error(JsonError::UnexpectedToken, offset))
+		}
+	}
+
+	/// Parse a dictionary node.
+	fn dict(&self, parsed_code:&NestedSegment, offset:usize) -> Result<Json, JsonError> {
+		let sub_segments:Vec<(usize, &NestedSegment)> = self.sub_segments(parsed_code, offset)?;
+		let end:usize = self.inner_end(parsed_code, offset);
+		let offset_at = |index:usize| sub_segments.get(index).map(|(offset, _)| *offset).unwrap_or(end);
+		let type_at = |index:usize| sub_segments.get(index).map(|(_, segment)| segment.type_name());
+		let mut entries:Vec<(Json, Json)> = Vec::new();
+		let mut key_indexes:HashMap<String, usize> = HashMap::new();
+		let mut index:usize = 0;
+		while index < sub_segments.len() {
+
+			// Validate entry.
+			if [Some(DICT_DIVIDER_NAME), Some(LIST_DIVIDER_NAME)].contains(&type_at(index)) {
+				return Err(self.error(JsonError::UnexpectedToken, offset_at(index)));
+			}
+			if type_at(index + 1) != Some(DICT_DIVIDER_NAME) {
+				return Err(self.error(JsonError::MissingColon, offset_at(index + 1)));
+			}
+			if [None, Some(DICT_DIVIDER_NAME), Some(LIST_DIVIDER_NAME)].contains(&type_at(index + 2)) {
+				return Err(self.error(JsonError::MissingValue, offset_at(index + 2)));
+			}
+			if type_at(index + 3).is_some() && type_at(index + 3) != Some(LIST_DIVIDER_NAME) {
+				return Err(self.error(JsonError::MissingComma, offset_at(index + 3)));
+			}
+			if index + 4 == sub_segments.len() && self.flavor != JsonFlavor::Json5 {
+				return Err(self.error(JsonError::TrailingComma, offset_at(index + 3)));
+			}
+
+			// Parse key and value.
+			let (key_offset, key_item) = sub_segments[index];
+			let key:Json = match self.flavor {
+				JsonFlavor::Strict if key_item.type_name() != STRING_NAME => return Err(self.error(JsonError::InvalidKey, key_offset)),
+				JsonFlavor::Strict => self.node(key_item, key_offset)?,
+				JsonFlavor::Lenient => self.node(key_item, key_offset).unwrap_or(Json::String(key_item.to_string().trim().to_string())),
+				JsonFlavor::Json5 => match key_item.type_name() {
+					STRING_NAME => self.node(key_item, key_offset)?,
+					IDENTIFIER_NAME | BOOL_NAME | NULL_NAME | SPECIAL_NUMBER_NAME if !key_item.to_string().starts_with(['+', '-']) => Json::String(key_item.to_string()),
+					_ => return Err(self.error(JsonError::InvalidKey, key_offset))
+				}
+			};
+			if self.string_keys && !matches!(key, Json::String(_)) {
+				return Err(self.error(JsonError::InvalidKey, key_offset));
+			}
+			let (value_offset, value_item) = sub_segments[index + 2];
+			let value:Json = self.node(value_item, value_offset)?;
+			index += 4;
+
+			// Store entry by duplicate key policy.
+			if self.duplicate_keys == DuplicateKeys::KeepAll {
+				entries.push((key, value));
+				continue;
+			}
+			match key_indexes.entry(key.to_string()) {
+				Entry::Vacant(entry) => {
+					entry.insert(entries.len());
+					entries.push((key, value));
+				},
+				Entry::Occupied(entry) => match self.duplicate_keys {
+					DuplicateKeys::Error => return Err(self.error(JsonError::DuplicateKey, key_offset)),
+					DuplicateKeys::LastWins => entries[*entry.get()].1 = value,
+					DuplicateKeys::FirstWins | DuplicateKeys::KeepAll => {}
+				}
+			}
+		}
+		Ok(Json::Dict(entries))
+	}
+
+	/// Parse an array node.
+	fn array(&self, parsed_code:&NestedSegment, offset:usize) -> Result<Json, JsonError> {
+		let sub_segments:Vec<(usize, &NestedSegment)> = self.sub_segments(parsed_code, offset)?;
+		let mut items:Vec<Json> = Vec::new();
+		let mut index:usize = 0;
+		while index < sub_segments.len() {
+			let (item_offset, item) = sub_segments[index];
+			if [DICT_DIVIDER_NAME, LIST_DIVIDER_NAME].contains(&item.type_name()) {
+				return Err(self.error(JsonError::UnexpectedToken, item_offset));
+			}
+			if let Some((divider_offset, divider)) = sub_segments.get(index + 1) {
+				if divider.type_name() != LIST_DIVIDER_NAME {
+					return Err(self.error(JsonError::MissingComma, *divider_offset));
+				}
+				if index + 2 == sub_segments.len() && self.flavor != JsonFlavor::Json5 {
+					return Err(self.error(JsonError::TrailingComma, *divider_offset));
+				}
+			}
+			items.push(self.node(item, item_offset)?);
+			index += 2;
+		}
+		Ok(Json::Array(items))
+	}
+
+	/// Parse a string node.
+	fn string(&self, parsed_code:&NestedSegment, offset:usize) -> Result<Json, JsonError> {
+		let NestedSegment::Code(_, code) = parsed_code else {
+			return Err(self.error(JsonError::UnexpectedToken, offset));
+		};
+		let inner_contents:String = parsed_code.sub_contents_to_string();
+		if self.flavor == JsonFlavor::Strict {
+			self.validate_strict_string(&inner_contents, offset + code.open_tag.len())?;
+		}
+		Ok(Json::String(if code.open_tag == "\"" || self.flavor == JsonFlavor::Json5 { Json::decode_string(&inner_contents, self.flavor) } else { inner_contents }))
+	}
+
+	/// Validate the inner contents of a string contain no unescaped control characters and only valid escape sequences.
+	fn validate_strict_string(&self, inner_contents:&str, offset:usize) -> Result<(), JsonError> {
+		let mut chars = inner_contents.char_indices();
+		while let Some((index, char)) = chars.next() {
+			if (char as u32) < 0x20 {
+				return Err(self.error(JsonError::ControlCharacter, offset + index));
+			}
+			if char == '\\' {
+				match chars.next() {
+					Some((_, '"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't')) => {},
+					Some((_, 'u')) if chars.by_ref().take(4).filter(|(_, char)| char.is_ascii_hexdigit()).count() == 4 => {},
+					_ => return Err(self.error(JsonError::InvalidEscape, offset + index))
+				}
+			}
+		}
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,255 @@
+use super::{ Json, JsonNumber };
+use std::ops::{ Index, IndexMut };
+
+
+
+static ERROR_INSTANCE:Json = Json::Null;
+
+
+
+/// A key that can be used to look up a value in json. Strings look up dict entries, numbers look up array items.
+pub trait JsonIndex {
+	fn index_into<'a>(&self, json:&'a Json) -> Option<&'a Json>;
+	fn index_into_mut<'a>(&self, json:&'a mut Json) -> Option<&'a mut Json>;
+	fn index_or_insert<'a>(&self, json:&'a mut Json) -> &'a mut Json;
+}
+impl JsonIndex for str {
+	fn index_into<'a>(&self, json:&'a Json) -> Option<&'a Json> {
+		json.as_object()?.iter().find(|(key, _)| key.as_str() == Some(self)).map(|(_, value)| value)
+	}
+	fn index_into_mut<'a>(&self, json:&'a mut Json) -> Option<&'a mut Json> {
+		json.as_object_mut()?.iter_mut().find(|(key, _)| key.as_str() == Some(self)).map(|(_, value)| value)
+	}
+	fn index_or_insert<'a>(&self, json:&'a mut Json) -> &'a mut Json {
+		if json.is_null() {
+			*json = Json::Dict(Vec::new());
+		}
+		let Json::Dict(entries) = json else {
+			panic!("Cannot index json with key '{self}', it is not a dict.");
+		};
+		let index:usize = match entries.iter().position(|(key, _)| key.as_str() == Some(self)) {
+			Some(index) => index,
+			None => {
+				entries.push((Json::String(self.to_string()), Json::Null));
+				entries.len() - 1
+			}
+		};
+		&mut entries[index].1
+	}
+}
+impl JsonIndex for String {
+	fn index_into<'a>(&self, json:&'a Json) -> Option<&'a Json> {
+		self.as_str().index_into(json)
+	}
+	fn index_into_mut<'a>(&self, json:&'a mut Json) -> Option<&'a mut Json> {
+		self.as_str().index_into_mut(json)
+	}
+	fn index_or_insert<'a>(&self, json:&'a mut Json) -> &'a mut Json {
+		self.as_str().index_or_insert(json)
+	}
+}
+impl JsonIndex for usize {
+	fn index_into<'a>(&self, json:&'a Json) -> Option<&'a Json> {
+		json.as_array()?.get(*self)
+	}
+	fn index_into_mut<'a>(&self, json:&'a mut Json) -> Option<&'a mut Json> {
+		json.as_array_mut()?.get_mut(*self)
+	}
+	fn index_or_insert<'a>(&self, json:&'a mut Json) -> &'a mut Json {
+		let length:Option<usize> = json.as_array().map(|items| items.len());
+		self.index_into_mut(json).unwrap_or_else(|| match length {
+			Some(length) => panic!("Cannot index json array at {self}, it has {length} items."),
+			None => panic!("Cannot index json at {self}, it is not an array.")
+		})
+	}
+}
+impl<T:JsonIndex + ?Sized> JsonIndex for &T {
+	fn index_into<'a>(&self, json:&'a Json) -> Option<&'a Json> {
+		(**self).index_into(json)
+	}
+	fn index_into_mut<'a>(&self, json:&'a mut Json) -> Option<&'a mut Json> {
+		(**self).index_into_mut(json)
+	}
+	fn index_or_insert<'a>(&self, json:&'a mut Json) -> &'a mut Json {
+		(**self).index_or_insert(json)
+	}
+}
+
+
+
+impl Json {
+
+	/* ACCESSOR METHODS */
+
+	/// Get the error Json. Used for when a json value is expected, but not available. Is null, so chained lookups keep returning it.
+	pub fn error_instance() -> &'static Json {
+		&ERROR_INSTANCE
+	}
+
+	/// Get a value by dict key or array index.
+	pub fn get<T:JsonIndex>(&self, index:T) -> Option<&Json> {
+		index.index_into(self)
+	}
+
+	/// Get a mutable value by dict key or array index.
+	pub fn get_mut<T:JsonIndex>(&mut self, index:T) -> Option<&mut Json> {
+		index.index_into_mut(self)
+	}
+
+	/// Wether or not the value is null.
+	pub fn is_null(&self) -> bool {
+		matches!(self, Json::Null)
+	}
+
+	/// Get the value as string slice, if it is a string.
+	pub fn as_str(&self) -> Option<&str> {
+		match self {
+			Json::String(value) => Some(value),
+			_ => None
+		}
+	}
+
+	/// Get the value as integer, if it is an integer. Lossless numbers are converted when they are whole numbers within range.
+	pub fn as_i64(&self) -> Option<i64> {
+		match self {
+			Json::Integer(value) => Some(*value),
+			Json::Number(value) => value.as_i64(),
+			_ => None
+		}
+	}
+
+	/// Get the value as unsigned integer, if it is a positive integer within range.
+	pub fn as_u64(&self) -> Option<u64> {
+		match self {
+			Json::Integer(value) => u64::try_from(*value).ok(),
+			Json::Number(value) => value.as_u64(),
+			_ => None
+		}
+	}
+
+	/// Get the value as float, if it is a number.
+	pub fn as_f64(&self) -> Option<f64> {
+		match self {
+			Json::Float(value) => Some(*value),
+			Json::Integer(value) => Some(*value as f64),
+			Json::Number(value) => Some(value.as_f64()),
+			_ => None
+		}
+	}
+
+	/// Get the value as lossless number, if it was parsed lossless.
+	pub fn as_number(&self) -> Option<&JsonNumber> {
+		match self {
+			Json::Number(value) => Some(value),
+			_ => None
+		}
+	}
+
+	/// Get the value as bool, if it is a bool.
+	pub fn as_bool(&self) -> Option<bool> {
+		match self {
+			Json::Bool(value) => Some(*value),
+			_ => None
+		}
+	}
+
+	/// Get the items, if the value is an array.
+	pub fn as_array(&self) -> Option<&Vec<Json>> {
+		match self {
+			Json::Array(items) => Some(items),
+			_ => None
+		}
+	}
+
+	/// Get the mutable items, if the value is an array.
+	pub fn as_array_mut(&mut self) -> Option<&mut Vec<Json>> {
+		match self {
+			Json::Array(items) => Some(items),
+			_ => None
+		}
+	}
+
+	/// Get the entries, if the value is a dict.
+	pub fn as_object(&self) -> Option<&Vec<(Json, Json)>> {
+		match self {
+			Json::Dict(entries) => Some(entries),
+			_ => None
+		}
+	}
+
+	/// Get the mutable entries, if the value is a dict.
+	pub fn as_object_mut(&mut self) -> Option<&mut Vec<(Json, Json)>> {
+		match self {
+			Json::Dict(entries) => Some(entries),
+			_ => None
+		}
+	}
+
+	/// Get the amount of entries in a dict or items in an array. Other values have no length.
+	pub fn len(&self) -> usize {
+		match self {
+			Json::Dict(entries) => entries.len(),
+			Json::Array(items) => items.len(),
+			_ => 0
+		}
+	}
+
+	/// Wether or not the value is a dict or array without entries or any other value.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+
+
+	/* MODIFICATION METHODS */
+
+	/// Set the value of a key in a dict. Existing keys keep their position, new keys are added at the end. Null becomes an empty dict first. Returns the previous value of the key.
+	pub fn insert(&mut self, key:&str, value:Json) -> Option<Json> {
+		let existing:Option<Json> = self.get(key).cloned();
+		*key.index_or_insert(self) = value;
+		existing
+	}
+
+	/// Remove a key from a dict, keeping the order of the other entries. Returns the removed value.
+	pub fn remove(&mut self, key:&str) -> Option<Json> {
+		let entries:&mut Vec<(Json, Json)> = self.as_object_mut()?;
+		let index:usize = entries.iter().position(|(entry_key, _)| entry_key.as_str() == Some(key))?;
+		Some(entries.remove(index).1)
+	}
+
+	/// Add an item to the end of an array. Null becomes an empty array first.
+	pub fn push(&mut self, value:Json) {
+		if self.is_null() {
+			*self = Json::Array(Vec::new());
+		}
+		match self {
+			Json::Array(items) => items.push(value),
+			_ => panic!("Cannot push to json, it is not an array.")
+		}
+	}
+
+	/// Insert an item into an array at the given index, moving the items after it.
+	pub fn insert_at(&mut self, index:usize, value:Json) {
+		match self {
+			Json::Array(items) => items.insert(index, value),
+			_ => panic!("Cannot insert into json at {index}, it is not an array.")
+		}
+	}
+
+	/// Remove the item at the given index from an array, keeping the order of the other items. Returns the removed item.
+	pub fn remove_at(&mut self, index:usize) -> Option<Json> {
+		let items:&mut Vec<Json> = self.as_array_mut()?;
+		if index < items.len() { Some(items.remove(index)) } else { None }
+	}
+}
+impl<T:JsonIndex> Index<T> for Json {
+	type Output = Json;
+	fn index(&self, index:T) -> &Self::Output {
+		index.index_into(self).unwrap_or(Json::error_instance())
+	}
+}
+impl<T:JsonIndex> IndexMut<T> for Json {
+	fn index_mut(&mut self, index:T) -> &mut Self::Output {
+		index.index_or_insert(self)
+	}
+}
\ No newline at end of file
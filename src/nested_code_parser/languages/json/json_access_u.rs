@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+	use crate::Json;
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn example_json() -> Json {
+		Json::new(r#"{"name": "omni", "version": 3, "ratio": 0.5, "stable": false, "list": [1, [2, 3], {"deep": null}]}"#).unwrap()
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_index() {
+		let json:Json = example_json();
+		assert_eq!(json["name"], Json::String("omni".to_string()));
+		assert_eq!(json["list"][1][0], Json::Integer(2));
+		assert_eq!(json[&"list".to_string()][2]["deep"], Json::Null);
+		assert_eq!(json["missing"]["further"][3], Json::Null);
+		assert!(std::ptr::eq(&json["list"][9], Json::error_instance()));
+	}
+
+	#[test]
+	fn test_get() {
+		let mut json:Json = example_json();
+		assert_eq!(json.get("version"), Some(&Json::Integer(3)));
+		assert_eq!(json.get("missing"), None);
+		assert_eq!(json["list"].get(5), None);
+		*json.get_mut("version").unwrap() = Json::Integer(4);
+		assert_eq!(json["version"], Json::Integer(4));
+	}
+
+	#[test]
+	fn test_typed_getters() {
+		let json:Json = example_json();
+		assert_eq!(json["name"].as_str(), Some("omni"));
+		assert_eq!(json["version"].as_i64(), Some(3));
+		assert_eq!(json["version"].as_f64(), Some(3.0));
+		assert_eq!(json["ratio"].as_f64(), Some(0.5));
+		assert_eq!(json["ratio"].as_i64(), None);
+		assert_eq!(json["stable"].as_bool(), Some(false));
+		assert_eq!(json["list"].as_array().map(|items| items.len()), Some(3));
+		assert_eq!(json.as_object().map(|entries| entries.len()), Some(5));
+		assert_eq!(json["name"].as_array(), None);
+		assert!(json["list"][2]["deep"].is_null());
+	}
+
+	#[test]
+	fn test_index_mut() {
+		let mut json:Json = example_json();
+		json["name"] = Json::String("parser".to_string());
+		json["new"]["nested"] = Json::Bool(true);
+		json["list"][0] = Json::Null;
+		assert_eq!(json.to_string(), r#"{"name":"parser","version":3,"ratio":0.5,"stable":false,"list":[null,[2,3],{"deep":null}],"new":{"nested":true}}"#);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_index_mut_out_of_bounds() {
+		let mut json:Json = example_json();
+		json["list"][10] = Json::Null;
+	}
+
+	#[test]
+	fn test_dict_modification() {
+		let mut json:Json = Json::Null;
+		assert_eq!(json.insert("b", Json::Integer(1)), None);
+		assert_eq!(json.insert("a", Json::Integer(2)), None);
+		assert_eq!(json.insert("b", Json::Integer(3)), Some(Json::Integer(1)));
+		assert_eq!(json.to_string(), r#"{"b":3,"a":2}"#);
+		assert_eq!(json.remove("b"), Some(Json::Integer(3)));
+		assert_eq!(json.remove("b"), None);
+		assert_eq!(json.to_string(), r#"{"a":2}"#);
+		assert_eq!(json.len(), 1);
+	}
+
+	#[test]
+	fn test_array_modification() {
+		let mut json:Json = Json::Null;
+		json.push(Json::Integer(1));
+		json.push(Json::Integer(3));
+		json.insert_at(1, Json::Integer(2));
+		assert_eq!(json.to_string(), "[1,2,3]");
+		assert_eq!(json.remove_at(0), Some(Json::Integer(1)));
+		assert_eq!(json.remove_at(5), None);
+		assert_eq!(json.to_string(), "[2,3]");
+		assert!(!json.is_empty());
+	}
+}
\ No newline at end of file
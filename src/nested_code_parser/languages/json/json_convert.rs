@@ -0,0 +1,176 @@
+use super::{ Json, JsonNumber };
+use std::{ borrow::Borrow, collections::{ BTreeMap, HashMap }, error::Error };
+
+
+
+macro_rules! json_from_integer {
+	($($type:ty),*) => {
+		$(
+			impl From<$type> for Json {
+				fn from(value:$type) -> Json {
+					match i64::try_from(value) {
+						Ok(value) => Json::Integer(value),
+						Err(_) => Json::Number(JsonNumber::from(value))
+					}
+				}
+			}
+			impl TryFrom<Json> for $type {
+				type Error = Box<dyn Error>;
+
+				fn try_from(json:Json) -> Result<$type, Box<dyn Error>> {
+					let value:Option<i128> = match &json {
+						Json::Integer(value) => Some(*value as i128),
+						Json::Number(value) => value.as_i128(),
+						_ => None
+					};
+					value.and_then(|value| <$type>::try_from(value).ok()).ok_or_else(|| conversion_error(&json, stringify!($type)))
+				}
+			}
+		)*
+	};
+}
+json_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! json_from_value {
+	($($type:ty => $variant:ident),*) => {
+		$(
+			impl From<$type> for Json {
+				fn from(value:$type) -> Json {
+					Json::$variant(value.into())
+				}
+			}
+		)*
+	};
+}
+json_from_value!(bool => Bool, f32 => Float, f64 => Float, String => String, &str => String, &String => String, char => String, JsonNumber => Number);
+
+macro_rules! json_try_into_option {
+	($($type:ty),*) => {
+		$(
+			impl TryFrom<Json> for Option<$type> {
+				type Error = Box<dyn Error>;
+
+				fn try_from(json:Json) -> Result<Option<$type>, Box<dyn Error>> {
+					match json {
+						Json::Null => Ok(None),
+						json => <$type>::try_from(json).map(Some)
+					}
+				}
+			}
+		)*
+	};
+}
+json_try_into_option!(bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, String);
+
+
+
+impl<T:Into<Json>> From<Option<T>> for Json {
+	fn from(value:Option<T>) -> Json {
+		value.map(Into::into).unwrap_or(Json::Null)
+	}
+}
+impl<T:Into<Json>> From<Vec<T>> for Json {
+	fn from(items:Vec<T>) -> Json {
+		Json::Array(items.into_iter().map(Into::into).collect())
+	}
+}
+impl<T:Into<Json> + Clone> From<&[T]> for Json {
+	fn from(items:&[T]) -> Json {
+		Json::Array(items.iter().cloned().map(Into::into).collect())
+	}
+}
+impl<T:Into<Json>> From<HashMap<String, T>> for Json {
+
+	/// Create a dict from a hash map. Keys are sorted, as hash maps have no order.
+	fn from(map:HashMap<String, T>) -> Json {
+		let mut entries:Vec<(String, T)> = map.into_iter().collect();
+		entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+		Json::Dict(entries.into_iter().map(|(key, value)| (Json::String(key), value.into())).collect())
+	}
+}
+impl<T:Into<Json>> From<BTreeMap<String, T>> for Json {
+	fn from(map:BTreeMap<String, T>) -> Json {
+		Json::Dict(map.into_iter().map(|(key, value)| (Json::String(key), value.into())).collect())
+	}
+}
+
+
+
+impl TryFrom<Json> for bool {
+	type Error = Box<dyn Error>;
+
+	fn try_from(json:Json) -> Result<bool, Box<dyn Error>> {
+		json.as_bool().ok_or_else(|| conversion_error(&json, "bool"))
+	}
+}
+impl TryFrom<Json> for f64 {
+	type Error = Box<dyn Error>;
+
+	fn try_from(json:Json) -> Result<f64, Box<dyn Error>> {
+		json.as_f64().ok_or_else(|| conversion_error(&json, "f64"))
+	}
+}
+impl TryFrom<Json> for f32 {
+	type Error = Box<dyn Error>;
+
+	fn try_from(json:Json) -> Result<f32, Box<dyn Error>> {
+		json.as_f64().map(|value| value as f32).ok_or_else(|| conversion_error(&json, "f32"))
+	}
+}
+impl TryFrom<Json> for String {
+	type Error = Box<dyn Error>;
+
+	fn try_from(json:Json) -> Result<String, Box<dyn Error>> {
+		match json {
+			Json::String(value) => Ok(value),
+			json => Err(conversion_error(&json, "String"))
+		}
+	}
+}
+impl<T:TryFrom<Json, Error = Box<dyn Error>>> TryFrom<Json> for Vec<T> {
+	type Error = Box<dyn Error>;
+
+	fn try_from(json:Json) -> Result<Vec<T>, Box<dyn Error>> {
+		match json {
+			Json::Array(items) => items.into_iter().map(T::try_from).collect(),
+			json => Err(conversion_error(&json, "Vec"))
+		}
+	}
+}
+impl<T:TryFrom<Json, Error = Box<dyn Error>>> TryFrom<Json> for HashMap<String, T> {
+	type Error = Box<dyn Error>;
+
+	fn try_from(json:Json) -> Result<HashMap<String, T>, Box<dyn Error>> {
+		dict_entries(json, "HashMap")
+	}
+}
+impl<T:TryFrom<Json, Error = Box<dyn Error>>> TryFrom<Json> for BTreeMap<String, T> {
+	type Error = Box<dyn Error>;
+
+	fn try_from(json:Json) -> Result<BTreeMap<String, T>, Box<dyn Error>> {
+		dict_entries(json, "BTreeMap")
+	}
+}
+
+
+
+/// Create the error for a json value that can not be converted to a type.
+fn conversion_error(json:&Json, type_name:&str) -> Box<dyn Error> {
+	format!("Could not convert json {json} to {type_name}.").into()
+}
+
+/// Collect the converted entries of a dict into a map.
+fn dict_entries<T:TryFrom<Json, Error = Box<dyn Error>>, U:FromIterator<(String, T)>>(json:Json, type_name:&str) -> Result<U, Box<dyn Error>> {
+	match json {
+		Json::Dict(entries) => collect_entries(entries, |key| format!("Could not convert json dict, key {key} is not a string.").into(), |_, value| T::try_from(value)),
+		json => Err(conversion_error(&json, type_name))
+	}
+}
+
+/// Collect owned or borrowed dict entries into a map, converting the values. Keys should be strings, other keys fail with the key error.
+pub(super) fn collect_entries<K:Borrow<Json>, V, T, U:FromIterator<(String, T)>, E>(entries:impl IntoIterator<Item = (K, V)>, key_error:impl Fn(&Json) -> E, convert:impl Fn(&str, V) -> Result<T, E>) -> Result<U, E> {
+	entries.into_iter().map(|(key, value)| match key.borrow() {
+		Json::String(key) => Ok((key.clone(), convert(key, value)?)),
+		key => Err(key_error(key))
+	}).collect()
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonNumber };
+	use std::{ collections::{ BTreeMap, HashMap }, error::Error };
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_from_rust() {
+		assert_eq!(Json::from(true), Json::Bool(true));
+		assert_eq!(Json::from(7u8), Json::Integer(7));
+		assert_eq!(Json::from(u64::MAX), Json::Number(JsonNumber::from(u64::MAX)));
+		assert_eq!(Json::from(1.5f32), Json::Float(1.5));
+		assert_eq!(Json::from("text"), Json::String("text".to_string()));
+		assert_eq!(Json::from('c'), Json::String("c".to_string()));
+		assert_eq!(Json::from(None::<i32>), Json::Null);
+		assert_eq!(Json::from(vec![Some(1), None]).to_string(), "[1,null]");
+		assert_eq!(Json::from(&["a", "b"][..]).to_string(), r#"["a","b"]"#);
+		let map:HashMap<String, Vec<i32>> = HashMap::from([("b".to_string(), vec![2]), ("a".to_string(), vec![1])]);
+		assert_eq!(Json::from(map).to_string(), r#"{"a":[1],"b":[2]}"#);
+		let map:BTreeMap<String, bool> = BTreeMap::from([("y".to_string(), false), ("x".to_string(), true)]);
+		assert_eq!(Json::from(map).to_string(), r#"{"x":true,"y":false}"#);
+	}
+
+	#[test]
+	fn test_try_into_rust() {
+		let json:Json = Json::new(r#"{"port": 8080, "ratio": 0.5, "name": "api", "tags": ["a", "b"], "limits": {"cpu": 2}, "tls": null}"#).unwrap();
+		assert_eq!(u16::try_from(json["port"].clone()).unwrap(), 8080);
+		assert!(u8::try_from(json["port"].clone()).is_err());
+		assert!(i64::try_from(json["ratio"].clone()).is_err());
+		assert_eq!(f64::try_from(json["port"].clone()).unwrap(), 8080.0);
+		assert_eq!(String::try_from(json["name"].clone()).unwrap(), "api");
+		assert_eq!(Vec::<String>::try_from(json["tags"].clone()).unwrap(), vec!["a", "b"]);
+		assert_eq!(HashMap::<String, u32>::try_from(json["limits"].clone()).unwrap()["cpu"], 2);
+		assert_eq!(BTreeMap::<String, i64>::try_from(json["limits"].clone()).unwrap().len(), 1);
+		assert_eq!(Option::<bool>::try_from(json["tls"].clone()).unwrap(), None);
+		assert_eq!(Option::<String>::try_from(json["name"].clone()).unwrap().as_deref(), Some("api"));
+		assert_eq!(u64::try_from(Json::Number(JsonNumber::from(u64::MAX))).unwrap(), u64::MAX);
+
+		let error:Box<dyn Error> = Vec::<bool>::try_from(json["tags"].clone()).unwrap_err();
+		assert_eq!(error.to_string(), r#"Could not convert json "a" to bool."#);
+		assert!(bool::try_from(Json::Null).is_err());
+	}
+}
@@ -0,0 +1,268 @@
+use super::Json;
+use std::{ collections::HashMap, fmt::{ self, Display, Formatter } };
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum JsonDifferenceKind {
+	Added,
+	Removed,
+	/// The value changed, but kept its type.
+	Changed,
+	/// The value changed to another type, like a number to a string.
+	TypeChanged
+}
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct JsonDifference {
+	pub path:String,
+	pub kind:JsonDifferenceKind,
+	pub old:Option<Json>,
+	pub new:Option<Json>
+}
+impl Display for JsonDifference {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		match (self.kind, &self.old, &self.new) {
+			(JsonDifferenceKind::Added, _, Some(new)) => write!(f, "added '{}': {new}", self.path),
+			(JsonDifferenceKind::Removed, Some(old), _) => write!(f, "removed '{}': {old}", self.path),
+			(JsonDifferenceKind::TypeChanged, Some(old), Some(new)) => write!(f, "changed type of '{}' from {} to {}: {old} -> {new}", self.path, type_name(old), type_name(new)),
+			(_, old, new) => write!(f, "changed '{}': {} -> {}", self.path, optional_string(old), optional_string(new))
+		}
+	}
+}
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct JsonConflict {
+	pub path:String,
+	pub base:Option<Json>,
+	pub ours:Option<Json>,
+	pub theirs:Option<Json>
+}
+impl Display for JsonConflict {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "conflict at '{}': base {}, ours {}, theirs {}", self.path, optional_string(&self.base), optional_string(&self.ours), optional_string(&self.theirs))
+	}
+}
+
+
+
+/// The result of a three-way merge. Conflicting locations keep our value.
+#[derive(Clone, PartialEq, Debug)]
+pub struct JsonMerge {
+	pub value:Json,
+	pub conflicts:Vec<JsonConflict>
+}
+impl JsonMerge {
+
+	/// Whether or not the merge had no conflicts.
+	pub fn is_clean(&self) -> bool {
+		self.conflicts.is_empty()
+	}
+}
+
+
+
+/// Compares and merges json values by location. Arrays are compared by index, or by an identity key when all items are dicts with a unique value for that key.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct JsonDiff {
+	identity_key:Option<String>,
+	exact_numbers:bool
+}
+impl JsonDiff {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new diff that compares arrays by index.
+	pub fn new() -> JsonDiff {
+		JsonDiff::default()
+	}
+
+	/// Return self with arrays of dicts matched by the value of a key, like "id" or "name". Other arrays are still compared by index.
+	pub fn identity_key(mut self, key:&str) -> Self {
+		self.identity_key = Some(key.to_string());
+		self
+	}
+
+	/// Return self with numbers compared by the text they are written as instead of by value, so changing 1 to 1.0 is a difference.
+	pub fn exact_numbers(mut self) -> Self {
+		self.exact_numbers = true;
+		self
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Get the differences between two values. Paths are JSON Pointers into the old value for removed locations and into the new value otherwise. Reordering of items matched by identity is not a difference.
+	pub fn diff(&self, old:&Json, new:&Json) -> Vec<JsonDifference> {
+		let mut differences:Vec<JsonDifference> = Vec::new();
+		self.diff_into(old, new, &mut Vec::new(), &mut differences);
+		differences
+	}
+
+	/// Merge the changes of two values made since a common base. Changes made on one side are applied, changes made on both sides are merged recursively when possible and reported as conflict otherwise. Paths are JSON Pointers into the merged value.
+	pub fn merge(&self, base:&Json, ours:&Json, theirs:&Json) -> JsonMerge {
+		let mut conflicts:Vec<JsonConflict> = Vec::new();
+		let value:Json = self.merge_into(Some(base), Some(ours), Some(theirs), &mut Vec::new(), &mut conflicts).unwrap_or(Json::Null);
+		JsonMerge { value, conflicts }
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Add the differences between two values at a location.
+	fn diff_into(&self, old:&Json, new:&Json, path:&mut Vec<String>, differences:&mut Vec<JsonDifference>) {
+		if self.same(old, new) {
+			return;
+		}
+		let difference = |path:&[String], kind:JsonDifferenceKind, old:Option<&Json>, new:Option<&Json>| JsonDifference { path: Json::build_pointer(path), kind, old: old.cloned(), new: new.cloned() };
+
+		// Compare dicts and arrays matched by identity by their keys.
+		if let (Some((old_is_dict, old_entries)), Some((new_is_dict, new_entries))) = (self.entries(old), self.entries(new)) {
+			if old_is_dict == new_is_dict {
+				let old_values:HashMap<&str, &Json> = old_entries.iter().map(|(key, value)| (key.as_str(), *value)).collect();
+				let new_keys:HashMap<&str, &Json> = new_entries.iter().map(|(key, value)| (key.as_str(), *value)).collect();
+				for (index, (key, old_value)) in old_entries.iter().enumerate() {
+					if !new_keys.contains_key(key.as_str()) {
+						path.push(if old_is_dict { key.clone() } else { index.to_string() });
+						differences.push(difference(path, JsonDifferenceKind::Removed, Some(old_value), None));
+						path.pop();
+					}
+				}
+				for (index, (key, new_value)) in new_entries.iter().enumerate() {
+					path.push(if new_is_dict { key.clone() } else { index.to_string() });
+					match old_values.get(key.as_str()) {
+						Some(old_value) => self.diff_into(old_value, new_value, path, differences),
+						None => differences.push(difference(path, JsonDifferenceKind::Added, None, Some(new_value)))
+					}
+					path.pop();
+				}
+				return;
+			}
+		}
+
+		// Compare other arrays by index.
+		if let (Json::Array(old_items), Json::Array(new_items)) = (old, new) {
+			for index in 0..old_items.len().max(new_items.len()) {
+				path.push(index.to_string());
+				match (old_items.get(index), new_items.get(index)) {
+					(Some(old_item), Some(new_item)) => self.diff_into(old_item, new_item, path, differences),
+					(Some(old_item), None) => differences.push(difference(path, JsonDifferenceKind::Removed, Some(old_item), None)),
+					(None, new_item) => differences.push(difference(path, JsonDifferenceKind::Added, None, new_item))
+				}
+				path.pop();
+			}
+			return;
+		}
+		let kind:JsonDifferenceKind = if type_name(old) == type_name(new) { JsonDifferenceKind::Changed } else { JsonDifferenceKind::TypeChanged };
+		differences.push(difference(path, kind, Some(old), Some(new)));
+	}
+
+	/// Merge the values at a location. Returns None when the location should not exist in the merged value.
+	fn merge_into(&self, base:Option<&Json>, ours:Option<&Json>, theirs:Option<&Json>, path:&mut Vec<String>, conflicts:&mut Vec<JsonConflict>) -> Option<Json> {
+		let same = |left:Option<&Json>, right:Option<&Json>| match (left, right) {
+			(Some(left), Some(right)) => self.same(left, right),
+			(left, right) => left.is_none() && right.is_none()
+		};
+		if same(ours, theirs) || same(base, theirs) {
+			return ours.cloned();
+		}
+		if same(base, ours) {
+			return theirs.cloned();
+		}
+
+		// Both sides changed, merge dicts and arrays matched by identity by their keys.
+		if let (Some((ours_is_dict, ours_entries)), Some((theirs_is_dict, theirs_entries))) = (ours.and_then(|ours| self.entries(ours)), theirs.and_then(|theirs| self.entries(theirs))) {
+			if ours_is_dict == theirs_is_dict {
+				let base_entries:Vec<(String, &Json)> = base.and_then(|base| self.entries(base)).filter(|(base_is_dict, _)| *base_is_dict == ours_is_dict).map(|(_, entries)| entries).unwrap_or_default();
+				let base_values:HashMap<&str, &Json> = base_entries.iter().map(|(key, value)| (key.as_str(), *value)).collect();
+				let ours_values:HashMap<&str, &Json> = ours_entries.iter().map(|(key, value)| (key.as_str(), *value)).collect();
+				let theirs_values:HashMap<&str, &Json> = theirs_entries.iter().map(|(key, value)| (key.as_str(), *value)).collect();
+				let keys = ours_entries.iter().chain(theirs_entries.iter().filter(|(key, _)| !ours_values.contains_key(key.as_str()))).map(|(key, _)| key.as_str());
+				let mut merged:Vec<(String, Json)> = Vec::new();
+				for key in keys {
+					path.push(if ours_is_dict { key.to_string() } else { merged.len().to_string() });
+					if let Some(value) = self.merge_into(base_values.get(key).copied(), ours_values.get(key).copied(), theirs_values.get(key).copied(), path, conflicts) {
+						merged.push((key.to_string(), value));
+					}
+					path.pop();
+				}
+				return Some(match ours_is_dict {
+					true => Json::Dict(merged.into_iter().map(|(key, value)| (Json::String(key), value)).collect()),
+					false => Json::Array(merged.into_iter().map(|(_, value)| value).collect())
+				});
+			}
+		}
+
+		// Merge other arrays by index, if none of them changed length.
+		if let (Some(Json::Array(base_items)), Some(Json::Array(ours_items)), Some(Json::Array(theirs_items))) = (base, ours, theirs) {
+			if base_items.len() == ours_items.len() && ours_items.len() == theirs_items.len() {
+				let mut merged:Vec<Json> = Vec::new();
+				for index in 0..base_items.len() {
+					path.push(index.to_string());
+					merged.extend(self.merge_into(base_items.get(index), ours_items.get(index), theirs_items.get(index), path, conflicts));
+					path.pop();
+				}
+				return Some(Json::Array(merged));
+			}
+		}
+		conflicts.push(JsonConflict { path: Json::build_pointer(path), base: base.cloned(), ours: ours.cloned(), theirs: theirs.cloned() });
+		ours.cloned()
+	}
+
+	/// Whether or not two values are the same. Numbers are compared by value, or by the text they are written as when comparing exact numbers.
+	fn same(&self, left:&Json, right:&Json) -> bool {
+		if !self.exact_numbers {
+			return left.equals(right);
+		}
+		match (left, right) {
+			(Json::Dict(entries), Json::Dict(other_entries)) => entries.len() == other_entries.len() && entries.iter().all(|(key, value)| other_entries.iter().any(|(other_key, other_value)| key == other_key && self.same(value, other_value))),
+			(Json::Array(items), Json::Array(other_items)) => items.len() == other_items.len() && items.iter().zip(other_items).all(|(item, other_item)| self.same(item, other_item)),
+			(Json::Integer(_) | Json::Float(_) | Json::Number(_), Json::Integer(_) | Json::Float(_) | Json::Number(_)) => left.equals(right) && left.to_string() == right.to_string(),
+			_ => left == right
+		}
+	}
+
+	/// Get the entries of a dict, or of an array matched by identity. Returns whether the value is a dict, and the entries by key.
+	fn entries<'a>(&self, json:&'a Json) -> Option<(bool, Vec<(String, &'a Json)>)> {
+		match json {
+			Json::Dict(entries) => Some((true, entries.iter().map(|(key, value)| (Json::key_string(key), value)).collect())),
+			Json::Array(items) => {
+				let identity_key:&str = self.identity_key.as_deref()?;
+				let entries:Vec<(String, &Json)> = items.iter().map(|item| item.as_object().and(item.get(identity_key)).map(|identity| (identity.to_string(), item))).collect::<Option<_>>()?;
+				let mut identities:Vec<&str> = entries.iter().map(|(identity, _)| identity.as_str()).collect();
+				identities.sort_unstable();
+				identities.dedup();
+				if identities.len() != entries.len() {
+					return None;
+				}
+				Some((false, entries))
+			},
+			_ => None
+		}
+	}
+}
+
+
+
+/// Get the name of the type of a value. All numbers share a type.
+fn type_name(json:&Json) -> &'static str {
+	match json {
+		Json::Dict(_) => "dict",
+		Json::Array(_) => "array",
+		Json::String(_) => "string",
+		Json::Integer(_) | Json::Float(_) | Json::Number(_) => "number",
+		Json::Bool(_) => "bool",
+		Json::Null => "null"
+	}
+}
+
+/// Get a value as string, or "missing" if it does not exist.
+fn optional_string(json:&Option<Json>) -> String {
+	json.as_ref().map(|json| json.to_string()).unwrap_or_else(|| "missing".to_string())
+}
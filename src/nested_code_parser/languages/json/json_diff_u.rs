@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ json, Json, JsonDiff, JsonDifference, JsonDifferenceKind, JsonMerge };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn differences(diff:&JsonDiff, old:Json, new:Json) -> Vec<String> {
+		diff.diff(&old, &new).iter().map(|difference| difference.to_string()).collect()
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_diff() {
+		let old:Json = json!({ "name": "api", "port": 80, "debug": false, "hosts": ["a", "b"], "limits": { "cpu": 1 } });
+		let new:Json = json!({ "name": "api", "port": "80", "hosts": ["a", "c", "d"], "limits": { "cpu": 1.0, "memory": 512 }, "tls": true });
+		assert_eq!(differences(&JsonDiff::new(), old.clone(), new.clone()), vec![
+			"removed '/debug': false",
+			"changed type of '/port' from number to string: 80 -> \"80\"",
+			"changed '/hosts/1': \"b\" -> \"c\"",
+			"added '/hosts/2': \"d\"",
+			"added '/limits/memory': 512",
+			"added '/tls': true"
+		]);
+		let difference:JsonDifference = JsonDiff::new().diff(&old, &new).remove(1);
+		assert_eq!((difference.path.as_str(), difference.kind), ("/port", JsonDifferenceKind::TypeChanged));
+		assert_eq!((difference.old, difference.new), (Some(json!(80)), Some(json!("80"))));
+		assert!(JsonDiff::new().diff(&old, &old).is_empty());
+	}
+
+	#[test]
+	fn test_diff_by_identity() {
+		let old:Json = json!({ "servers": [{ "id": "a", "port": 1 }, { "id": "b", "port": 2 }, { "id": "c", "port": 3 }] });
+		let new:Json = json!({ "servers": [{ "id": "c", "port": 3 }, { "id": "a", "port": 10 }, { "id": "d", "port": 4 }] });
+		assert_eq!(differences(&JsonDiff::new().identity_key("id"), old.clone(), new.clone()), vec![
+			"removed '/servers/1': {\"id\":\"b\",\"port\":2}",
+			"changed '/servers/1/port': 1 -> 10",
+			"added '/servers/2': {\"id\":\"d\",\"port\":4}"
+		]);
+		assert_eq!(JsonDiff::new().diff(&old, &new).len(), 6);
+
+		// Arrays without unique identities are compared by index.
+		assert_eq!(differences(&JsonDiff::new().identity_key("id"), json!([{ "id": 1 }, { "id": 1 }]), json!([{ "id": 1 }, { "id": 2 }])), vec!["changed '/1/id': 1 -> 2"]);
+	}
+
+	#[test]
+	fn test_diff_exact_numbers() {
+		let old:Json = json!({ "a": 1, "b": [1.5, 2] });
+		let new:Json = json!({ "b": [1.5, 2.0], "a": 1 });
+		assert!(JsonDiff::new().diff(&old, &new).is_empty());
+		assert_eq!(differences(&JsonDiff::new().exact_numbers(), old.clone(), new.clone()), vec!["changed '/b/1': 2 -> 2.0"]);
+		assert!(JsonDiff::new().exact_numbers().diff(&old, &old).is_empty());
+		assert_eq!(JsonDiff::new().exact_numbers().merge(&old, &old, &new).value, new);
+	}
+
+	#[test]
+	fn test_merge() {
+		let base:Json = json!({ "name": "api", "port": 80, "replicas": 1, "hosts": ["a", "b"], "env": { "LOG": "info" } });
+		let ours:Json = json!({ "name": "api", "port": 8080, "replicas": 2, "hosts": ["a", "b"], "env": { "LOG": "debug" } });
+		let theirs:Json = json!({ "name": "api-v2", "port": 80, "replicas": 3, "hosts": ["a", "b"], "env": { "LOG": "info", "TZ": "UTC" }, "tls": true });
+		let merge:JsonMerge = JsonDiff::new().merge(&base, &ours, &theirs);
+		assert_eq!(merge.value, json!({ "name": "api-v2", "port": 8080, "replicas": 2, "hosts": ["a", "b"], "env": { "LOG": "debug", "TZ": "UTC" }, "tls": true }));
+		assert!(!merge.is_clean());
+		assert_eq!(merge.conflicts.iter().map(|conflict| conflict.to_string()).collect::<Vec<String>>(), vec!["conflict at '/replicas': base 1, ours 2, theirs 3"]);
+
+		// Removed on one side and changed on the other.
+		let merge:JsonMerge = JsonDiff::new().merge(&json!({ "a": 1, "b": 1 }), &json!({ "b": 1 }), &json!({ "a": 2, "b": 2 }));
+		assert_eq!(merge.value, json!({ "b": 2 }));
+		assert_eq!(merge.conflicts[0].to_string(), "conflict at '/a': base 1, ours missing, theirs 2");
+		assert!(JsonDiff::new().merge(&json!([1, 2]), &json!([1, 2, 3]), &json!([0, 2])).conflicts.len() == 1);
+	}
+
+	#[test]
+	fn test_merge_by_identity() {
+		let base:Json = json!([{ "name": "web", "port": 80 }, { "name": "db", "port": 5432 }]);
+		let ours:Json = json!([{ "name": "web", "port": 8080 }, { "name": "db", "port": 5432 }, { "name": "cache", "port": 6379 }]);
+		let theirs:Json = json!([{ "name": "web", "port": 80, "tls": true }]);
+		let merge:JsonMerge = JsonDiff::new().identity_key("name").merge(&base, &ours, &theirs);
+		assert!(merge.is_clean());
+		assert_eq!(merge.value, json!([{ "name": "web", "port": 8080, "tls": true }, { "name": "cache", "port": 6379 }]));
+	}
+}
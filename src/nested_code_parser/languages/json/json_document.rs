@@ -0,0 +1,291 @@
+use super::{ json::{ ARRAY_NAME, COMMENT_NAME, DICT_NAME, LIST_DIVIDER_NAME, STRING_NAME }, json5_parser, Json, JsonError, JsonFlavor, JsonReader };
+use crate::NestedSegment;
+use std::{ error::Error, fmt::{ self, Display, Formatter } };
+
+
+
+const DEFAULT_SEPARATOR:&str = " ";
+
+
+
+/// An editable JSON, JSONC or JSON5 document backed by its lossless parse tree. Edits only rewrite the affected tokens, so comments, key order and white-space are kept.
+pub struct JsonDocument {
+	tree:NestedSegment
+}
+impl JsonDocument {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new document from JSON, JSONC or JSON5 contents. The contents are parsed once, the tree the document keeps is validated like reading JSON5 would.
+	pub fn new(contents:&str) -> Result<JsonDocument, JsonError> {
+		JsonFlavor::Json5.validate_nesting_depth(contents)?;
+		let tree:NestedSegment = json5_parser().parse(contents);
+		JsonReader::new(JsonFlavor::Json5).read_parsed(contents, &tree)?;
+		Ok(JsonDocument { tree })
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the value of the whole document. Numbers are kept as the exact text they were written as. Fails if the edited tree can not be parsed as json.
+	pub fn value(&self) -> Result<Json, JsonError> {
+		Json::parse_lossless(&self.tree.to_string(), JsonFlavor::Json5)
+	}
+
+	/// Get the value at the location of an RFC 6901 JSON Pointer. Numbers are kept as the exact text they were written as.
+	pub fn get(&self, pointer:&str) -> Option<Json> {
+		Json::parse_lossless(&self.source(pointer)?, JsonFlavor::Json5).ok()
+	}
+
+	/// Get the source text of the value at the location of an RFC 6901 JSON Pointer, exactly as written. Strings keep their quotes and escape sequences. Useful for tooling that needs to reproduce the source exactly.
+	pub fn source(&self, pointer:&str) -> Option<String> {
+		let path:Vec<usize> = self.locate(&Json::parse_pointer(pointer).ok()?)?;
+		Some(self.segment_at(&path).to_string())
+	}
+
+
+
+	/* MODIFICATION METHODS */
+
+	/// Get an editing handle for a dict key or array index in the root, for chained edits like `document.at("font").at("size").set(14)`.
+	pub fn at<T:Display>(&mut self, key:T) -> JsonDocumentEntry<'_> {
+		JsonDocumentEntry { document: self, tokens: vec![key.to_string()] }
+	}
+
+	/// Set the value at the location of an RFC 6901 JSON Pointer. Existing values are replaced in place, new dict keys and array items appended with '-' are added after the last entry, using the layout of the existing entries.
+	pub fn set(&mut self, pointer:&str, value:&Json) -> Result<(), Box<dyn Error>> {
+		let mut tokens:Vec<String> = Json::parse_pointer(pointer)?;
+
+		// Replace existing value.
+		if let Some(mut path) = self.locate(&tokens) {
+			let value_index:usize = path.pop().unwrap();
+			self.segment_at_mut(&path).sub_segments_mut().splice(value_index..value_index + 1, parse_snippet(&value.to_string()));
+			return Ok(());
+		}
+
+		// Add new value to parent.
+		let last_token:String = tokens.pop().unwrap();
+		let parent_path:Vec<usize> = self.locate(&tokens).ok_or(format!("Could not set value at json pointer '{pointer}', its parent does not exist."))?;
+		let parent:&mut NestedSegment = self.segment_at_mut(&parent_path);
+		let entries:Vec<Entry> = entries(parent);
+		match parent.type_name() {
+			DICT_NAME => {
+				let key:String = Json::escape_string(&last_token);
+				let (before_colon, after_colon) = entries.last().and_then(|entry| entry.colon).map(|colon_index| (
+					whitespace_at(parent, colon_index.checked_sub(1)),
+					whitespace_at(parent, Some(colon_index + 1))
+				)).unwrap_or((String::new(), DEFAULT_SEPARATOR.to_string()));
+				append_entry(parent, &entries, &format!("{key}{before_colon}:{after_colon}{value}"));
+			},
+			ARRAY_NAME if last_token == "-" || Json::pointer_array_index(&last_token) == Some(entries.len()) => {
+				append_entry(parent, &entries, &value.to_string());
+			},
+			ARRAY_NAME => return Err(format!("Could not set value at json pointer '{pointer}', index out of bounds.").into()),
+			_ => return Err(format!("Could not set value at json pointer '{pointer}', the parent is not a dict or array.").into())
+		}
+		Ok(())
+	}
+
+	/// Remove the value at the location of an RFC 6901 JSON Pointer, together with its key and one adjacent comma.
+	pub fn remove(&mut self, pointer:&str) -> Result<(), Box<dyn Error>> {
+		let tokens:Vec<String> = Json::parse_pointer(pointer)?;
+		let mut path:Vec<usize> = self.locate(&tokens).ok_or(format!("Could not remove value at json pointer '{pointer}', it does not exist."))?;
+		if tokens.is_empty() {
+			return Err("Could not remove the root of a json document.".into());
+		}
+		let value_index:usize = path.pop().unwrap();
+		let parent:&mut NestedSegment = self.segment_at_mut(&path);
+		let entry:Entry = entries(parent).into_iter().find(|entry| entry.value == value_index).unwrap();
+
+		// Find the range to remove. Prefers the comma after the entry, so the white-space after the previous entry stays as it is. Only one side of white-space is removed.
+		let sub_segments:&[NestedSegment] = parent.sub_segments();
+		let mut start:usize = entry.start();
+		if start > 0 && sub_segments[start - 1].is_whitespace() {
+			start -= 1;
+		}
+		let end:usize = match entry.comma {
+			Some(comma_index) if start == entry.start() && sub_segments.get(comma_index + 1).map(|segment| segment.is_whitespace()).unwrap_or(false) => comma_index + 1,
+			Some(comma_index) => comma_index,
+			None => {
+				if let Some(previous_comma) = (0..start).rev().find(|index| is_significant(&sub_segments[*index])).filter(|index| sub_segments[*index].type_name() == LIST_DIVIDER_NAME) {
+					start = previous_comma;
+				}
+				value_index
+			}
+		};
+		parent.sub_segments_mut().drain(start..=end);
+		Ok(())
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Find the path of child indexes from the root of the tree to the value the reference tokens point to.
+	fn locate(&self, tokens:&[String]) -> Option<Vec<usize>> {
+		let mut path:Vec<usize> = vec![self.tree.sub_segments().iter().position(is_significant)?];
+		for token in tokens {
+			let container:&NestedSegment = self.segment_at(&path);
+			let entry:Entry = match container.type_name() {
+				DICT_NAME => entries(container).into_iter().find(|entry| entry.key.map(|key_index| key_name(&container.sub_segments()[key_index]) == *token).unwrap_or(false))?,
+				ARRAY_NAME => entries(container).into_iter().nth(Json::pointer_array_index(token)?)?,
+				_ => return None
+			};
+			path.push(entry.value);
+		}
+		Some(path)
+	}
+
+	/// Get the segment at a path of child indexes.
+	fn segment_at(&self, path:&[usize]) -> &NestedSegment {
+		path.iter().fold(&self.tree, |segment, index| &segment.sub_segments()[*index])
+	}
+
+	/// Get the mutable segment at a path of child indexes.
+	fn segment_at_mut(&mut self, path:&[usize]) -> &mut NestedSegment {
+		path.iter().fold(&mut self.tree, |segment, index| &mut segment.sub_segments_mut()[*index])
+	}
+}
+impl Display for JsonDocument {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.tree.to_string())
+	}
+}
+
+
+
+/// An editing handle for a location in a JsonDocument. Edits through the handle rewrite only the affected tokens, like the pointer based methods of the document.
+pub struct JsonDocumentEntry<'a> {
+	document:&'a mut JsonDocument,
+	tokens:Vec<String>
+}
+impl<'a> JsonDocumentEntry<'a> {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the RFC 6901 JSON Pointer of the location.
+	pub fn pointer(&self) -> String {
+		Json::build_pointer(&self.tokens)
+	}
+
+	/// Get the value at the location.
+	pub fn get(&self) -> Option<Json> {
+		self.document.get(&self.pointer())
+	}
+
+
+
+	/* MODIFICATION METHODS */
+
+	/// Get a handle for a dict key or array index within the location.
+	pub fn at<T:Display>(mut self, key:T) -> Self {
+		self.tokens.push(key.to_string());
+		self
+	}
+
+	/// Set the value at the location. Works like `JsonDocument::set`.
+	pub fn set<T:Into<Json>>(self, value:T) -> Result<(), Box<dyn Error>> {
+		let pointer:String = self.pointer();
+		self.document.set(&pointer, &value.into())
+	}
+
+	/// Remove the value at the location. Works like `JsonDocument::remove`.
+	pub fn remove(self) -> Result<(), Box<dyn Error>> {
+		let pointer:String = self.pointer();
+		self.document.remove(&pointer)
+	}
+}
+
+
+
+/// The child indexes of an entry in a parsed dict or array.
+struct Entry {
+	key:Option<usize>,
+	colon:Option<usize>,
+	value:usize,
+	comma:Option<usize>
+}
+impl Entry {
+
+	/// Get the index of the first segment of the entry.
+	fn start(&self) -> usize {
+		self.key.unwrap_or(self.value)
+	}
+}
+
+/// Whether or not a segment is part of the json structure, so not white-space or a comment.
+fn is_significant(segment:&NestedSegment) -> bool {
+	!segment.is_whitespace() && segment.type_name() != COMMENT_NAME
+}
+
+/// Get the entries of a parsed dict or array. Expects the structure to be validated.
+fn entries(container:&NestedSegment) -> Vec<Entry> {
+	let significant:Vec<usize> = container.sub_segments().iter().enumerate().filter(|(_, segment)| is_significant(segment)).map(|(index, _)| index).collect();
+	let is_dict:bool = container.type_name() == DICT_NAME;
+	let mut entries:Vec<Entry> = Vec::new();
+	let mut cursor:usize = 0;
+	while cursor < significant.len() {
+		let (key, colon) = if is_dict { cursor += 2; (Some(significant[cursor - 2]), Some(significant[cursor - 1])) } else { (None, None) };
+		let value:usize = significant[cursor];
+		let comma:Option<usize> = significant.get(cursor + 1).copied().filter(|index| container.sub_segments()[*index].type_name() == LIST_DIVIDER_NAME);
+		entries.push(Entry { key, colon, value, comma });
+		cursor += 2;
+	}
+	entries
+}
+
+/// Get the name of a parsed dict key.
+fn key_name(key:&NestedSegment) -> String {
+	if key.type_name() == STRING_NAME {
+		if let Some(name) = Json::new_json5(&key.to_string()).ok().as_ref().and_then(|name| name.as_str()) {
+			return name.to_string();
+		}
+	}
+	key.to_string()
+}
+
+/// Get the source of a white-space child of a container, or nothing if the child is not white-space.
+fn whitespace_at(container:&NestedSegment, index:Option<usize>) -> String {
+	index.and_then(|index| container.sub_segments().get(index)).filter(|segment| segment.is_whitespace()).map(|segment| segment.to_string()).unwrap_or_default()
+}
+
+/// Get the white-space directly before a child of a container. A line comment before the white-space holds the line break, so it is added.
+fn separator_before(container:&NestedSegment, index:usize) -> Option<String> {
+	let sub_segments:&[NestedSegment] = container.sub_segments();
+	let mut separator:String = String::new();
+	let mut cursor:usize = index;
+	if let Some(whitespace) = cursor.checked_sub(1).map(|index| &sub_segments[index]).filter(|segment| segment.is_whitespace()) {
+		separator = whitespace.to_string();
+		cursor -= 1;
+	}
+	if cursor.checked_sub(1).map(|index| &sub_segments[index]).filter(|segment| segment.type_name() == COMMENT_NAME && segment.to_string().ends_with('\n')).is_some() {
+		separator.insert(0, '\n');
+	}
+	Some(separator).filter(|separator| !separator.is_empty())
+}
+
+/// Parse a snippet of json into segments.
+fn parse_snippet(snippet:&str) -> Vec<NestedSegment> {
+	match json5_parser().parse(snippet) {
+		NestedSegment::Code(_, code) => code.sub_segments,
+		segment => vec![segment]
+	}
+}
+
+/// Add an entry after the last entry of a container, separated the same way as the existing entries.
+fn append_entry(container:&mut NestedSegment, entries:&[Entry], entry_source:&str) {
+	match entries.last() {
+		None => {
+			container.sub_segments_mut().splice(0..0, parse_snippet(entry_source));
+		},
+		Some(last_entry) => {
+			let separator:String = separator_before(container, entries[0].start()).unwrap_or(DEFAULT_SEPARATOR.to_string());
+			let (index, snippet) = match last_entry.comma {
+				Some(comma_index) => (comma_index + 1, format!("{separator}{entry_source},")),
+				None => (last_entry.value + 1, format!(",{separator}{entry_source}"))
+			};
+			container.sub_segments_mut().splice(index..index, parse_snippet(&snippet));
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonDocument, JsonError, JsonFlavor, JsonNumber };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	const SETTINGS:&str = "// Editor settings\n{\n\t\"theme\": \"dark\", // or 'light'\n\t/* sizes */\n\t\"font\": { \"size\": 12, \"family\": \"mono\" },\n\t\"plugins\": [\"git\", \"lint\"]\n}\n";
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_unchanged_round_trip() {
+		let document:JsonDocument = JsonDocument::new(SETTINGS).unwrap();
+		assert_eq!(document.to_string(), SETTINGS);
+		assert_eq!(document.get("/font/size"), Some(Json::Number(JsonNumber::new("12").unwrap())));
+		assert_eq!(document.get("/plugins/1"), Some(Json::String("lint".to_string())));
+		assert_eq!(document.get("/missing"), None);
+		assert_eq!(document.source("/font"), Some("{ \"size\": 12, \"family\": \"mono\" }".to_string()));
+		assert_eq!(document.value().unwrap()["theme"], Json::String("dark".to_string()));
+		assert!(JsonDocument::new("{\"a\": }").is_err());
+		assert_eq!(JsonDocument::new("[12345678901234567890, 0.10]").unwrap().value().unwrap().to_string(), "[12345678901234567890,0.10]");
+	}
+
+	#[test]
+	fn test_source() {
+		let document:JsonDocument = JsonDocument::new(r#"{"a\n": 'b\u00e9', "c": "\"d\""}"#).unwrap();
+		assert_eq!(document.source("/a\n"), Some(r#"'b\u00e9'"#.to_string()));
+		assert_eq!(document.get("/a\n"), Some(Json::String("bé".to_string())));
+		assert_eq!(document.source("/c"), Some(r#""\"d\"""#.to_string()));
+		assert_eq!(document.get("/c").unwrap().to_string(), r#""\"d\"""#);
+	}
+
+	#[test]
+	fn test_replace_value() {
+		let mut document:JsonDocument = JsonDocument::new(SETTINGS).unwrap();
+		document.set("/font/size", &Json::Integer(14)).unwrap();
+		document.set("/theme", &Json::String("light".to_string())).unwrap();
+		assert_eq!(document.to_string(), SETTINGS.replace("12", "14").replace("\"dark\"", "\"light\""));
+	}
+
+	#[test]
+	fn test_entry_handle() {
+		let mut document:JsonDocument = JsonDocument::new(SETTINGS).unwrap();
+		document.at("font").at("size").set(14).unwrap();
+		document.at("plugins").at(0).set("format").unwrap();
+		document.at("font").at("family").remove().unwrap();
+		assert_eq!(document.at("plugins").at(1).pointer(), "/plugins/1");
+		assert_eq!(document.at("plugins").at(1).get(), Some(Json::String("lint".to_string())));
+		assert_eq!(document.to_string(), SETTINGS.replace("12", "14").replace("\"git\"", "\"format\"").replace(", \"family\": \"mono\"", ""));
+		assert!(document.at("missing").at("key").set(true).is_err());
+		assert_eq!(document.value().unwrap()["font"]["size"], Json::Number(JsonNumber::new("14").unwrap()));
+	}
+
+	#[test]
+	fn test_add_value() {
+		let mut document:JsonDocument = JsonDocument::new(SETTINGS).unwrap();
+		document.set("/font/bold", &Json::Bool(true)).unwrap();
+		document.set("/plugins/-", &Json::String("format".to_string())).unwrap();
+		document.set("/plugins/0", &Json::Null).unwrap();
+		document.set("/tabs", &Json::Integer(4)).unwrap();
+		let expected:&str = "// Editor settings\n{\n\t\"theme\": \"dark\", // or 'light'\n\t/* sizes */\n\t\"font\": { \"size\": 12, \"family\": \"mono\", \"bold\": true },\n\t\"plugins\": [null, \"lint\", \"format\"],\n\t\"tabs\": 4\n}\n";
+		assert_eq!(document.to_string(), expected);
+		assert!(document.set("/missing/key", &Json::Null).is_err());
+		assert!(document.set("/plugins/9", &Json::Null).is_err());
+
+		// A line comment before the first entry holds the line break.
+		let mut document:JsonDocument = JsonDocument::new("{\n\t// Comment\n\t\"a\": 1\n}").unwrap();
+		document.set("/b", &Json::Integer(2)).unwrap();
+		assert_eq!(document.to_string(), "{\n\t// Comment\n\t\"a\": 1,\n\t\"b\": 2\n}");
+
+		// The spacing around the colon of the last entry is copied.
+		let mut document:JsonDocument = JsonDocument::new("{\"a\" : 1}").unwrap();
+		document.set("/b", &Json::Integer(2)).unwrap();
+		assert_eq!(document.to_string(), "{\"a\" : 1, \"b\" : 2}");
+		let mut document:JsonDocument = JsonDocument::new("{\"a\" /* key */ :1}").unwrap();
+		document.set("/b", &Json::Integer(2)).unwrap();
+		assert_eq!(document.to_string(), "{\"a\" /* key */ :1, \"b\" :2}");
+	}
+
+	#[test]
+	fn test_new_rejects_invalid_input() {
+		assert!(matches!(JsonDocument::new("{} garbage"), Err(JsonError::TrailingContents(_))));
+		assert!(matches!(JsonDocument::new("[1] // comment\n]"), Err(JsonError::TrailingContents(_))));
+		assert!(JsonDocument::new("[01]").is_err());
+		assert!(matches!(JsonDocument::new(&"[".repeat(100000)), Err(JsonError::NestingTooDeep(_))));
+		assert_eq!(JsonDocument::new(" // comment\n{}\n").unwrap().to_string(), " // comment\n{}\n");
+	}
+
+	#[test]
+	fn test_add_to_empty_and_trailing_comma() {
+		let mut document:JsonDocument = JsonDocument::new("{\n\t\"list\": [],\n\t\"dict\": {},\n}").unwrap();
+		document.set("/list/0", &Json::Integer(1)).unwrap();
+		document.set("/dict/a", &Json::Integer(2)).unwrap();
+		document.set("/added", &Json::Integer(3)).unwrap();
+		assert_eq!(document.to_string(), "{\n\t\"list\": [1],\n\t\"dict\": {\"a\": 2},\n\t\"added\": 3,\n}");
+	}
+
+	#[test]
+	fn test_remove_value() {
+		let mut document:JsonDocument = JsonDocument::new(SETTINGS).unwrap();
+		document.remove("/font/family").unwrap();
+		document.remove("/plugins/0").unwrap();
+		document.remove("/theme").unwrap();
+		let expected:&str = "// Editor settings\n{ // or 'light'\n\t/* sizes */\n\t\"font\": { \"size\": 12 },\n\t\"plugins\": [\"lint\"]\n}\n";
+		assert_eq!(document.to_string(), expected);
+		assert!(document.remove("/theme").is_err());
+		assert!(document.remove("").is_err());
+		assert_eq!(document.value().unwrap(), Json::parse_lossless(expected, JsonFlavor::Json5).unwrap());
+	}
+}
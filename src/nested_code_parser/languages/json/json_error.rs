@@ -0,0 +1,135 @@
+use std::{ error::Error, fmt::{ self, Display, Formatter } };
+
+
+
+const EXCERPT_RADIUS:usize = 20;
+
+
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct JsonPosition {
+	pub offset:usize,
+	pub line:usize,
+	pub column:usize,
+	pub excerpt:String
+}
+impl JsonPosition {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new position from a byte offset in the source. Lines and columns start at 1, columns count characters. The excerpt is the part of the line around the offset.
+	pub fn new(source:&str, offset:usize) -> JsonPosition {
+		let offset:usize = (0..=offset.min(source.len())).rev().find(|offset| source.is_char_boundary(*offset)).unwrap_or(0);
+		let line_start:usize = source[..offset].rfind('\n').map(|index| index + 1).unwrap_or(0);
+		let line_end:usize = source[offset..].find('\n').map(|index| offset + index).unwrap_or(source.len());
+		let before:Vec<char> = source[line_start..offset].chars().collect();
+		let after:String = source[offset..line_end].chars().take(EXCERPT_RADIUS).collect();
+		JsonPosition {
+			offset,
+			line: source[..offset].matches('\n').count() + 1,
+			column: before.len() + 1,
+			excerpt: before[before.len().saturating_sub(EXCERPT_RADIUS)..].iter().collect::<String>() + &after
+		}
+	}
+}
+
+
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum JsonError {
+	Empty(JsonPosition),
+	UnexpectedToken(JsonPosition),
+	TrailingContents(JsonPosition),
+	MissingColon(JsonPosition),
+	MissingComma(JsonPosition),
+	MissingValue(JsonPosition),
+	TrailingComma(JsonPosition),
+	InvalidKey(JsonPosition),
+	DuplicateKey(JsonPosition),
+	InvalidNumber(JsonPosition),
+	InvalidWhiteSpace(JsonPosition),
+	InvalidEscape(JsonPosition),
+	ControlCharacter(JsonPosition),
+	UnterminatedString(JsonPosition),
+	UnterminatedDict(JsonPosition),
+	UnterminatedArray(JsonPosition),
+	UnterminatedComment(JsonPosition),
+	NestingTooDeep(JsonPosition),
+	InvalidEncoding(JsonPosition),
+	ReadFailed(JsonPosition)
+}
+impl JsonError {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the position in the source where the error was found.
+	pub fn position(&self) -> &JsonPosition {
+		match self {
+			JsonError::Empty(position) | JsonError::UnexpectedToken(position) | JsonError::TrailingContents(position) |
+			JsonError::MissingColon(position) | JsonError::MissingComma(position) | JsonError::MissingValue(position) |
+			JsonError::TrailingComma(position) | JsonError::InvalidKey(position) | JsonError::DuplicateKey(position) | JsonError::InvalidNumber(position) |
+			JsonError::InvalidWhiteSpace(position) | JsonError::InvalidEscape(position) | JsonError::ControlCharacter(position) |
+			JsonError::UnterminatedString(position) | JsonError::UnterminatedDict(position) | JsonError::UnterminatedArray(position) |
+			JsonError::UnterminatedComment(position) | JsonError::NestingTooDeep(position) | JsonError::InvalidEncoding(position) | JsonError::ReadFailed(position) => position
+		}
+	}
+
+	/// Get the mutable position of the error, used to place errors from a part of a larger source.
+	pub(super) fn position_mut(&mut self) -> &mut JsonPosition {
+		match self {
+			JsonError::Empty(position) | JsonError::UnexpectedToken(position) | JsonError::TrailingContents(position) |
+			JsonError::MissingColon(position) | JsonError::MissingComma(position) | JsonError::MissingValue(position) |
+			JsonError::TrailingComma(position) | JsonError::InvalidKey(position) | JsonError::DuplicateKey(position) | JsonError::InvalidNumber(position) |
+			JsonError::InvalidWhiteSpace(position) | JsonError::InvalidEscape(position) | JsonError::ControlCharacter(position) |
+			JsonError::UnterminatedString(position) | JsonError::UnterminatedDict(position) | JsonError::UnterminatedArray(position) |
+			JsonError::UnterminatedComment(position) | JsonError::NestingTooDeep(position) | JsonError::InvalidEncoding(position) | JsonError::ReadFailed(position) => position
+		}
+	}
+
+	/// Get the line of the error, starting at 1.
+	pub fn line(&self) -> usize {
+		self.position().line
+	}
+
+	/// Get the column of the error in characters, starting at 1.
+	pub fn column(&self) -> usize {
+		self.position().column
+	}
+
+	/// Get the part of the source line around the error.
+	pub fn excerpt(&self) -> &str {
+		&self.position().excerpt
+	}
+
+	/// Get a short description of the kind of error.
+	pub fn description(&self) -> &str {
+		match self {
+			JsonError::Empty(_) => "no json value found",
+			JsonError::UnexpectedToken(_) => "unexpected token",
+			JsonError::TrailingContents(_) => "unexpected contents after json value",
+			JsonError::MissingColon(_) => "expected ':'",
+			JsonError::MissingComma(_) => "expected ','",
+			JsonError::MissingValue(_) => "expected a value",
+			JsonError::TrailingComma(_) => "trailing ','",
+			JsonError::InvalidKey(_) => "keys should be strings",
+			JsonError::DuplicateKey(_) => "duplicate key",
+			JsonError::InvalidNumber(_) => "invalid number",
+			JsonError::InvalidWhiteSpace(_) => "invalid white-space",
+			JsonError::InvalidEscape(_) => "invalid escape sequence",
+			JsonError::ControlCharacter(_) => "unescaped control character in string",
+			JsonError::UnterminatedString(_) => "unterminated string",
+			JsonError::UnterminatedDict(_) => "unterminated dictionary",
+			JsonError::UnterminatedArray(_) => "unterminated array",
+			JsonError::UnterminatedComment(_) => "unterminated comment",
+			JsonError::NestingTooDeep(_) => "dicts and arrays are nested too deep",
+			JsonError::InvalidEncoding(_) => "invalid UTF-8",
+			JsonError::ReadFailed(_) => "could not read input"
+		}
+	}
+}
+impl Display for JsonError {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "Could not parse JSON, {} at line {}, column {}: {:?}", self.description(), self.line(), self.column(), self.excerpt())
+	}
+}
+impl Error for JsonError {}
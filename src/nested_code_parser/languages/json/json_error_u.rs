@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonError, JsonPosition };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn strict_error(contents:&str) -> JsonError {
+		Json::new_strict(contents).unwrap_err()
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_position() {
+		let position:JsonPosition = JsonPosition::new("ab\ncdé\nfg", 5);
+		assert_eq!((position.line, position.column, position.excerpt.as_str()), (2, 3, "cdé"));
+		assert_eq!(JsonPosition::new(&"x".repeat(100), 50).excerpt.len(), 40);
+	}
+
+	#[test]
+	fn test_error_kinds() {
+		assert!(matches!(strict_error(""), JsonError::Empty(_)));
+		assert!(matches!(strict_error("[1] 2"), JsonError::TrailingContents(_)));
+		assert!(matches!(strict_error(r#"{"a" 1}"#), JsonError::MissingColon(_)));
+		assert!(matches!(strict_error(r#"{"a": 1 "b": 2}"#), JsonError::MissingComma(_)));
+		assert!(matches!(strict_error(r#"{"a": }"#), JsonError::MissingValue(_)));
+		assert!(matches!(strict_error(r#"{"a": 1,}"#), JsonError::TrailingComma(_)));
+		assert!(matches!(strict_error(r#"[1, 2,]"#), JsonError::TrailingComma(_)));
+		assert!(matches!(strict_error(r#"[1 2]"#), JsonError::MissingComma(_)));
+		assert!(matches!(strict_error(r#"[,]"#), JsonError::UnexpectedToken(_)));
+		assert!(matches!(strict_error(r#"{1: 2}"#), JsonError::InvalidKey(_)));
+		assert!(matches!(strict_error(r#"["\x"]"#), JsonError::InvalidEscape(_)));
+		assert!(matches!(strict_error("[\"\t\"]"), JsonError::ControlCharacter(_)));
+		assert!(matches!(strict_error("[\u{a0}1]"), JsonError::InvalidWhiteSpace(_)));
+		assert!(matches!(strict_error(r#"["abc"#), JsonError::UnterminatedString(_)));
+		assert!(matches!(strict_error(r#"{"a": [1"#), JsonError::UnterminatedArray(_)));
+		assert!(matches!(strict_error(r#"{"a": 1"#), JsonError::UnterminatedDict(_)));
+	}
+
+	#[test]
+	fn test_error_location() {
+		let error:JsonError = strict_error("{\n\t\"a\": [1, 2],\n\t\"b\": [3 4]\n}");
+		assert!(matches!(error, JsonError::MissingComma(_)));
+		assert_eq!((error.line(), error.column(), error.excerpt()), (3, 10, "\t\"b\": [3 4]"));
+		assert_eq!(error.to_string(), "Could not parse JSON, expected ',' at line 3, column 10: \"\\t\\\"b\\\": [3 4]\"");
+
+		let error:JsonError = Json::new("\n\n  [1, 2,]").unwrap_err();
+		assert_eq!((error.line(), error.column()), (3, 8));
+	}
+
+	#[test]
+	fn test_large_input_excerpt() {
+		let contents:String = format!("[{}\"\\q\"]", "1, ".repeat(100_000));
+		let error:JsonError = strict_error(&contents);
+		assert!(matches!(error, JsonError::InvalidEscape(_)));
+		assert_eq!(error.column(), 300_003);
+		assert!(error.to_string().len() < 200);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,144 @@
+use super::{ Json, JsonDiff, JsonDifference, JsonDifferenceKind, JsonDocument, JsonFlavor, JsonIndex };
+use file_ref::FileRef;
+use std::{ error::Error, fs::{ self, File, OpenOptions }, io::{ self, ErrorKind, Write }, ops::{ Index, IndexMut }, path::Path, process, sync::atomic::{ AtomicUsize, Ordering }, time::{ SystemTime, UNIX_EPOCH } };
+
+
+
+const TEMPORARY_FILE_EXTENSION:&str = "tmp";
+const TEMPORARY_FILE_ATTEMPTS:usize = 16;
+static TEMPORARY_FILE_COUNTER:AtomicUsize = AtomicUsize::new(0);
+
+
+
+/// Json loaded from a file or contents, that can be saved back. By default the original formatting and comments are kept when saving, only rewriting the values that changed.
+pub struct JsonFile {
+	json:Json,
+	document:Option<JsonDocument>,
+	source_file:Option<String>
+}
+impl JsonFile {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new JsonFile from a file.
+	pub fn from_file(path:&str) -> Result<JsonFile, Box<dyn Error>> {
+		let mut json_file:JsonFile = Self::from_contents(&FileRef::new(path).read()?)?;
+		json_file.source_file = Some(path.to_string());
+		Ok(json_file)
+	}
+
+	/// Create a new JsonFile from contents. Contents that are not valid JSON, JSONC or JSON5 are parsed leniently, but can not keep their formatting. Numbers are kept as the exact text they were written as.
+	pub fn from_contents(contents:&str) -> Result<JsonFile, Box<dyn Error>> {
+		Ok(match JsonDocument::new(contents) {
+			Ok(document) => JsonFile { json: document.value()?, document: Some(document), source_file: None },
+			Err(_) => JsonFile { json: Json::parse_lossless(contents, JsonFlavor::Lenient)?, document: None, source_file: None }
+		})
+	}
+
+	/// Return self without the original formatting, saving the json pretty-printed instead.
+	pub fn discard_formatting(mut self) -> Self {
+		self.document = None;
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the json.
+	pub fn json(&self) -> &Json {
+		&self.json
+	}
+
+	/// Get the mutable json.
+	pub fn json_mut(&mut self) -> &mut Json {
+		&mut self.json
+	}
+
+	/// Get the path of the file the json came from, if there is one.
+	pub fn source_file(&self) -> Option<&str> {
+		self.source_file.as_deref()
+	}
+
+	/// Whether or not the original formatting is kept when saving.
+	pub fn keeps_formatting(&self) -> bool {
+		self.document.is_some()
+	}
+
+	/// Get the contents to save. Changes are applied to the original document when keeping formatting.
+	pub fn to_contents(&self) -> Result<String, Box<dyn Error>> {
+		let Some(document) = &self.document else {
+			return Ok(self.json.to_pretty_string() + "\n");
+		};
+		let mut document:JsonDocument = JsonDocument::new(&document.to_string())?;
+		let differences:Vec<JsonDifference> = JsonDiff::new().exact_numbers().diff(&document.value()?, &self.json);
+
+		// Set values first, then remove in reverse order so array indexes of later removals stay valid.
+		for difference in differences.iter().filter(|difference| difference.kind != JsonDifferenceKind::Removed) {
+			document.set(&difference.path, difference.new.as_ref().unwrap())?;
+		}
+		for difference in differences.iter().rev().filter(|difference| difference.kind == JsonDifferenceKind::Removed) {
+			document.remove(&difference.path)?;
+		}
+		Ok(document.to_string())
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Save the changes made to the original file if there is one.
+	pub fn save_changes(&self) -> Result<(), Box<dyn Error>> {
+		match &self.source_file {
+			Some(path) => self.save_to_file(path),
+			None => Err("Could not save changes to json, json did not come from a file. Please use 'save_to_file' instead.".into())
+		}
+	}
+
+	/// Save the json to the specified file. Writes and syncs a uniquely named temporary file in the same folder first and then replaces the target, so the target is never left half-written and concurrent saves do not share a temporary file. The folder is synced after replacing, so the replacement survives a crash.
+	pub fn save_to_file(&self, file_path:&str) -> Result<(), Box<dyn Error>> {
+		let contents:String = self.to_contents()?;
+		let (temporary_path, mut temporary_file) = create_temporary_file(file_path).map_err(|error| format!("Could not save json to '{file_path}': {error}"))?;
+		temporary_file.write_all(contents.as_bytes()).and_then(|_| temporary_file.sync_all()).and_then(|_| fs::rename(&temporary_path, file_path)).map_err(|error| {
+			let _ = fs::remove_file(&temporary_path);
+			format!("Could not save json to '{file_path}': {error}")
+		})?;
+		sync_parent_folder(file_path).map_err(|error| format!("Could not sync the folder of '{file_path}': {error}").into())
+	}
+}
+impl<T:JsonIndex> Index<T> for JsonFile {
+	type Output = Json;
+	fn index(&self, index:T) -> &Self::Output {
+		&self.json[index]
+	}
+}
+impl<T:JsonIndex> IndexMut<T> for JsonFile {
+	fn index_mut(&mut self, index:T) -> &mut Self::Output {
+		&mut self.json[index]
+	}
+}
+
+
+
+/// Create a new temporary file next to a file. The name holds the process id, the time and a counter, creating fails instead of opening a file that already exists.
+fn create_temporary_file(file_path:&str) -> io::Result<(String, File)> {
+	let mut attempt:usize = 0;
+	loop {
+		let nanos:u128 = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_nanos()).unwrap_or_default();
+		let temporary_path:String = format!("{file_path}.{}-{nanos}-{}.{TEMPORARY_FILE_EXTENSION}", process::id(), TEMPORARY_FILE_COUNTER.fetch_add(1, Ordering::Relaxed));
+		match OpenOptions::new().write(true).create_new(true).open(&temporary_path) {
+			Ok(file) => return Ok((temporary_path, file)),
+			Err(error) if error.kind() == ErrorKind::AlreadyExists && attempt + 1 < TEMPORARY_FILE_ATTEMPTS => attempt += 1,
+			Err(error) => return Err(error)
+		}
+	}
+}
+
+/// Sync the folder of a file, so a rename in the folder is stored. Folders can not be opened as file on Windows, so there it does nothing.
+fn sync_parent_folder(file_path:&str) -> io::Result<()> {
+	if cfg!(windows) {
+		return Ok(());
+	}
+	let folder:&Path = Path::new(file_path).parent().filter(|folder| !folder.as_os_str().is_empty()).unwrap_or(Path::new("."));
+	File::open(folder)?.sync_all()
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ json, Json, JsonFile };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	const CONFIG:&str = "{\n\t// Service settings.\n\t\"name\": \"api\",\n\t\"port\": 80,\n\t\"hosts\": [\"a\", \"b\", \"c\"],\n\t\"debug\": true\n}\n";
+
+	/// Whether or not temporary files of a saved file are left in the working folder.
+	fn temporary_files_exist(file_path:&str) -> bool {
+		std::fs::read_dir(".").unwrap().any(|entry| entry.unwrap().file_name().to_string_lossy().starts_with(&format!("{file_path}.")))
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_keep_formatting() {
+		let mut json_file:JsonFile = JsonFile::from_contents(CONFIG).unwrap();
+		assert!(json_file.keeps_formatting());
+		json_file["port"] = json!(8080);
+		json_file["tls"] = json!({ "cert": "x.pem" });
+		json_file["hosts"].remove_at(2);
+		json_file["hosts"].remove_at(0);
+		json_file.json_mut().remove("debug");
+		assert_eq!(json_file.to_contents().unwrap(), "{\n\t// Service settings.\n\t\"name\": \"api\",\n\t\"port\": 8080,\n\t\"hosts\": [\"b\"],\n\t\"tls\": {\"cert\":\"x.pem\"}\n}\n");
+		assert_eq!(JsonFile::from_contents(CONFIG).unwrap().to_contents().unwrap(), CONFIG);
+
+		let mut json_file:JsonFile = JsonFile::from_contents(CONFIG).unwrap();
+		json_file["port"] = json!(80);
+		assert_eq!(json_file.to_contents().unwrap(), CONFIG);
+		json_file["port"] = json!(80.0);
+		assert_eq!(json_file.to_contents().unwrap(), CONFIG.replace("80", "80.0"));
+
+		let json_file:JsonFile = JsonFile::from_contents(CONFIG).unwrap().discard_formatting();
+		assert_eq!(json_file.to_contents().unwrap(), Json::new_json5(CONFIG).unwrap().to_pretty_string() + "\n");
+	}
+
+	#[test]
+	fn test_save_and_load() {
+		let temp_file:&str = "test_json_file.json";
+		assert!(JsonFile::from_contents(CONFIG).unwrap().save_changes().is_err());
+		JsonFile::from_contents(CONFIG).unwrap().save_to_file(temp_file).unwrap();
+
+		let mut json_file:JsonFile = JsonFile::from_file(temp_file).unwrap();
+		assert_eq!(json_file.source_file(), Some(temp_file));
+		json_file["name"] = json!("api-v2");
+		json_file.save_changes().unwrap();
+
+		let loaded_file:JsonFile = JsonFile::from_file(temp_file).unwrap();
+		assert_eq!(loaded_file["name"], json!("api-v2"));
+		assert!(std::fs::read_to_string(temp_file).unwrap().contains("// Service settings."));
+		assert!(!temporary_files_exist(temp_file));
+		std::fs::remove_file(temp_file).unwrap();
+	}
+
+	#[test]
+	fn test_concurrent_saves() {
+		let temp_file:&str = "test_json_file_concurrent.json";
+		let threads:Vec<std::thread::JoinHandle<()>> = (0..8).map(|index| std::thread::spawn(move || {
+			let mut json_file:JsonFile = JsonFile::from_contents(CONFIG).unwrap();
+			json_file["name"] = json!(format!("api-{index}"));
+			for _ in 0..10 {
+				json_file.save_to_file(temp_file).unwrap();
+			}
+		})).collect();
+		for thread in threads {
+			thread.join().unwrap();
+		}
+		assert!(JsonFile::from_file(temp_file).unwrap()["name"].as_str().unwrap().starts_with("api-"));
+		assert!(!temporary_files_exist(temp_file));
+		std::fs::remove_file(temp_file).unwrap();
+	}
+
+	#[test]
+	fn test_lenient_contents() {
+		let json_file:JsonFile = JsonFile::from_contents("{'key': TRUE}").unwrap();
+		assert!(!json_file.keeps_formatting());
+		assert_eq!(json_file["key"], json!(true));
+	}
+}
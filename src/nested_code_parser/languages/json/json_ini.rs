@@ -0,0 +1,112 @@
+use super::Json;
+use crate::{ IniCategory, IniCore };
+use std::error::Error;
+
+
+
+const COMMENT_CHARS:[char; 2] = [';', '#'];
+
+
+
+impl Json {
+
+	/* INI METHODS */
+
+	/// Parse INI contents to a dict. Variables before the first category are added to the root, categories become dicts. INI has no types, so all values are strings. Lines starting with ';' or '#' are comments.
+	pub fn from_ini(contents:&str) -> Result<Json, Box<dyn Error>> {
+		let contents:String = contents.lines().filter(|line| !line.trim_start().starts_with(COMMENT_CHARS)).collect::<Vec<&str>>().join("\n");
+		let ini:IniCore = IniCore::from_contents(&contents, &|value| value.to_owned(), &|value| value.to_owned())?;
+		let mut root:Vec<(Json, Json)> = Vec::new();
+		let mut categories = ini.categories().iter().peekable();
+
+		// Add variables before the first category, then all category headers in order, so categories without variables are kept.
+		if let Some(category) = categories.next_if(|category| category.name.is_empty()) {
+			add_ini_variables(&mut root, category);
+		}
+		for line in contents.lines().map(|line| line.trim()).filter(|line| line.starts_with('[') && line.ends_with(']')) {
+			let name:&str = line[1..line.len() - 1].trim();
+			if !name.is_empty() {
+				category_entries(&mut root, name)?;
+			}
+		}
+
+		// Add category variables.
+		for category in categories {
+			let entries:&mut Vec<(Json, Json)> = match category.name.is_empty() {
+				true => &mut root,
+				false => category_entries(&mut root, &category.name)?
+			};
+			add_ini_variables(entries, category);
+		}
+		Ok(Json::Dict(root))
+	}
+
+	/// Convert a dict to INI contents. Dicts in the root become categories, other values of the root are written before the first category. Dicts nested deeper, arrays and null can not be represented in INI and return an error.
+	pub fn to_ini(&self) -> Result<String, Box<dyn Error>> {
+		let entries:&Vec<(Json, Json)> = self.as_object().ok_or_else(|| conversion_error(&[], "the root should be a dict"))?;
+		let mut variables:Vec<String> = Vec::new();
+		let mut categories:Vec<String> = Vec::new();
+		for (key, value) in entries {
+			let name:String = Json::key_string(key);
+			match value {
+				Json::Dict(category_entries) => {
+					if name.is_empty() || name.contains([']', '\n', '\r']) {
+						return Err(conversion_error(&[&name], "category names should not be empty or contain ']' or line breaks"));
+					}
+					let mut category:String = format!("[{name}]");
+					for (variable_key, variable_value) in category_entries {
+						category += &format!("\n{}", ini_variable(&[&name, &Json::key_string(variable_key)], variable_value)?);
+					}
+					categories.push(category);
+				},
+				value => variables.push(ini_variable(&[&name], value)?)
+			}
+		}
+		Ok(variables.into_iter().chain(categories).collect::<Vec<String>>().join("\n\n") + "\n")
+	}
+}
+
+
+
+/// Get the entries of a category in the root, adding an empty dict for the category if it does not exist yet.
+fn category_entries<'a>(root:&'a mut Vec<(Json, Json)>, name:&str) -> Result<&'a mut Vec<(Json, Json)>, Box<dyn Error>> {
+	if !root.iter().any(|(key, _)| key.as_str() == Some(name)) {
+		root.push((Json::String(name.to_string()), Json::Dict(Vec::new())));
+	}
+	root.iter_mut().find(|(key, _)| key.as_str() == Some(name)).and_then(|(_, value)| value.as_object_mut()).ok_or_else(|| format!("Could not parse ini, category '{name}' is also used as variable name.").into())
+}
+
+/// Add the variables of an INI category to dict entries. Later variables with the same name replace earlier ones.
+fn add_ini_variables(entries:&mut Vec<(Json, Json)>, category:&IniCategory) {
+	for variable in &category.data {
+		let value:Json = Json::String(variable.value.trim().to_string());
+		match entries.iter_mut().find(|(key, _)| key.as_str() == Some(&variable.name)) {
+			Some((_, existing)) => *existing = value,
+			None => entries.push((Json::String(variable.name.clone()), value))
+		}
+	}
+}
+
+/// Get the INI line of a variable.
+fn ini_variable(path:&[&str], value:&Json) -> Result<String, Box<dyn Error>> {
+	let name:&str = path[path.len() - 1];
+	if name.trim() != name || name.is_empty() || name.starts_with(['[', ';', '#']) || name.contains(['=', '\n', '\r']) {
+		return Err(conversion_error(path, "variable names should not be empty, start with '[', ';' or '#', contain '=' or line breaks or have surrounding white-space"));
+	}
+	let value:String = match value {
+		Json::Dict(_) => return Err(conversion_error(path, "ini can not have dicts within categories")),
+		Json::Array(_) => return Err(conversion_error(path, "ini does not support arrays")),
+		Json::Null => return Err(conversion_error(path, "ini does not support null")),
+		Json::String(value) => value.clone(),
+		value => value.to_string()
+	};
+	if value.trim() != value || value.contains(['\n', '\r']) {
+		return Err(conversion_error(path, "ini values should not contain line breaks or have surrounding white-space"));
+	}
+	Ok(format!("{name}={value}"))
+}
+
+/// Create the error for a value that can not be converted to INI.
+fn conversion_error(path:&[&str], reason:&str) -> Box<dyn Error> {
+	format!("Could not convert json at '{}' to ini, {reason}.", Json::build_pointer(path)).into()
+}
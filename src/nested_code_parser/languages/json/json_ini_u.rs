@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ json, Json };
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_from_ini() {
+		let json:Json = Json::from_ini("; settings\nname = api\n\n[server]\nhost=localhost\nport=8080\n# tls\n[server]\nport=9090\n[logging]\nlevel=debug=verbose\n").unwrap();
+		assert_eq!(json, json!({ "name": "api", "server": { "host": "localhost", "port": "9090" }, "logging": { "level": "debug=verbose" } }));
+		assert!(Json::from_ini("server=1\n[server]\nport=1").is_err());
+		assert!(Json::from_ini("[server]\nport").is_err());
+	}
+
+	#[test]
+	fn test_ini_empty_categories() {
+		assert_eq!(Json::from_ini("[empty]").unwrap(), json!({ "empty": {} }));
+		assert_eq!(Json::from_ini("name=api\n[empty]\n[server]\nport=1\n[last]\n").unwrap(), json!({ "name": "api", "empty": {}, "server": { "port": "1" }, "last": {} }));
+		assert!(Json::from_ini("empty=1\n[empty]").is_err());
+		let json:Json = json!({ "a": {}, "b": { "c": "d" } });
+		assert_eq!(Json::from_ini(&json.to_ini().unwrap()).unwrap(), json);
+	}
+
+	#[test]
+	fn test_to_ini() {
+		let json:Json = json!({ "server": { "host": "localhost", "port": 8080, "debug": false }, "name": "api", "ratio": 0.5 });
+		let contents:String = json.to_ini().unwrap();
+		assert_eq!(contents, "name=api\n\nratio=0.5\n\n[server]\nhost=localhost\nport=8080\ndebug=false\n");
+		assert_eq!(Json::from_ini(&contents).unwrap(), json!({ "name": "api", "ratio": "0.5", "server": { "host": "localhost", "port": "8080", "debug": "false" } }));
+	}
+
+	#[test]
+	fn test_to_ini_errors() {
+		let error = |json:Json| json.to_ini().unwrap_err().to_string();
+		assert_eq!(error(json!([1])), "Could not convert json at '' to ini, the root should be a dict.");
+		assert_eq!(error(json!({ "servers": ["a", "b"] })), "Could not convert json at '/servers' to ini, ini does not support arrays.");
+		assert_eq!(error(json!({ "server": { "tls": { "cert": "x" } } })), "Could not convert json at '/server/tls' to ini, ini can not have dicts within categories.");
+		assert_eq!(error(json!({ "server": { "host": null } })), "Could not convert json at '/server/host' to ini, ini does not support null.");
+		assert_eq!(error(json!({ "motd": "line\nline" })), "Could not convert json at '/motd' to ini, ini values should not contain line breaks or have surrounding white-space.");
+		assert!(json!({ "a=b": 1 }).to_ini().is_err());
+		assert!(json!({ "a]": {} }).to_ini().is_err());
+	}
+}
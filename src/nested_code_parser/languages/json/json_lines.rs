@@ -0,0 +1,168 @@
+use super::{ Json, JsonError, JsonFlavor, JsonPosition, JsonReader, JsonWriter };
+use std::io::{ self, BufRead, Write };
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MalformedLines {
+	/// Yields an error for each malformed line and continues with the next line.
+	Report,
+	/// Silently skips malformed lines.
+	Skip
+}
+
+
+
+/// Reads NDJSON / JSON Lines, one json document per line. Blank lines are ignored. Errors are positioned in the whole input, so their line number is the line in the source.
+pub struct JsonLinesReader<T:BufRead> {
+	source:T,
+	reader:JsonReader,
+	malformed_lines:MalformedLines,
+	line_number:usize,
+	offset:usize,
+	finished:bool
+}
+impl<T:BufRead> JsonLinesReader<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new reader that parses each line as strict json and reports malformed lines.
+	pub fn new(source:T) -> JsonLinesReader<T> {
+		JsonLinesReader {
+			source,
+			reader: JsonReader::new(JsonFlavor::Strict),
+			malformed_lines: MalformedLines::Report,
+			line_number: 0,
+			offset: 0,
+			finished: false
+		}
+	}
+
+	/// Return self with a different reader used to parse each line.
+	pub fn with_reader(mut self, reader:JsonReader) -> Self {
+		self.reader = reader;
+		self
+	}
+
+	/// Return self with a policy for malformed lines.
+	pub fn malformed_lines(mut self, policy:MalformedLines) -> Self {
+		self.malformed_lines = policy;
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the line number of the last read line, starting at 1.
+	pub fn line_number(&self) -> usize {
+		self.line_number
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Read the next non-blank line and parse it.
+	fn read_line(&mut self) -> Option<Result<Json, JsonError>> {
+		let mut bytes:Vec<u8> = Vec::new();
+		loop {
+			bytes.clear();
+			let line_offset:usize = self.offset;
+			match self.source.read_until(b'\n', &mut bytes) {
+				Ok(0) => return None,
+				Ok(length) => self.offset += length,
+				Err(_) => {
+					self.line_number += 1;
+					self.finished = true;
+					return Some(Err(self.error(JsonError::ReadFailed, line_offset, 1)));
+				}
+			}
+			self.line_number += 1;
+
+			// Parse the line.
+			let result:Result<Json, JsonError> = match std::str::from_utf8(&bytes) {
+				Ok(line) => {
+					let line:&str = line.strip_suffix('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)).unwrap_or(line);
+					if line.trim().is_empty() {
+						continue;
+					}
+					self.reader.read(line).map_err(|mut error| {
+						let position:&mut JsonPosition = error.position_mut();
+						position.line = self.line_number;
+						position.offset += line_offset;
+						error
+					})
+				},
+				Err(error) => {
+					let column:usize = String::from_utf8_lossy(&bytes[..error.valid_up_to()]).chars().count() + 1;
+					Err(self.error(JsonError::InvalidEncoding, line_offset + error.valid_up_to(), column))
+				}
+			};
+			if result.is_err() && self.malformed_lines == MalformedLines::Skip {
+				continue;
+			}
+			return Some(result);
+		}
+	}
+
+	/// Create an error at an offset in the current line. The excerpt is left empty, as the line can not be shown.
+	fn error(&self, kind:fn(JsonPosition) -> JsonError, offset:usize, column:usize) -> JsonError {
+		kind(JsonPosition { offset, line: self.line_number, column, excerpt: String::new() })
+	}
+}
+impl<T:BufRead> Iterator for JsonLinesReader<T> {
+	type Item = Result<Json, JsonError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished {
+			return None;
+		}
+		self.read_line()
+	}
+}
+
+
+
+/// Writes NDJSON / JSON Lines, each json document as a compact line.
+pub struct JsonLinesWriter<T:Write> {
+	target:T,
+	writer:JsonWriter
+}
+impl<T:Write> JsonLinesWriter<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new writer.
+	pub fn new(target:T) -> JsonLinesWriter<T> {
+		JsonLinesWriter { target, writer: JsonWriter::compact() }
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Write a json document as a line.
+	pub fn write(&mut self, json:&Json) -> io::Result<()> {
+		self.writer.write_io(json, &mut self.target)?;
+		self.target.write_all(b"\n")
+	}
+
+	/// Write multiple json documents as lines.
+	pub fn write_all<'a, U:IntoIterator<Item = &'a Json>>(&mut self, documents:U) -> io::Result<()> {
+		for json in documents {
+			self.write(json)?;
+		}
+		Ok(())
+	}
+
+	/// Flush the target.
+	pub fn flush(&mut self) -> io::Result<()> {
+		self.target.flush()
+	}
+
+	/// Get the target back.
+	pub fn into_inner(self) -> T {
+		self.target
+	}
+}
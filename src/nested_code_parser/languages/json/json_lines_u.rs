@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonError, JsonFlavor, JsonLinesReader, JsonLinesWriter, JsonReader, MalformedLines };
+	use std::io::{ self, BufRead, BufReader, Cursor, Read };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	const LOG_EXPORT:&str = "{\"level\": \"info\", \"id\": 1}\r\n\n{\"level\": \"warn\", \"id\": 2}\n{\"level\": \n[1, 2]\n   \n\"last\"";
+
+	/// A reader that fails after its contents.
+	struct FailingReader(Cursor<Vec<u8>>);
+	impl Read for FailingReader {
+		fn read(&mut self, buffer:&mut [u8]) -> io::Result<usize> {
+			match self.0.read(buffer)? {
+				0 => Err(io::Error::other("connection lost")),
+				length => Ok(length)
+			}
+		}
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_read_lines() {
+		let mut reader:JsonLinesReader<Cursor<&str>> = JsonLinesReader::new(Cursor::new(LOG_EXPORT));
+		assert_eq!(reader.next().unwrap().unwrap()["id"], Json::Integer(1));
+		assert_eq!(reader.line_number(), 1);
+		assert_eq!(reader.next().unwrap().unwrap()["id"], Json::Integer(2));
+		assert_eq!(reader.line_number(), 3);
+		let error:JsonError = reader.next().unwrap().unwrap_err();
+		assert!(matches!(error, JsonError::UnterminatedDict(_)));
+		assert_eq!((error.line(), error.column(), error.position().offset), (4, 1, 56));
+		assert_eq!(reader.next().unwrap().unwrap(), Json::new("[1, 2]").unwrap());
+		assert_eq!(reader.next().unwrap().unwrap(), Json::String("last".to_string()));
+		assert_eq!(reader.line_number(), 7);
+		assert!(reader.next().is_none());
+	}
+
+	#[test]
+	fn test_skip_malformed_lines() {
+		let documents:Vec<Result<Json, JsonError>> = JsonLinesReader::new(Cursor::new(LOG_EXPORT)).malformed_lines(MalformedLines::Skip).collect();
+		assert_eq!(documents.len(), 4);
+		assert!(documents.iter().all(|document| document.is_ok()));
+
+		let documents:Vec<Result<Json, JsonError>> = JsonLinesReader::new(Cursor::new("{a: 1}\n{b: 2,}")).with_reader(JsonReader::new(JsonFlavor::Json5)).collect();
+		assert_eq!(documents, vec![Json::new_json5("{a: 1}"), Json::new_json5("{b: 2}")]);
+	}
+
+	#[test]
+	fn test_read_failures() {
+		let mut reader:JsonLinesReader<BufReader<&[u8]>> = JsonLinesReader::new(BufReader::new(b"1\n\"\xff\"\n2".as_slice()));
+		assert_eq!(reader.next().unwrap(), Ok(Json::Integer(1)));
+		let error:JsonError = reader.next().unwrap().unwrap_err();
+		assert!(matches!(error, JsonError::InvalidEncoding(_)));
+		assert_eq!((error.line(), error.column()), (2, 2));
+		assert_eq!(reader.next().unwrap(), Ok(Json::Integer(2)));
+
+		let source:Box<dyn BufRead> = Box::new(BufReader::new(FailingReader(Cursor::new(b"1\n".to_vec()))));
+		let results:Vec<Result<Json, JsonError>> = JsonLinesReader::new(source).collect();
+		assert_eq!(results.len(), 2);
+		assert!(matches!(results[1], Err(JsonError::ReadFailed(_))));
+	}
+
+	#[test]
+	fn test_write_lines() {
+		let mut writer:JsonLinesWriter<Vec<u8>> = JsonLinesWriter::new(Vec::new());
+		writer.write(&Json::new(r#"{"text": "multi\nline", "list": [1, 2]}"#).unwrap()).unwrap();
+		writer.write_all(&[Json::Null, Json::Bool(true)]).unwrap();
+		writer.flush().unwrap();
+		let output:String = String::from_utf8(writer.into_inner()).unwrap();
+		assert_eq!(output, "{\"text\":\"multi\\nline\",\"list\":[1,2]}\nnull\ntrue\n");
+		assert_eq!(JsonLinesReader::new(Cursor::new(output)).count(), 3);
+	}
+}
@@ -0,0 +1,30 @@
+/// Build a json value using json syntax, like `json!({ "name": "api", "hosts": ["a", "b"], "tls": null })`. Keys and values can be any Rust expression with a conversion to json.
+#[macro_export]
+macro_rules! json {
+	(null) => { $crate::Json::Null };
+	([ $($contents:tt)* ]) => { $crate::Json::Array($crate::json_internal!(@array [] () $($contents)*)) };
+	({ $($contents:tt)* }) => { $crate::Json::Dict($crate::json_internal!(@dict [] () $($contents)*)) };
+	($value:expr) => { $crate::Json::from($value) };
+}
+
+/// Munches the tokens of a json! array or dict, splitting items on commas and keys on colons.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! json_internal {
+
+	// Array items.
+	(@array [$($items:expr,)*] ()) => { vec![$($items,)*] };
+	(@array [$($items:expr,)*] ($($item:tt)+)) => { vec![$($items,)* $crate::json!($($item)+)] };
+	(@array [$($items:expr,)*] ($($item:tt)+) , $($rest:tt)*) => { $crate::json_internal!(@array [$($items,)* $crate::json!($($item)+),] () $($rest)*) };
+	(@array [$($items:expr,)*] ($($item:tt)*) $next:tt $($rest:tt)*) => { $crate::json_internal!(@array [$($items,)*] ($($item)* $next) $($rest)*) };
+
+	// Dict keys.
+	(@dict [$($entries:expr,)*] ()) => { vec![$($entries,)*] };
+	(@dict [$($entries:expr,)*] ($($key:tt)+) : $($rest:tt)*) => { $crate::json_internal!(@value [$($entries,)*] ($($key)+) () $($rest)*) };
+	(@dict [$($entries:expr,)*] ($($key:tt)*) $next:tt $($rest:tt)*) => { $crate::json_internal!(@dict [$($entries,)*] ($($key)* $next) $($rest)*) };
+
+	// Dict values.
+	(@value [$($entries:expr,)*] ($($key:tt)+) ($($value:tt)+)) => { vec![$($entries,)* ($crate::Json::String(($($key)+).to_string()), $crate::json!($($value)+))] };
+	(@value [$($entries:expr,)*] ($($key:tt)+) ($($value:tt)+) , $($rest:tt)*) => { $crate::json_internal!(@dict [$($entries,)* ($crate::Json::String(($($key)+).to_string()), $crate::json!($($value)+)),] () $($rest)*) };
+	(@value [$($entries:expr,)*] ($($key:tt)+) ($($value:tt)*) $next:tt $($rest:tt)*) => { $crate::json_internal!(@value [$($entries,)*] ($($key)+) ($($value)* $next) $($rest)*) };
+}
@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ json, Json };
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_json_macro() {
+		let port:i64 = 8080;
+		let hosts:Vec<&str> = vec!["a.example", "b.example"];
+		let key:String = "dynamic".to_string();
+		let config:Json = json!({
+			"name": "api",
+			"port": port,
+			"offset": -1,
+			"ratio": 0.5 * 2.0,
+			"hosts": hosts,
+			"tls": null,
+			"nested": { "list": [true, null, [], {}, "x",], "empty": {} },
+			key: format!("{}-{}", "a", port),
+		});
+		assert_eq!(config.to_string(), r#"{"name":"api","port":8080,"offset":-1,"ratio":1.0,"hosts":["a.example","b.example"],"tls":null,"nested":{"list":[true,null,[],{},"x"],"empty":{}},"dynamic":"a-8080"}"#);
+		assert_eq!(json!(null), Json::Null);
+		assert_eq!(json!([]), Json::Array(Vec::new()));
+		assert_eq!(json!({}), Json::Dict(Vec::new()));
+		assert_eq!(json!(Some(1)), Json::Integer(1));
+		assert_eq!(json!([1, [2, 3]])[1][0], Json::Integer(2));
+	}
+}
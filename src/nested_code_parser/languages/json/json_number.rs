@@ -0,0 +1,141 @@
+use std::{ error::Error, fmt::{ self, Display, Formatter } };
+
+
+
+/// The highest amount of integer digits that can fit an i128 or u64, used to avoid expanding huge exponents.
+const MAX_INTEGER_DIGITS:i64 = 39;
+const MAX_DECIMAL_SHIFT:u64 = 4096;
+
+
+
+/// A JSON number stored as the lexeme it was written as. Keeps numbers beyond the range of i64 and f64 exact and keeps the difference between '-0', '1.0' and '1.00'.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct JsonNumber {
+	lexeme:String
+}
+impl JsonNumber {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new number from a lexeme. The lexeme should be a valid RFC 8259 number.
+	pub fn new(lexeme:&str) -> Result<JsonNumber, Box<dyn Error>> {
+		if Self::is_valid_lexeme(lexeme) {
+			Ok(JsonNumber { lexeme: lexeme.to_string() })
+		} else {
+			Err(format!("Invalid json number '{lexeme}'.").into())
+		}
+	}
+
+	/// Create a new number from a float. Returns None for NaN and infinity, which JSON can not represent.
+	pub fn from_f64(value:f64) -> Option<JsonNumber> {
+		if value.is_finite() { Some(JsonNumber { lexeme: format!("{value:?}") }) } else { None }
+	}
+
+	/// Whether or not a lexeme is a valid RFC 8259 number.
+	fn is_valid_lexeme(lexeme:&str) -> bool {
+		let unsigned:&str = lexeme.strip_prefix('-').unwrap_or(lexeme);
+		let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+			Some(index) => (&unsigned[..index], Some(&unsigned[index + 1..])),
+			None => (unsigned, None)
+		};
+		let (integer, fraction) = match mantissa.split_once('.') {
+			Some((integer, fraction)) => (integer, Some(fraction)),
+			None => (mantissa, None)
+		};
+		let is_digits = |text:&str| !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_digit());
+		is_digits(integer) && (integer == "0" || !integer.starts_with('0')) &&
+		fraction.map(is_digits).unwrap_or(true) &&
+		exponent.map(|exponent| is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent))).unwrap_or(true)
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the number exactly as it was written.
+	pub fn as_str(&self) -> &str {
+		&self.lexeme
+	}
+
+	/// Whether or not the number was written without fraction or exponent.
+	pub fn is_integer(&self) -> bool {
+		!self.lexeme.contains(['.', 'e', 'E'])
+	}
+
+	/// Get the number as i64, if it is a whole number within range.
+	pub fn as_i64(&self) -> Option<i64> {
+		self.as_i128().and_then(|value| i64::try_from(value).ok())
+	}
+
+	/// Get the number as u64, if it is a positive whole number within range.
+	pub fn as_u64(&self) -> Option<u64> {
+		self.as_i128().and_then(|value| u64::try_from(value).ok())
+	}
+
+	/// Get the number as i128, if it is a whole number within range. Numbers like '1.0' and '2e3' count as whole numbers.
+	pub fn as_i128(&self) -> Option<i128> {
+		let (negative, digits, point) = self.decimal_parts();
+		if digits.bytes().all(|byte| byte == b'0') {
+			return Some(0);
+		}
+		if !(0..=MAX_INTEGER_DIGITS).contains(&point) || digits[(point as usize).min(digits.len())..].bytes().any(|byte| byte != b'0') {
+			return None;
+		}
+		let integer:String = format!("{}{}{}", if negative { "-" } else { "" }, &digits[..(point as usize).min(digits.len())], "0".repeat((point as usize).saturating_sub(digits.len())));
+		integer.parse::<i128>().ok()
+	}
+
+	/// Get the number as the nearest f64.
+	pub fn as_f64(&self) -> f64 {
+		self.lexeme.parse::<f64>().unwrap_or(f64::NAN)
+	}
+
+	/// Get the number in plain decimal notation, with the exponent applied. Keeps the digits as written, so '1.50e1' becomes '15.0'. Returns None when the exponent would shift the point more than 4096 digits.
+	pub fn to_decimal_string(&self) -> Option<String> {
+		let (negative, digits, point) = self.decimal_parts();
+		if point.unsigned_abs() > MAX_DECIMAL_SHIFT {
+			return None;
+		}
+		let sign:&str = if negative { "-" } else { "" };
+		let body:String = if point <= 0 {
+			format!("0.{}{}", "0".repeat(-point as usize), digits)
+		} else if point as usize >= digits.len() {
+			digits.clone() + &"0".repeat(point as usize - digits.len())
+		} else {
+			format!("{}.{}", &digits[..point as usize], &digits[point as usize..])
+		};
+
+		// Remove leading zeros caused by shifting the point.
+		let integer_length:usize = body.find('.').unwrap_or(body.len());
+		let leading_zeros:usize = body[..integer_length - 1].bytes().take_while(|byte| *byte == b'0').count();
+		Some(format!("{sign}{}", &body[leading_zeros..]))
+	}
+
+	/// Split the lexeme into its sign, all written digits and the position of the decimal point in those digits.
+	fn decimal_parts(&self) -> (bool, String, i64) {
+		let unsigned:&str = self.lexeme.strip_prefix('-').unwrap_or(&self.lexeme);
+		let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+			Some(index) => (&unsigned[..index], unsigned[index + 1..].parse::<i64>().unwrap_or(if unsigned[index + 1..].starts_with('-') { i64::MIN } else { i64::MAX })),
+			None => (unsigned, 0)
+		};
+		let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+		(unsigned.len() != self.lexeme.len(), integer.to_string() + fraction, (integer.len() as i64).saturating_add(exponent))
+	}
+}
+impl Display for JsonNumber {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.lexeme)
+	}
+}
+macro_rules! json_number_from_integer {
+	($($type:ty),*) => {
+		$(
+			impl From<$type> for JsonNumber {
+				fn from(value:$type) -> Self {
+					JsonNumber { lexeme: value.to_string() }
+				}
+			}
+		)*
+	};
+}
+json_number_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonFlavor, JsonNumber };
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_lexeme_validation() {
+		for lexeme in ["0", "-0", "1.0", "1.00", "12345678901234567890123", "-1.5e+2", "2E-3", "0.000001"] {
+			assert_eq!(JsonNumber::new(lexeme).unwrap().as_str(), lexeme);
+		}
+		for lexeme in ["", "-", "01", "+1", ".5", "5.", "1e", "1e+", "0x10", "NaN", "1.5.2"] {
+			assert!(JsonNumber::new(lexeme).is_err(), "{lexeme}");
+		}
+		assert_eq!(JsonNumber::from_f64(1.0).unwrap().as_str(), "1.0");
+		assert_eq!(JsonNumber::from_f64(f64::NAN), None);
+		assert_eq!(JsonNumber::from(u128::MAX).as_str(), "340282366920938463463374607431768211455");
+	}
+
+	#[test]
+	fn test_conversions() {
+		let number = |lexeme:&str| JsonNumber::new(lexeme).unwrap();
+		assert_eq!(number("9223372036854775807").as_i64(), Some(i64::MAX));
+		assert_eq!(number("9223372036854775808").as_i64(), None);
+		assert_eq!(number("18446744073709551615").as_u64(), Some(u64::MAX));
+		assert_eq!(number("-1").as_u64(), None);
+		assert_eq!(number("-170141183460469231731687303715884105728").as_i128(), Some(i128::MIN));
+		assert_eq!(number("1e40").as_i128(), None);
+		assert_eq!(number("2.50e1").as_i64(), Some(25));
+		assert_eq!(number("2.5").as_i64(), None);
+		assert_eq!(number("-0.0").as_i64(), Some(0));
+		assert_eq!(number("0.1").as_f64(), 0.1);
+		assert!(number("1e400").as_f64().is_infinite());
+		assert!(!number("1.0").is_integer() && number("-10").is_integer());
+	}
+
+	#[test]
+	fn test_decimal_string() {
+		let decimal = |lexeme:&str| JsonNumber::new(lexeme).unwrap().to_decimal_string();
+		for (lexeme, expected) in [("1.5e3", "1500"), ("1.25e-2", "0.0125"), ("12e-1", "1.2"), ("1.50e1", "15.0"), ("-0", "-0"), ("1.00", "1.00"), ("0.05e2", "5"), ("0e5", "0"), ("-5e-1", "-0.5"), ("123456789012345678901234567890.5", "123456789012345678901234567890.5")] {
+			assert_eq!(decimal(lexeme).as_deref(), Some(expected), "{lexeme}");
+		}
+		assert_eq!(decimal("1e5000"), None);
+		assert_eq!(decimal("1e-99999999999999999999"), None);
+	}
+
+	#[test]
+	fn test_lossless_parsing() {
+		let source:&str = r#"{"id": 123456789012345678901234567890, "price": 19.990, "zero": -0, "float": 1.0, "exp": 1E+2, "small": 3}"#;
+		let json:Json = Json::parse_lossless(source, JsonFlavor::Strict).unwrap();
+		assert_eq!(json.to_string(), source.replace(": ", ":").replace(", ", ","));
+		assert_eq!(json["id"].as_number().map(|number| number.as_str()), Some("123456789012345678901234567890"));
+		assert_eq!(json["small"].as_i64(), Some(3));
+		assert_eq!(json["exp"].as_u64(), Some(100));
+		assert_eq!(json["price"].as_f64(), Some(19.99));
+		assert!(json["price"].equals(&Json::Float(19.99)));
+		assert!(json["exp"].equals(&Json::Integer(100)));
+		assert_eq!(json.to_canonical_string().unwrap(), r#"{"exp":100,"float":1,"id":1.2345678901234568e+29,"price":19.99,"small":3,"zero":0}"#);
+
+		// Non-standard numbers are parsed as usual.
+		let json:Json = Json::parse_lossless("[0x10, +7, 1.50]", JsonFlavor::Json5).unwrap();
+		assert_eq!(json.to_string(), "[16,7,1.50]");
+		assert_eq!(Json::new("1.00").unwrap(), Json::Float(1.0));
+	}
+}
@@ -0,0 +1,129 @@
+use super::Json;
+use std::{ collections::HashMap, error::Error, ops::Index };
+
+
+
+/// An order-preserving json dict with string keys and a hash index, for constant time key lookup on large dicts. Only the entries of the object itself are indexed, values that are dicts stay `Json::Dict` and are searched linearly.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct JsonObject {
+	entries:Vec<(String, Json)>,
+	indexes:HashMap<String, usize>
+}
+impl JsonObject {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new empty object.
+	pub fn new() -> JsonObject {
+		JsonObject::default()
+	}
+
+	/// Create a new object from the entries of a dict. Keys should be strings. For duplicate keys the last value wins, at the position of the first entry. Use `JsonReader::read_object` with a duplicate key policy for other behavior.
+	pub fn from_entries(entries:Vec<(Json, Json)>) -> Result<JsonObject, Box<dyn Error>> {
+		let mut object:JsonObject = JsonObject { entries: Vec::with_capacity(entries.len()), indexes: HashMap::with_capacity(entries.len()) };
+		for (key, value) in entries {
+			match key {
+				Json::String(key) => { object.insert(&key, value); },
+				key => return Err(format!("Could not create json object, key {key} is not a string.").into())
+			}
+		}
+		Ok(object)
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the amount of entries.
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Whether or not the object has no entries.
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Whether or not the object has a key.
+	pub fn contains_key(&self, key:&str) -> bool {
+		self.indexes.contains_key(key)
+	}
+
+	/// Get the value of a key.
+	pub fn get(&self, key:&str) -> Option<&Json> {
+		self.indexes.get(key).map(|index| &self.entries[*index].1)
+	}
+
+	/// Get the dict value of a key as an object with its own hash index. Building the index copies the dict, so keep the object for repeated lookups. Fails if the key does not exist, the value is not a dict or it has keys that are not strings.
+	pub fn get_object(&self, key:&str) -> Result<JsonObject, Box<dyn Error>> {
+		JsonObject::try_from(self.get(key).ok_or(format!("Could not create json object, key '{key}' does not exist."))?.clone())
+	}
+
+	/// Get the mutable value of a key.
+	pub fn get_mut(&mut self, key:&str) -> Option<&mut Json> {
+		self.indexes.get(key).map(|index| &mut self.entries[*index].1)
+	}
+
+	/// Get the keys in order.
+	pub fn keys(&self) -> impl Iterator<Item = &str> {
+		self.entries.iter().map(|(key, _)| key.as_str())
+	}
+
+	/// Get the values in order.
+	pub fn values(&self) -> impl Iterator<Item = &Json> {
+		self.entries.iter().map(|(_, value)| value)
+	}
+
+	/// Get the entries in order.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &Json)> {
+		self.entries.iter().map(|(key, value)| (key.as_str(), value))
+	}
+
+
+
+	/* MODIFICATION METHODS */
+
+	/// Set the value of a key. Existing keys keep their position and return their previous value, new keys are added at the end.
+	pub fn insert(&mut self, key:&str, value:Json) -> Option<Json> {
+		match self.indexes.get(key) {
+			Some(index) => Some(std::mem::replace(&mut self.entries[*index].1, value)),
+			None => {
+				self.indexes.insert(key.to_string(), self.entries.len());
+				self.entries.push((key.to_string(), value));
+				None
+			}
+		}
+	}
+
+	/// Remove a key and return its value. Keeps the order of the other entries, so this takes linear time.
+	pub fn remove(&mut self, key:&str) -> Option<Json> {
+		let index:usize = self.indexes.remove(key)?;
+		let (_, value) = self.entries.remove(index);
+		for (key, _) in &self.entries[index..] {
+			*self.indexes.get_mut(key).unwrap() -= 1;
+		}
+		Some(value)
+	}
+}
+impl Index<&str> for JsonObject {
+	type Output = Json;
+
+	fn index(&self, key:&str) -> &Json {
+		self.get(key).unwrap_or(Json::error_instance())
+	}
+}
+impl From<JsonObject> for Json {
+	fn from(object:JsonObject) -> Json {
+		Json::Dict(object.entries.into_iter().map(|(key, value)| (Json::String(key), value)).collect())
+	}
+}
+impl TryFrom<Json> for JsonObject {
+	type Error = Box<dyn Error>;
+
+	fn try_from(json:Json) -> Result<JsonObject, Box<dyn Error>> {
+		match json {
+			Json::Dict(entries) => JsonObject::from_entries(entries),
+			json => Err(format!("Could not create json object from {json}, it is not a dict.").into())
+		}
+	}
+}
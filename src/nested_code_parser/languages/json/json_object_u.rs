@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonObject };
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_from_dict() {
+		let object:JsonObject = JsonObject::try_from(Json::new(r#"{"b": 1, "a": [true], "b": 2}"#).unwrap()).unwrap();
+		assert_eq!(object.len(), 2);
+		assert_eq!(object.keys().collect::<Vec<&str>>(), vec!["b", "a"]);
+		assert_eq!(object["b"], Json::Integer(2));
+		assert_eq!(object["a"][0], Json::Bool(true));
+		assert_eq!(object["missing"], Json::Null);
+		assert_eq!(Json::from(object).to_string(), r#"{"b":2,"a":[true]}"#);
+		assert!(JsonObject::try_from(Json::new("{1: 2}").unwrap()).is_err());
+		assert!(JsonObject::try_from(Json::new("[1, 2]").unwrap()).is_err());	}
+
+	#[test]
+	fn test_get_object() {
+		let object:JsonObject = JsonObject::try_from(Json::new(r#"{"a": {"b": {"c": 1}}, "d": [1]}"#).unwrap()).unwrap();
+		let nested:JsonObject = object.get_object("a").unwrap().get_object("b").unwrap();
+		assert_eq!(nested.keys().collect::<Vec<&str>>(), vec!["c"]);
+		assert_eq!(nested["c"], Json::Integer(1));
+		assert!(object.get_object("d").is_err());
+		assert!(object.get_object("missing").is_err());
+	}
+
+	#[test]
+	fn test_modification() {
+		let mut object:JsonObject = JsonObject::new();
+		for index in 0..1000 {
+			object.insert(&format!("key_{index}"), Json::Integer(index));
+		}
+		assert_eq!(object.insert("key_10", Json::Null), Some(Json::Integer(10)));
+		assert_eq!(object.remove("key_5"), Some(Json::Integer(5)));
+		assert_eq!(object.remove("key_5"), None);
+		assert!(!object.contains_key("key_5"));
+		assert_eq!(object.get("key_999"), Some(&Json::Integer(999)));
+		*object.get_mut("key_999").unwrap() = Json::Bool(false);
+		assert_eq!(object.iter().nth(998), Some(("key_999", &Json::Bool(false))));
+		assert_eq!(object.values().nth(9), Some(&Json::Null));
+		assert_eq!(object.len(), 999);
+		assert!(!object.is_empty());
+	}
+}
@@ -0,0 +1,175 @@
+use super::Json;
+use std::error::Error;
+
+
+
+const OP_KEY:&str = "op";
+const PATH_KEY:&str = "path";
+const FROM_KEY:&str = "from";
+const VALUE_KEY:&str = "value";
+
+
+
+impl Json {
+
+	/* PATCH METHODS */
+
+	/// Apply an RFC 6902 JSON Patch document. The operations are applied in order and all or nothing, when one fails the json stays unchanged.
+	pub fn apply_patch(&mut self, patch:&Json) -> Result<(), Box<dyn Error>> {
+		let operations:&Vec<Json> = patch.as_array().ok_or("Could not apply json patch, it should be an array of operations.")?;
+		let mut patched:Json = self.clone();
+		for (index, operation) in operations.iter().enumerate() {
+			patched.apply_patch_operation(operation).map_err(|error| format!("Could not apply json patch operation {index}: {error}"))?;
+		}
+		*self = patched;
+		Ok(())
+	}
+
+	/// Apply a single patch operation.
+	fn apply_patch_operation(&mut self, operation:&Json) -> Result<(), Box<dyn Error>> {
+		let get_string = |key:&str| operation[key].as_str().ok_or(format!("missing '{key}'."));
+		let get_value = || operation.get(VALUE_KEY).cloned().ok_or(format!("missing '{VALUE_KEY}'."));
+		let path:&str = get_string(PATH_KEY)?;
+		match get_string(OP_KEY)? {
+			"add" => self.pointer_add(path, get_value()?),
+			"remove" => self.pointer_remove(path).map(|_| ()),
+			"replace" => {
+				let target:&mut Json = self.pointer_mut(path).ok_or(format!("path '{path}' does not exist."))?;
+				*target = get_value()?;
+				Ok(())
+			},
+			"move" => {
+				let from:&str = get_string(FROM_KEY)?;
+				if path.starts_with(from) && path[from.len()..].starts_with('/') {
+					return Err(format!("can not move '{from}' into its own child '{path}'.").into());
+				}
+				let value:Json = self.pointer_remove(from)?;
+				self.pointer_add(path, value)
+			},
+			"copy" => {
+				let from:&str = get_string(FROM_KEY)?;
+				let value:Json = self.pointer(from).cloned().ok_or(format!("path '{from}' does not exist."))?;
+				self.pointer_add(path, value)
+			},
+			"test" => {
+				let expected:Json = get_value()?;
+				match self.pointer(path) {
+					Some(value) if value.equals(&expected) => Ok(()),
+					Some(value) => Err(format!("test failed, '{path}' is {value} instead of {expected}.").into()),
+					None => Err(format!("test failed, path '{path}' does not exist.").into())
+				}
+			},
+			op => Err(format!("unknown op '{op}'.").into())
+		}
+	}
+
+	/// Compare json values the way JSON Patch does. Numbers are compared by value, dict entries regardless of order.
+	pub fn equals(&self, other:&Json) -> bool {
+		match (self, other) {
+			(Json::Dict(entries), Json::Dict(other_entries)) => {
+				entries.len() == other_entries.len() && entries.iter().all(|(key, value)| match key.as_str() {
+					Some(key) => other.get(key).map(|other_value| value.equals(other_value)).unwrap_or(false),
+					None => other_entries.iter().any(|(other_key, other_value)| key == other_key && value.equals(other_value))
+				})
+			},
+			(Json::Array(items), Json::Array(other_items)) => items.len() == other_items.len() && items.iter().zip(other_items).all(|(item, other_item)| item.equals(other_item)),
+			(Json::Integer(_) | Json::Float(_) | Json::Number(_), Json::Integer(_) | Json::Float(_) | Json::Number(_)) => match (self.as_i128(), other.as_i128()) {
+				(Some(value), Some(other_value)) => value == other_value,
+				_ => self.as_f64() == other.as_f64()
+			},
+			_ => self == other
+		}
+	}
+
+	/// Get a number as i128 if it is a whole number, for exact comparisons.
+	fn as_i128(&self) -> Option<i128> {
+		match self {
+			Json::Integer(value) => Some(*value as i128),
+			Json::Number(value) => value.as_i128(),
+			_ => None
+		}
+	}
+
+	/// Create an RFC 6902 JSON Patch document that turns the source into the target.
+	pub fn diff_patch(source:&Json, target:&Json) -> Json {
+		let mut operations:Vec<Json> = Vec::new();
+		Self::_diff_patch(source, target, &mut Vec::new(), &mut operations);
+		Json::Array(operations)
+	}
+	fn _diff_patch(source:&Json, target:&Json, path:&mut Vec<String>, operations:&mut Vec<Json>) {
+		let operation = |op:&str, path:&[String], value:Option<&Json>| {
+			let mut operation:Json = Json::Dict(vec![
+				(Json::String(OP_KEY.to_string()), Json::String(op.to_string())),
+				(Json::String(PATH_KEY.to_string()), Json::String(Json::build_pointer(path)))
+			]);
+			if let Some(value) = value {
+				operation.insert(VALUE_KEY, value.clone());
+			}
+			operation
+		};
+		match (source, target) {
+			_ if source.equals(target) => {},
+			(Json::Dict(source_entries), Json::Dict(target_entries)) => {
+				for (key, _) in source_entries {
+					let key:String = Self::key_string(key);
+					if target.get(&key).is_none() {
+						path.push(key);
+						operations.push(operation("remove", path, None));
+						path.pop();
+					}
+				}
+				for (key, target_value) in target_entries {
+					path.push(Self::key_string(key));
+					match source.get(path.last().unwrap()) {
+						Some(source_value) => Self::_diff_patch(source_value, target_value, path, operations),
+						None => operations.push(operation("add", path, Some(target_value)))
+					}
+					path.pop();
+				}
+			},
+			(Json::Array(source_items), Json::Array(target_items)) => {
+				let common_length:usize = source_items.len().min(target_items.len());
+				for index in 0..common_length {
+					path.push(index.to_string());
+					Self::_diff_patch(&source_items[index], &target_items[index], path, operations);
+					path.pop();
+				}
+				for index in (common_length..source_items.len()).rev() {
+					path.push(index.to_string());
+					operations.push(operation("remove", path, None));
+					path.pop();
+				}
+				for target_item in &target_items[common_length..] {
+					path.push("-".to_string());
+					operations.push(operation("add", path, Some(target_item)));
+					path.pop();
+				}
+			},
+			_ => operations.push(operation("replace", path, Some(target)))
+		}
+	}
+
+	/// Get a dict key as string. Non-string keys, allowed by the lenient flavor, are turned into their json text.
+	pub(super) fn key_string(key:&Json) -> String {
+		key.as_str().map(|key| key.to_string()).unwrap_or_else(|| key.to_string())
+	}
+
+	/// Apply an RFC 7386 JSON Merge Patch. Dicts in the patch are merged recursively, null removes a key and any other value replaces the current one.
+	pub fn merge_patch(&mut self, patch:&Json) {
+		let Json::Dict(patch_entries) = patch else {
+			*self = patch.clone();
+			return;
+		};
+		if !matches!(self, Json::Dict(_)) {
+			*self = Json::Dict(Vec::new());
+		}
+		for (key, patch_value) in patch_entries {
+			let key:String = Self::key_string(key);
+			if patch_value.is_null() {
+				self.remove(&key);
+			} else {
+				self[key.as_str()].merge_patch(patch_value);
+			}
+		}
+	}
+}
\ No newline at end of file
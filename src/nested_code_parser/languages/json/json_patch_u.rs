@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+	use crate::Json;
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn json(contents:&str) -> Json {
+		Json::new_strict(contents).unwrap()
+	}
+
+	fn patched(document:&str, patch:&str) -> Result<String, String> {
+		let mut document:Json = json(document);
+		document.apply_patch(&json(patch)).map(|_| document.to_string()).map_err(|error| error.to_string())
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_rfc_operations() {
+		assert_eq!(patched(r#"{"foo": "bar"}"#, r#"[{"op": "add", "path": "/baz", "value": "qux"}]"#), Ok(r#"{"foo":"bar","baz":"qux"}"#.to_string()));
+		assert_eq!(patched(r#"{"foo": ["bar", "baz"]}"#, r#"[{"op": "add", "path": "/foo/1", "value": "qux"}]"#), Ok(r#"{"foo":["bar","qux","baz"]}"#.to_string()));
+		assert_eq!(patched(r#"{"baz": "qux", "foo": "bar"}"#, r#"[{"op": "remove", "path": "/baz"}]"#), Ok(r#"{"foo":"bar"}"#.to_string()));
+		assert_eq!(patched(r#"{"baz": "qux", "foo": "bar"}"#, r#"[{"op": "replace", "path": "/baz", "value": "boo"}]"#), Ok(r#"{"baz":"boo","foo":"bar"}"#.to_string()));
+		assert_eq!(
+			patched(r#"{"foo": {"bar": "baz", "waldo": "fred"}, "qux": {"corge": "grault"}}"#, r#"[{"op": "move", "from": "/foo/waldo", "path": "/qux/thud"}]"#),
+			Ok(r#"{"foo":{"bar":"baz"},"qux":{"corge":"grault","thud":"fred"}}"#.to_string())
+		);
+		assert_eq!(patched(r#"{"foo": ["all", "grass", "cows", "eat"]}"#, r#"[{"op": "move", "from": "/foo/1", "path": "/foo/3"}]"#), Ok(r#"{"foo":["all","cows","eat","grass"]}"#.to_string()));
+		assert_eq!(patched(r#"{"foo": [1]}"#, r#"[{"op": "copy", "from": "/foo", "path": "/bar"}]"#), Ok(r#"{"foo":[1],"bar":[1]}"#.to_string()));
+		assert_eq!(patched(r#"{"baz": "qux", "foo": ["a", 2, "c"]}"#, r#"[{"op": "test", "path": "/baz", "value": "qux"}, {"op": "test", "path": "/foo/1", "value": 2.0}]"#), Ok(r#"{"baz":"qux","foo":["a",2,"c"]}"#.to_string()));
+		assert_eq!(patched(r#"{"foo": "bar"}"#, r#"[{"op": "add", "path": "", "value": [1]}]"#), Ok("[1]".to_string()));
+	}
+
+	#[test]
+	fn test_failing_patch_is_atomic() {
+		let mut document:Json = json(r#"{"baz": "qux"}"#);
+		let error:String = document.apply_patch(&json(r#"[{"op": "add", "path": "/a", "value": 1}, {"op": "test", "path": "/baz", "value": "bar"}]"#)).unwrap_err().to_string();
+		assert!(error.starts_with("Could not apply json patch operation 1: test failed"));
+		assert_eq!(document.to_string(), r#"{"baz":"qux"}"#);
+
+		assert!(patched(r#"{"foo": {}}"#, r#"[{"op": "move", "from": "/foo", "path": "/foo/child"}]"#).is_err());
+		assert!(patched(r#"{}"#, r#"[{"op": "remove", "path": "/missing"}]"#).is_err());
+		assert!(patched(r#"{}"#, r#"[{"op": "invalid", "path": ""}]"#).is_err());
+		assert!(patched(r#"{}"#, r#"[{"op": "add", "path": "/a"}]"#).is_err());
+	}
+
+	#[test]
+	fn test_equals() {
+		assert!(json(r#"{"a": 1, "b": [1.0, {"c": null}]}"#).equals(&json(r#"{"b": [1, {"c": null}], "a": 1.0}"#)));
+		assert!(!json(r#"{"a": 1}"#).equals(&json(r#"{"a": 1, "b": 2}"#)));
+		assert!(!json(r#"[1, 2]"#).equals(&json(r#"[2, 1]"#)));
+	}
+
+	#[test]
+	fn test_diff_patch() {
+		let pairs:[(&str, &str); 4] = [
+			(r#"{"a": 1, "b": {"c": [1, 2, 3]}, "d": "x"}"#, r#"{"a": 2, "b": {"c": [1, 5]}, "e": true}"#),
+			(r#"[1, 2]"#, r#"[1, 2, {"x": 3}, 4]"#),
+			(r#"{"a/b": {"~": 1}}"#, r#"{"a/b": {"~": 2}}"#),
+			(r#""text""#, r#"{"now": "dict"}"#)
+		];
+		for (source, target) in pairs {
+			let patch:Json = Json::diff_patch(&json(source), &json(target));
+			let mut patched:Json = json(source);
+			patched.apply_patch(&patch).unwrap();
+			assert!(patched.equals(&json(target)), "{source} -> {target} with {patch}");
+		}
+		assert_eq!(Json::diff_patch(&json(r#"{"a": [1, 2]}"#), &json(r#"{"a": [1, 3]}"#)).to_string(), r#"[{"op":"replace","path":"/a/1","value":3}]"#);
+		assert_eq!(Json::diff_patch(&json("[1]"), &json("[1.0]")), Json::Array(Vec::new()));
+	}
+
+	#[test]
+	fn test_merge_patch() {
+		let mut document:Json = json(r#"{"title": "Goodbye!", "author": {"givenName": "John", "familyName": "Doe"}, "tags": ["example", "sample"], "content": "This will be unchanged"}"#);
+		document.merge_patch(&json(r#"{"title": "Hello!", "phoneNumber": "+01-123-456-7890", "author": {"familyName": null}, "tags": ["example"]}"#));
+		assert!(document.equals(&json(r#"{"title": "Hello!", "author": {"givenName": "John"}, "tags": ["example"], "content": "This will be unchanged", "phoneNumber": "+01-123-456-7890"}"#)));
+
+		for (original, patch, result) in [(r#"{"a": "b"}"#, r#"{"a": "c"}"#, r#"{"a":"c"}"#), (r#"{"a": ["b"]}"#, r#"{"a": "c"}"#, r#"{"a":"c"}"#), (r#"["a", "b"]"#, r#"["c", "d"]"#, r#"["c","d"]"#), (r#"{"a": "foo"}"#, "null", "null"), (r#"{"e": null}"#, r#"{"a": 1}"#, r#"{"e":null,"a":1}"#), ("[1, 2]", r#"{"a": "b", "c": null}"#, r#"{"a":"b"}"#), ("{}", r#"{"a": {"bb": {"ccc": null}}}"#, r#"{"a":{"bb":{}}}"#)] {
+			let mut document:Json = json(original);
+			document.merge_patch(&json(patch));
+			assert_eq!(document.to_string(), result, "{original} + {patch}");
+		}
+	}
+}
\ No newline at end of file
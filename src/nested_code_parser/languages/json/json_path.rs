@@ -0,0 +1,538 @@
+use super::{ Json, JsonFlavor };
+use regex::Regex;
+use std::{ borrow::Cow, error::Error };
+
+
+
+const BLANK_CHARS:[char; 4] = [' ', '\t', '\n', '\r'];
+const MAX_INTEGER:i64 = (1 << 53) - 1;
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct JsonPath {
+	segments:Vec<PathSegment>
+}
+impl JsonPath {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Parse an RFC 9535 JSONPath query like '$.store.book[?@.price < 10].title'.
+	pub fn new(query:&str) -> Result<JsonPath, Box<dyn Error>> {
+		let mut parser:PathParser = PathParser { query, chars: query.chars().collect(), cursor: 0 };
+		let path:PathQuery = parser.query()?;
+		if !path.absolute {
+			return Err(parser.error("the query should start with '$'"));
+		}
+		if parser.cursor != parser.chars.len() {
+			return Err(parser.error("unexpected character"));
+		}
+		Ok(JsonPath { segments: path.segments })
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Select all nodes in the document the query matches, in document order.
+	pub fn select<'a>(&self, json:&'a Json) -> Vec<&'a Json> {
+		select_segments(&self.segments, json, json)
+	}
+}
+
+
+
+impl Json {
+
+	/* QUERY METHODS */
+
+	/// Select all nodes matching an RFC 9535 JSONPath query.
+	pub fn query(&self, query:&str) -> Result<Vec<&Json>, Box<dyn Error>> {
+		Ok(JsonPath::new(query)?.select(self))
+	}
+}
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+struct PathQuery {
+	absolute:bool,
+	segments:Vec<PathSegment>
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum PathSegment {
+	Child(Vec<PathSelector>),
+	Descendant(Vec<PathSelector>)
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum PathSelector {
+	Name(String),
+	Wildcard,
+	Index(i64),
+	Slice(Option<i64>, Option<i64>, Option<i64>),
+	Filter(FilterExpression)
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum FilterExpression {
+	Or(Vec<FilterExpression>),
+	And(Vec<FilterExpression>),
+	Not(Box<FilterExpression>),
+	Comparison(FilterValue, ComparisonOperator, FilterValue),
+	Test(FilterValue)
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum FilterValue {
+	Literal(Json),
+	Query(PathQuery),
+	Function(String, Vec<FilterValue>)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum ComparisonOperator { Equal, NotEqual, Less, LessOrEqual, Greater, GreaterOrEqual }
+
+/// The result of evaluating a value in a filter. Functions like 'match' result in a logical value, queries in a list of nodes and other values in a single value or nothing.
+enum EvaluatedValue<'a> {
+	Nodes(Vec<&'a Json>),
+	Value(Option<Cow<'a, Json>>),
+	Logical(bool)
+}
+impl<'a> EvaluatedValue<'a> {
+
+	/// Get the single value to compare. Lists of nodes only have a value when they contain exactly one node.
+	fn into_value(self) -> Option<Cow<'a, Json>> {
+		match self {
+			EvaluatedValue::Nodes(nodes) if nodes.len() == 1 => Some(Cow::Borrowed(nodes[0])),
+			EvaluatedValue::Nodes(_) | EvaluatedValue::Logical(_) => None,
+			EvaluatedValue::Value(value) => value
+		}
+	}
+}
+
+
+
+/* EVALUATION */
+
+/// Apply a list of segments to a node.
+fn select_segments<'a>(segments:&[PathSegment], root:&'a Json, node:&'a Json) -> Vec<&'a Json> {
+	let mut nodes:Vec<&Json> = vec![node];
+	for segment in segments {
+		nodes = nodes.into_iter().flat_map(|node| match segment {
+			PathSegment::Child(selectors) => select_children(selectors, root, node),
+			PathSegment::Descendant(selectors) => descendants_of(node).into_iter().flat_map(|descendant| select_children(selectors, root, descendant)).collect()
+		}).collect();
+	}
+	nodes
+}
+
+/// Get a node and all of its descendants in document order.
+fn descendants_of(node:&Json) -> Vec<&Json> {
+	let mut descendants:Vec<&Json> = Vec::new();
+	let mut stack:Vec<&Json> = vec![node];
+	while let Some(node) = stack.pop() {
+		descendants.push(node);
+		stack.extend(children_of(node).into_iter().rev());
+	}
+	descendants
+}
+
+/// Get the values of a dict or the items of an array.
+fn children_of(node:&Json) -> Vec<&Json> {
+	match node {
+		Json::Dict(entries) => entries.iter().map(|(_, value)| value).collect(),
+		Json::Array(items) => items.iter().collect(),
+		_ => Vec::new()
+	}
+}
+
+/// Apply the selectors of a segment to a node.
+fn select_children<'a>(selectors:&[PathSelector], root:&'a Json, node:&'a Json) -> Vec<&'a Json> {
+	selectors.iter().flat_map(|selector| match selector {
+		PathSelector::Name(name) => node.as_object().and_then(|_| node.get(name)).into_iter().collect(),
+		PathSelector::Wildcard => children_of(node),
+		PathSelector::Index(index) => node.as_array().and_then(|items| normalize_index(*index, items.len()).and_then(|index| items.get(index))).into_iter().collect(),
+		PathSelector::Slice(start, end, step) => node.as_array().map(|items| slice_indexes(*start, *end, *step, items.len()).into_iter().map(|index| &items[index]).collect()).unwrap_or_default(),
+		PathSelector::Filter(expression) => children_of(node).into_iter().filter(|child| evaluate_filter(expression, root, child)).collect()
+	}).collect()
+}
+
+/// Turn a possibly negative index into an index from the start.
+fn normalize_index(index:i64, length:usize) -> Option<usize> {
+	let index:i64 = if index < 0 { length as i64 + index } else { index };
+	if index >= 0 && (index as usize) < length { Some(index as usize) } else { None }
+}
+
+/// Get the indexes selected by a slice, following the RFC 9535 slice algorithm.
+fn slice_indexes(start:Option<i64>, end:Option<i64>, step:Option<i64>, length:usize) -> Vec<usize> {
+	let length:i64 = length as i64;
+	let step:i64 = step.unwrap_or(1);
+	if step == 0 {
+		return Vec::new();
+	}
+	let normalize = |index:i64| if index >= 0 { index } else { length + index };
+	let (mut cursor, bound) = if step > 0 {
+		(normalize(start.unwrap_or(0)).clamp(0, length), normalize(end.unwrap_or(length)).clamp(0, length))
+	} else {
+		(normalize(start.unwrap_or(length - 1)).clamp(-1, length - 1), normalize(end.unwrap_or(-length - 1)).clamp(-1, length - 1))
+	};
+	let mut indexes:Vec<usize> = Vec::new();
+	while (step > 0 && cursor < bound) || (step < 0 && bound < cursor) {
+		indexes.push(cursor as usize);
+		cursor = match cursor.checked_add(step) {
+			Some(next_cursor) => next_cursor,
+			None => break
+		};
+	}
+	indexes
+}
+
+/// Check if a node matches a filter expression.
+fn evaluate_filter(expression:&FilterExpression, root:&Json, current:&Json) -> bool {
+	match expression {
+		FilterExpression::Or(expressions) => expressions.iter().any(|expression| evaluate_filter(expression, root, current)),
+		FilterExpression::And(expressions) => expressions.iter().all(|expression| evaluate_filter(expression, root, current)),
+		FilterExpression::Not(expression) => !evaluate_filter(expression, root, current),
+		FilterExpression::Comparison(left, operator, right) => {
+			let left:Option<Cow<Json>> = evaluate_value(left, root, current).into_value();
+			let right:Option<Cow<Json>> = evaluate_value(right, root, current).into_value();
+			let (left, right) = (left.as_deref(), right.as_deref());
+			match operator {
+				ComparisonOperator::Equal => values_equal(left, right),
+				ComparisonOperator::NotEqual => !values_equal(left, right),
+				ComparisonOperator::Less => value_less(left, right),
+				ComparisonOperator::LessOrEqual => value_less(left, right) || values_equal(left, right),
+				ComparisonOperator::Greater => value_less(right, left),
+				ComparisonOperator::GreaterOrEqual => value_less(right, left) || values_equal(left, right)
+			}
+		},
+		FilterExpression::Test(value) => match evaluate_value(value, root, current) {
+			EvaluatedValue::Nodes(nodes) => !nodes.is_empty(),
+			EvaluatedValue::Logical(result) => result,
+			EvaluatedValue::Value(value) => value.is_some()
+		}
+	}
+}
+
+/// Evaluate a literal, query or function in a filter.
+fn evaluate_value<'a>(value:&'a FilterValue, root:&'a Json, current:&'a Json) -> EvaluatedValue<'a> {
+	match value {
+		FilterValue::Literal(literal) => EvaluatedValue::Value(Some(Cow::Borrowed(literal))),
+		FilterValue::Query(query) => EvaluatedValue::Nodes(select_segments(&query.segments, root, if query.absolute { root } else { current })),
+		FilterValue::Function(name, arguments) => {
+			let mut arguments = arguments.iter().map(|argument| evaluate_value(argument, root, current));
+			match name.as_str() {
+				"length" => EvaluatedValue::Value(arguments.next().and_then(|argument| argument.into_value()).and_then(|value| match value.as_ref() {
+					Json::String(text) => Some(text.chars().count()),
+					Json::Array(_) | Json::Dict(_) => Some(value.len()),
+					_ => None
+				}).map(|length| Cow::Owned(Json::Integer(length as i64)))),
+				"count" => EvaluatedValue::Value(match arguments.next() {
+					Some(EvaluatedValue::Nodes(nodes)) => Some(Cow::Owned(Json::Integer(nodes.len() as i64))),
+					_ => None
+				}),
+				"value" => EvaluatedValue::Value(arguments.next().and_then(|argument| argument.into_value())),
+				"match" | "search" => {
+					let text:Option<Cow<Json>> = arguments.next().and_then(|argument| argument.into_value());
+					let pattern:Option<Cow<Json>> = arguments.next().and_then(|argument| argument.into_value());
+					EvaluatedValue::Logical(match (text.as_deref(), pattern.as_deref()) {
+						(Some(Json::String(text)), Some(Json::String(pattern))) => {
+							let pattern:String = if name == "match" { format!("^(?:{pattern})$") } else { pattern.to_string() };
+							Regex::new(&pattern).map(|regex| regex.is_match(text)).unwrap_or(false)
+						},
+						_ => false
+					})
+				},
+				_ => EvaluatedValue::Value(None)
+			}
+		}
+	}
+}
+
+/// Compare two filter values for equality. Two missing values are equal.
+fn values_equal(left:Option<&Json>, right:Option<&Json>) -> bool {
+	match (left, right) {
+		(Some(left), Some(right)) => left.equals(right),
+		(None, None) => true,
+		_ => false
+	}
+}
+
+/// Check if a value is less than another. Only numbers and strings can be ordered.
+fn value_less(left:Option<&Json>, right:Option<&Json>) -> bool {
+	match (left, right) {
+		(Some(Json::String(left)), Some(Json::String(right))) => left < right,
+		(Some(left), Some(right)) => matches!((left.as_f64(), right.as_f64()), (Some(left), Some(right)) if left < right),
+		_ => false
+	}
+}
+
+
+
+/* PARSING */
+
+struct PathParser<'a> {
+	query:&'a str,
+	chars:Vec<char>,
+	cursor:usize
+}
+impl<'a> PathParser<'a> {
+
+	/// Create an error at the cursor.
+	fn error(&self, message:&str) -> Box<dyn Error> {
+		format!("Could not parse JSONPath '{}', {message} at position {}.", self.query, self.cursor).into()
+	}
+
+	/// Get the character at the cursor.
+	fn peek(&self) -> Option<char> {
+		self.chars.get(self.cursor).copied()
+	}
+
+	/// Check if the text at the cursor starts with the given string.
+	fn starts_with(&self, text:&str) -> bool {
+		text.chars().enumerate().all(|(index, char)| self.chars.get(self.cursor + index) == Some(&char))
+	}
+
+	/// Move the cursor past the given string if the text at the cursor starts with it.
+	fn consume(&mut self, text:&str) -> bool {
+		let matches:bool = self.starts_with(text);
+		if matches {
+			self.cursor += text.chars().count();
+		}
+		matches
+	}
+
+	/// Move the cursor past the given string or return an error.
+	fn expect(&mut self, text:&str) -> Result<(), Box<dyn Error>> {
+		if self.consume(text) { Ok(()) } else { Err(self.error(&format!("expected '{text}'"))) }
+	}
+
+	/// Move the cursor past any blank space.
+	fn skip_blank(&mut self) {
+		while self.peek().map(|char| BLANK_CHARS.contains(&char)).unwrap_or(false) {
+			self.cursor += 1;
+		}
+	}
+
+	/// Parse a query starting with '$' or '@' and its segments.
+	fn query(&mut self) -> Result<PathQuery, Box<dyn Error>> {
+		let absolute:bool = match self.peek() {
+			Some('$') => true,
+			Some('@') => false,
+			_ => return Err(self.error("expected '$' or '@'"))
+		};
+		self.cursor += 1;
+		let mut segments:Vec<PathSegment> = Vec::new();
+		loop {
+			let segment_start:usize = self.cursor;
+			self.skip_blank();
+			if self.consume("..") {
+				segments.push(PathSegment::Descendant(self.segment_selectors(true)?));
+			} else if self.consume(".") {
+				segments.push(PathSegment::Child(self.segment_selectors(false)?));
+			} else if self.peek() == Some('[') {
+				segments.push(PathSegment::Child(self.bracketed_selection()?));
+			} else {
+				self.cursor = segment_start;
+				return Ok(PathQuery { absolute, segments });
+			}
+		}
+	}
+
+	/// Parse the selectors of a segment after its dots, either a wildcard or a member name. Only '..' can be followed by a bracketed list.
+	fn segment_selectors(&mut self, after_double_dot:bool) -> Result<Vec<PathSelector>, Box<dyn Error>> {
+		if after_double_dot && self.peek() == Some('[') {
+			return self.bracketed_selection();
+		}
+		if self.consume("*") {
+			return Ok(vec![PathSelector::Wildcard]);
+		}
+		let name_start:usize = self.cursor;
+		while self.peek().map(|char| char.is_alphabetic() || char == '_' || !char.is_ascii() || (self.cursor > name_start && char.is_ascii_digit())).unwrap_or(false) {
+			self.cursor += 1;
+		}
+		if self.cursor == name_start {
+			return Err(self.error(if after_double_dot { "expected a name, '*' or '[' after '..'" } else { "expected a name or '*' after '.'" }));
+		}
+		Ok(vec![PathSelector::Name(self.chars[name_start..self.cursor].iter().collect())])
+	}
+
+	/// Parse a bracketed, comma separated list of selectors.
+	fn bracketed_selection(&mut self) -> Result<Vec<PathSelector>, Box<dyn Error>> {
+		self.expect("[")?;
+		let mut selectors:Vec<PathSelector> = Vec::new();
+		loop {
+			self.skip_blank();
+			selectors.push(self.selector()?);
+			self.skip_blank();
+			if self.consume("]") {
+				return Ok(selectors);
+			}
+			self.expect(",")?;
+		}
+	}
+
+	/// Parse a single selector in brackets.
+	fn selector(&mut self) -> Result<PathSelector, Box<dyn Error>> {
+		match self.peek() {
+			Some('\'' | '"') => Ok(PathSelector::Name(self.string_literal()?)),
+			Some('*') => { self.cursor += 1; Ok(PathSelector::Wildcard) },
+			Some('?') => {
+				self.cursor += 1;
+				self.skip_blank();
+				Ok(PathSelector::Filter(self.logical_or()?))
+			},
+			_ => {
+				let start:Option<i64> = self.integer()?;
+				self.skip_blank();
+				if !self.consume(":") {
+					return start.map(PathSelector::Index).ok_or_else(|| self.error("expected a selector"));
+				}
+				self.skip_blank();
+				let end:Option<i64> = self.integer()?;
+				self.skip_blank();
+				let step:Option<i64> = if self.consume(":") { self.skip_blank(); self.integer()? } else { None };
+				Ok(PathSelector::Slice(start, end, step))
+			}
+		}
+	}
+
+	/// Parse an optional integer without leading zeros, within the I-JSON range of ±(2^53 - 1) required by RFC 9535.
+	fn integer(&mut self) -> Result<Option<i64>, Box<dyn Error>> {
+		let start:usize = self.cursor;
+		self.consume("-");
+		while self.peek().map(|char| char.is_ascii_digit()).unwrap_or(false) {
+			self.cursor += 1;
+		}
+		let text:String = self.chars[start..self.cursor].iter().collect();
+		match text.as_str() {
+			"" => Ok(None),
+			"-" | "-0" => Err(self.error("invalid integer")),
+			_ if text.trim_start_matches('-').len() > 1 && text.trim_start_matches('-').starts_with('0') => Err(self.error("integers can not have leading zeros")),
+			_ => text.parse::<i64>().ok().filter(|value| (-MAX_INTEGER..=MAX_INTEGER).contains(value)).map(Some).ok_or_else(|| self.error("integer out of range"))
+		}
+	}
+
+	/// Parse a single or double quoted string literal.
+	fn string_literal(&mut self) -> Result<String, Box<dyn Error>> {
+		let quote:char = self.peek().ok_or_else(|| self.error("expected a string"))?;
+		self.cursor += 1;
+		let mut escaped_contents:String = String::new();
+		loop {
+			match self.peek() {
+				None => return Err(self.error("unterminated string")),
+				Some(char) if char == quote => { self.cursor += 1; break; },
+				Some('\\') if self.chars.get(self.cursor + 1) == Some(&quote) => { escaped_contents.push(quote); self.cursor += 2; },
+				Some('\\') => {
+					escaped_contents.push('\\');
+					escaped_contents.extend(self.chars.get(self.cursor + 1));
+					self.cursor += 2;
+				},
+				Some(char) => { escaped_contents.push(char); self.cursor += 1; }
+			}
+		}
+		Ok(Json::decode_string(&escaped_contents, JsonFlavor::Strict))
+	}
+
+	/// Parse expressions separated by '||'.
+	fn logical_or(&mut self) -> Result<FilterExpression, Box<dyn Error>> {
+		let mut expressions:Vec<FilterExpression> = vec![self.logical_and()?];
+		while { self.skip_blank(); self.consume("||") } {
+			self.skip_blank();
+			expressions.push(self.logical_and()?);
+		}
+		Ok(if expressions.len() == 1 { expressions.remove(0) } else { FilterExpression::Or(expressions) })
+	}
+
+	/// Parse expressions separated by '&&'.
+	fn logical_and(&mut self) -> Result<FilterExpression, Box<dyn Error>> {
+		let mut expressions:Vec<FilterExpression> = vec![self.basic_expression()?];
+		while { self.skip_blank(); self.consume("&&") } {
+			self.skip_blank();
+			expressions.push(self.basic_expression()?);
+		}
+		Ok(if expressions.len() == 1 { expressions.remove(0) } else { FilterExpression::And(expressions) })
+	}
+
+	/// Parse a negation, a parenthesized expression, a comparison or a test.
+	fn basic_expression(&mut self) -> Result<FilterExpression, Box<dyn Error>> {
+		if self.consume("!") {
+			self.skip_blank();
+			return Ok(FilterExpression::Not(Box::new(self.basic_expression()?)));
+		}
+		if self.consume("(") {
+			self.skip_blank();
+			let expression:FilterExpression = self.logical_or()?;
+			self.skip_blank();
+			self.expect(")")?;
+			return Ok(expression);
+		}
+		let left:FilterValue = self.filter_value()?;
+		let operator_start:usize = self.cursor;
+		self.skip_blank();
+		let operator:ComparisonOperator = match () {
+			_ if self.consume("==") => ComparisonOperator::Equal,
+			_ if self.consume("!=") => ComparisonOperator::NotEqual,
+			_ if self.consume("<=") => ComparisonOperator::LessOrEqual,
+			_ if self.consume(">=") => ComparisonOperator::GreaterOrEqual,
+			_ if self.consume("<") => ComparisonOperator::Less,
+			_ if self.consume(">") => ComparisonOperator::Greater,
+			_ => {
+				self.cursor = operator_start;
+				return match left {
+					FilterValue::Literal(_) => Err(self.error("a literal can not be used as test")),
+					left => Ok(FilterExpression::Test(left))
+				};
+			}
+		};
+		self.skip_blank();
+		Ok(FilterExpression::Comparison(left, operator, self.filter_value()?))
+	}
+
+	/// Parse a literal, query or function call.
+	fn filter_value(&mut self) -> Result<FilterValue, Box<dyn Error>> {
+		match self.peek() {
+			Some('$' | '@') => Ok(FilterValue::Query(self.query()?)),
+			Some('\'' | '"') => Ok(FilterValue::Literal(Json::String(self.string_literal()?))),
+			Some(char) if char == '-' || char.is_ascii_digit() => {
+				let start:usize = self.cursor;
+				while self.peek().map(|char| char.is_ascii_digit() || ['-', '+', '.', 'e', 'E'].contains(&char)).unwrap_or(false) {
+					self.cursor += 1;
+				}
+				let text:String = self.chars[start..self.cursor].iter().collect();
+				Json::new_strict(&text).ok().filter(|value| value.as_f64().is_some()).map(FilterValue::Literal).ok_or_else(|| self.error("invalid number"))
+			},
+			_ => {
+				for (literal, value) in [("true", Json::Bool(true)), ("false", Json::Bool(false)), ("null", Json::Null)] {
+					if self.consume(literal) {
+						return Ok(FilterValue::Literal(value));
+					}
+				}
+				let name_start:usize = self.cursor;
+				while self.peek().map(|char| char.is_ascii_lowercase() || char == '_' || (self.cursor > name_start && char.is_ascii_digit())).unwrap_or(false) {
+					self.cursor += 1;
+				}
+				let name:String = self.chars[name_start..self.cursor].iter().collect();
+				if name.is_empty() || !self.consume("(") {
+					return Err(self.error("expected a literal, query or function"));
+				}
+				let mut arguments:Vec<FilterValue> = Vec::new();
+				self.skip_blank();
+				while !self.consume(")") {
+					if !arguments.is_empty() {
+						self.expect(",")?;
+						self.skip_blank();
+					}
+					arguments.push(self.filter_value()?);
+					self.skip_blank();
+				}
+				if !["length", "count", "value", "match", "search"].contains(&name.as_str()) {
+					return Err(self.error(&format!("unknown function '{name}'")));
+				}
+				Ok(FilterValue::Function(name, arguments))
+			}
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonPath };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn store() -> Json {
+		Json::new_strict(r#"{ "store": {
+			"book": [
+				{ "category": "reference", "author": "Nigel Rees", "title": "Sayings of the Century", "price": 8.95 },
+				{ "category": "fiction", "author": "Evelyn Waugh", "title": "Sword of Honour", "price": 12.99 },
+				{ "category": "fiction", "author": "Herman Melville", "title": "Moby Dick", "isbn": "0-553-21311-3", "price": 8.99 },
+				{ "category": "fiction", "author": "J. R. R. Tolkien", "title": "The Lord of the Rings", "isbn": "0-395-19395-8", "price": 22.99 }
+			],
+			"bicycle": { "color": "red", "price": 399 }
+		} }"#).unwrap()
+	}
+
+	fn query(json:&Json, query:&str) -> String {
+		Json::Array(json.query(query).unwrap().into_iter().cloned().collect()).to_string()
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_rfc_store_examples() {
+		let json:Json = store();
+		assert_eq!(query(&json, "$.store.book[*].author"), r#"["Nigel Rees","Evelyn Waugh","Herman Melville","J. R. R. Tolkien"]"#);
+		assert_eq!(query(&json, "$..author"), r#"["Nigel Rees","Evelyn Waugh","Herman Melville","J. R. R. Tolkien"]"#);
+		assert_eq!(query(&json, "$.store..price"), "[8.95,12.99,8.99,22.99,399]");
+		assert_eq!(query(&json, "$..book[2].title"), r#"["Moby Dick"]"#);
+		assert_eq!(query(&json, "$..book[-1].title"), r#"["The Lord of the Rings"]"#);
+		assert_eq!(query(&json, "$..book[0,1].title"), r#"["Sayings of the Century","Sword of Honour"]"#);
+		assert_eq!(query(&json, "$..book[:2].title"), r#"["Sayings of the Century","Sword of Honour"]"#);
+		assert_eq!(query(&json, "$..book[?@.isbn].title"), r#"["Moby Dick","The Lord of the Rings"]"#);
+		assert_eq!(query(&json, "$..book[?(@.price < 10)].title"), r#"["Sayings of the Century","Moby Dick"]"#);
+		assert_eq!(query(&json, "$..book[?@.price<10 && @.category=='fiction'].title"), r#"["Moby Dick"]"#);
+		assert_eq!(query(&json, "$..book[?@.price > $.store.bicycle.price]"), "[]");
+		assert_eq!(json.query("$..*").unwrap().len(), 27);
+	}
+
+	#[test]
+	fn test_slices() {
+		let json:Json = Json::new_strict(r#"["a", "b", "c", "d", "e", "f", "g"]"#).unwrap();
+		assert_eq!(query(&json, "$[1:3]"), r#"["b","c"]"#);
+		assert_eq!(query(&json, "$[5:]"), r#"["f","g"]"#);
+		assert_eq!(query(&json, "$[1:5:2]"), r#"["b","d"]"#);
+		assert_eq!(query(&json, "$[5:1:-2]"), r#"["f","d"]"#);
+		assert_eq!(query(&json, "$[::-1]"), r#"["g","f","e","d","c","b","a"]"#);
+		assert_eq!(query(&json, "$[-2:]"), r#"["f","g"]"#);
+		assert_eq!(query(&json, "$[::0]"), "[]");
+		assert_eq!(query(&json, "$[0, 0, -1, 10]"), r#"["a","a","g"]"#);
+		assert_eq!(query(&json, "$[1::9007199254740991]"), r#"["b"]"#);
+		assert_eq!(query(&json, "$[-1:-9007199254740991:-9007199254740991]"), r#"["g"]"#);
+	}
+
+	#[test]
+	fn test_names_and_filters() {
+		let json:Json = Json::new_strict(r#"{"o": {"j j": {"k.k": 3}}, "'": {"@": 2}, "a": [3, 5, 1, 2, 4, 6, {"b": "j"}, {"b": "k"}, {"b": {}}, {"b": "kilo"}]}"#).unwrap();
+		assert_eq!(query(&json, "$.o['j j']['k.k']"), "[3]");
+		assert_eq!(query(&json, r#"$.o["j j"]["k.k"]"#), "[3]");
+		assert_eq!(query(&json, r#"$["'"]["@"]"#), "[2]");
+		assert_eq!(query(&json, "$['\\'']"), r#"[{"@":2}]"#);
+		assert_eq!(query(&json, "$.a[?@ > 3.5]"), "[5,4,6]");
+		assert_eq!(query(&json, "$.a[?@.b == 'kilo']"), r#"[{"b":"kilo"}]"#);
+		assert_eq!(query(&json, "$.a[?!@.b]"), "[3,5,1,2,4,6]");
+		assert_eq!(query(&json, "$.a[?@ < 2 || @.b == 'k']"), r#"[1,{"b":"k"}]"#);
+		assert_eq!(query(&json, "$.a[?match(@.b, 'k.*')]"), r#"[{"b":"k"},{"b":"kilo"}]"#);
+		assert_eq!(query(&json, "$.a[?search(@.b, 'il')]"), r#"[{"b":"kilo"}]"#);
+		assert_eq!(query(&json, "$.a[?length(@.b) == 4]"), r#"[{"b":"kilo"}]"#);
+		assert_eq!(query(&json, "$[?count(@.*) > 1]"), r#"[[3,5,1,2,4,6,{"b":"j"},{"b":"k"},{"b":{}},{"b":"kilo"}]]"#);
+		assert_eq!(query(&json, "$.a[?value(@.b) == 'j']"), r#"[{"b":"j"}]"#);
+		assert_eq!(query(&json, "$.a[?@.missing == @.other]"), query(&json, "$.a[*]"));
+	}
+
+	#[test]
+	fn test_returns_references() {
+		let json:Json = store();
+		let nodes:Vec<&Json> = JsonPath::new("$.store.bicycle").unwrap().select(&json);
+		assert!(std::ptr::eq(nodes[0], &json["store"]["bicycle"]));
+	}
+
+	#[test]
+	fn test_invalid_queries() {
+		for invalid in ["", "store", "$.", "$[", "$[01]", "$['a'", "$[?@ == ]", "$[?unknown(@)]", "$[?1]", "$.a b", "$.[0]", "$[9007199254740992]", "$[-9007199254740992]", "$[1::9223372036854775807]", "$[-9223372036854775808]"] {
+			assert!(JsonPath::new(invalid).is_err(), "{invalid}");
+		}
+	}
+}
\ No newline at end of file
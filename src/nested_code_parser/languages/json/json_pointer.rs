@@ -0,0 +1,119 @@
+use super::Json;
+use std::error::Error;
+
+
+
+const APPEND_TOKEN:&str = "-";
+
+
+
+impl Json {
+
+	/* POINTER METHODS */
+
+	/// Split an RFC 6901 JSON Pointer like '/a/b~1c/0' into its unescaped reference tokens. The empty pointer refers to the whole document.
+	pub fn parse_pointer(pointer:&str) -> Result<Vec<String>, Box<dyn Error>> {
+		if pointer.is_empty() {
+			return Ok(Vec::new());
+		}
+		if !pointer.starts_with('/') {
+			return Err(format!("Invalid json pointer '{pointer}', it should start with '/'.").into());
+		}
+		pointer[1..].split('/').map(|token| {
+			let mut output:String = String::with_capacity(token.len());
+			let mut chars = token.chars();
+			while let Some(char) = chars.next() {
+				if char != '~' {
+					output.push(char);
+					continue;
+				}
+				match chars.next() {
+					Some('0') => output.push('~'),
+					Some('1') => output.push('/'),
+					_ => return Err(format!("Invalid json pointer '{pointer}', '~' should be followed by '0' or '1'.").into())
+				}
+			}
+			Ok(output)
+		}).collect()
+	}
+
+	/// Build an RFC 6901 JSON Pointer from reference tokens.
+	pub fn build_pointer<T:AsRef<str>>(tokens:&[T]) -> String {
+		tokens.iter().map(|token| format!("/{}", token.as_ref().replace('~', "~0").replace('/', "~1"))).collect()
+	}
+
+	/// Get the value an RFC 6901 JSON Pointer refers to.
+	pub fn pointer(&self, pointer:&str) -> Option<&Json> {
+		let mut target:&Json = self;
+		for token in Self::parse_pointer(pointer).ok()? {
+			target = match target {
+				Json::Array(items) => items.get(Self::pointer_array_index(&token)?)?,
+				_ => target.get(&token)?
+			};
+		}
+		Some(target)
+	}
+
+	/// Get the mutable value an RFC 6901 JSON Pointer refers to.
+	pub fn pointer_mut(&mut self, pointer:&str) -> Option<&mut Json> {
+		let mut target:&mut Json = self;
+		for token in Self::parse_pointer(pointer).ok()? {
+			target = match target {
+				Json::Array(items) => items.get_mut(Self::pointer_array_index(&token)?)?,
+				_ => target.get_mut(&token)?
+			};
+		}
+		Some(target)
+	}
+
+	/// Parse a reference token as array index. Indexes can not have leading zeros or signs.
+	pub(super) fn pointer_array_index(token:&str) -> Option<usize> {
+		if token.is_empty() || (token.len() > 1 && token.starts_with('0')) || !token.chars().all(|char| char.is_ascii_digit()) {
+			return None;
+		}
+		token.parse::<usize>().ok()
+	}
+
+	/// Add a value at the location of a pointer. Dict keys are set, array items are inserted, '-' appends to an array. The empty pointer replaces the whole document.
+	pub fn pointer_add(&mut self, pointer:&str, value:Json) -> Result<(), Box<dyn Error>> {
+		let (parent, token) = match self.pointer_parent_mut(pointer)? {
+			Some(parent_and_token) => parent_and_token,
+			None => { *self = value; return Ok(()); }
+		};
+		match parent {
+			Json::Dict(_) => { parent.insert(&token, value); },
+			Json::Array(items) if token == APPEND_TOKEN => items.push(value),
+			Json::Array(items) => match Self::pointer_array_index(&token).filter(|index| *index <= items.len()) {
+				Some(index) => items.insert(index, value),
+				None => return Err(format!("Could not add value at json pointer '{pointer}', index out of bounds.").into())
+			},
+			_ => return Err(format!("Could not add value at json pointer '{pointer}', the parent is not a dict or array.").into())
+		}
+		Ok(())
+	}
+
+	/// Remove the value at the location of a pointer and return it.
+	pub fn pointer_remove(&mut self, pointer:&str) -> Result<Json, Box<dyn Error>> {
+		let (parent, token) = match self.pointer_parent_mut(pointer)? {
+			Some(parent_and_token) => parent_and_token,
+			None => return Ok(std::mem::replace(self, Json::Null))
+		};
+		let removed:Option<Json> = match parent {
+			Json::Array(_) => Self::pointer_array_index(&token).and_then(|index| parent.remove_at(index)),
+			_ => parent.remove(&token)
+		};
+		removed.ok_or(format!("Could not remove value at json pointer '{pointer}', it does not exist.").into())
+	}
+
+	/// Get the parent of the value a pointer refers to and the last reference token. Returns None for the empty pointer.
+	fn pointer_parent_mut(&mut self, pointer:&str) -> Result<Option<(&mut Json, String)>, Box<dyn Error>> {
+		let mut tokens:Vec<String> = Self::parse_pointer(pointer)?;
+		let Some(last_token) = tokens.pop() else {
+			return Ok(None);
+		};
+		match self.pointer_mut(&Self::build_pointer(&tokens)) {
+			Some(parent) => Ok(Some((parent, last_token))),
+			None => Err(format!("Could not find the parent of json pointer '{pointer}'.").into())
+		}
+	}
+}
\ No newline at end of file
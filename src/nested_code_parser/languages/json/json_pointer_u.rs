@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+	use crate::Json;
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn rfc_example() -> Json {
+		Json::new_strict(r#"{"foo": ["bar", "baz"], "": 0, "a/b": 1, "c%d": 2, "e^f": 3, "g|h": 4, "i\\j": 5, "k\"l": 6, " ": 7, "m~n": 8}"#).unwrap()
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_rfc_examples() {
+		let json:Json = rfc_example();
+		assert_eq!(json.pointer(""), Some(&json));
+		assert_eq!(json.pointer("/foo").unwrap().to_string(), r#"["bar","baz"]"#);
+		for (pointer, expected) in [("/foo/0", Json::String("bar".to_string())), ("/", Json::Integer(0)), ("/a~1b", Json::Integer(1)), ("/c%d", Json::Integer(2)), ("/e^f", Json::Integer(3)), ("/g|h", Json::Integer(4)), ("/i\\j", Json::Integer(5)), ("/k\"l", Json::Integer(6)), ("/ ", Json::Integer(7)), ("/m~0n", Json::Integer(8))] {
+			assert_eq!(json.pointer(pointer), Some(&expected), "{pointer}");
+		}
+	}
+
+	#[test]
+	fn test_invalid_pointers() {
+		let json:Json = rfc_example();
+		for pointer in ["foo", "/foo/01", "/foo/-", "/foo/2", "/m~2n", "/missing/0"] {
+			assert_eq!(json.pointer(pointer), None, "{pointer}");
+		}
+		assert!(Json::parse_pointer("/a~").is_err());
+	}
+
+	#[test]
+	fn test_build_pointer() {
+		assert_eq!(Json::build_pointer(&["a/b", "m~n", "0"]), "/a~1b/m~0n/0");
+		assert_eq!(Json::parse_pointer("/a~1b/m~0n/0").unwrap(), vec!["a/b", "m~n", "0"]);
+		assert_eq!(Json::build_pointer::<&str>(&[]), "");
+	}
+
+	#[test]
+	fn test_pointer_mut() {
+		let mut json:Json = rfc_example();
+		*json.pointer_mut("/foo/1").unwrap() = Json::Null;
+		assert_eq!(json["foo"][1], Json::Null);
+	}
+
+	#[test]
+	fn test_pointer_add_and_remove() {
+		let mut json:Json = Json::new(r#"{"list": [1, 3]}"#).unwrap();
+		json.pointer_add("/list/1", Json::Integer(2)).unwrap();
+		json.pointer_add("/list/-", Json::Integer(4)).unwrap();
+		json.pointer_add("/key", Json::Bool(true)).unwrap();
+		assert_eq!(json.to_string(), r#"{"list":[1,2,3,4],"key":true}"#);
+		assert!(json.pointer_add("/list/9", Json::Null).is_err());
+		assert!(json.pointer_add("/missing/key", Json::Null).is_err());
+
+		assert_eq!(json.pointer_remove("/list/0").unwrap(), Json::Integer(1));
+		assert_eq!(json.pointer_remove("/key").unwrap(), Json::Bool(true));
+		assert!(json.pointer_remove("/key").is_err());
+		assert_eq!(json.to_string(), r#"{"list":[2,3,4]}"#);
+	}
+}
\ No newline at end of file
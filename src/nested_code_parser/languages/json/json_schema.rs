@@ -0,0 +1,361 @@
+use super::Json;
+use regex::Regex;
+use std::{ collections::HashMap, error::Error, fmt::{ self, Display, Formatter } };
+
+
+
+const SKIPPED_KEYWORDS:[&str; 4] = ["const", "enum", "default", "examples"];
+
+
+
+#[derive(Clone, Debug)]
+pub struct JsonSchema {
+	schema:Json,
+	regexes:HashMap<String, Regex>
+}
+impl JsonSchema {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new JSON Schema (draft 2020-12) validator. Supports the core applicators and the validation vocabulary, with '$ref' to locations in the same schema.
+	pub fn new(schema:Json) -> Result<JsonSchema, Box<dyn Error>> {
+		if !matches!(schema, Json::Dict(_) | Json::Bool(_)) {
+			return Err(format!("Invalid json schema, it should be a dict or bool, found {schema}.").into());
+		}
+		let mut regexes:HashMap<String, Regex> = HashMap::new();
+		Self::compile_schema(&schema, &mut regexes)?;
+		Ok(JsonSchema { schema, regexes })
+	}
+
+	/// Compile all regex patterns in a schema and check that every 'multipleOf' is positive.
+	fn compile_schema(schema:&Json, regexes:&mut HashMap<String, Regex>) -> Result<(), Box<dyn Error>> {
+		let mut patterns:Vec<&str> = Vec::new();
+		match schema {
+			Json::Dict(entries) => for (key, value) in entries {
+				match (key.as_str(), value) {
+					(Some(keyword), _) if SKIPPED_KEYWORDS.contains(&keyword) => continue,
+					(Some("pattern"), Json::String(pattern)) => patterns.push(pattern),
+					(Some("multipleOf"), divisor) if divisor.as_f64().is_some_and(|divisor| divisor <= 0.0) => return Err(format!("Invalid json schema, 'multipleOf' should be positive, found {divisor}.").into()),
+					(Some("patternProperties"), Json::Dict(properties)) => patterns.extend(properties.iter().filter_map(|(pattern, _)| pattern.as_str())),
+					_ => {}
+				}
+				Self::compile_schema(value, regexes)?;
+			},
+			Json::Array(items) => for item in items {
+				Self::compile_schema(item, regexes)?;
+			},
+			_ => {}
+		}
+		for pattern in patterns {
+			if !regexes.contains_key(pattern) {
+				regexes.insert(pattern.to_string(), Regex::new(pattern).map_err(|error| format!("Invalid json schema, could not compile pattern '{pattern}': {error}"))?);
+			}
+		}
+		Ok(())
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Validate an instance against the schema. Returns all found errors.
+	pub fn validate(&self, instance:&Json) -> Result<(), Vec<JsonSchemaError>> {
+		let errors:Vec<JsonSchemaError> = self.check(&self.schema, instance, &[], &[], &[]);
+		if errors.is_empty() { Ok(()) } else { Err(errors) }
+	}
+
+	/// Whether or not an instance is valid against the schema.
+	pub fn is_valid(&self, instance:&Json) -> bool {
+		self.check(&self.schema, instance, &[], &[], &[]).is_empty()
+	}
+
+	/// Check an instance against a (sub-)schema and return the errors. References holds the '$ref' values followed since the instance location last changed, following one of them again would never end.
+	fn check(&self, schema:&Json, instance:&Json, instance_path:&[String], schema_path:&[String], references:&[&str]) -> Vec<JsonSchemaError> {
+		let entries:&Vec<(Json, Json)> = match schema {
+			Json::Bool(true) => return Vec::new(),
+			Json::Bool(false) => return vec![JsonSchemaError::new(instance_path, schema_path, "no value is allowed here".to_string())],
+			Json::Dict(entries) => entries,
+			_ => return Vec::new()
+		};
+		let mut errors:Vec<JsonSchemaError> = Vec::new();
+		for (keyword, value) in entries {
+			let Some(keyword) = keyword.as_str() else { continue; };
+			let keyword_path:Vec<String> = child_path(schema_path, keyword);
+			let check:KeywordCheck = KeywordCheck { schema, keyword, value, instance, instance_path, schema_path, keyword_path: &keyword_path, references };
+
+			// Keywords are checked in separate methods by instance type, keeping the stack used per nested value small.
+			errors.extend(match keyword {
+				"type" | "const" | "enum" | "$ref" | "allOf" | "anyOf" | "oneOf" | "not" | "if" => self.check_applicator(&check),
+				"minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" | "multipleOf" | "minLength" | "maxLength" | "pattern" => self.check_scalar(&check),
+				"prefixItems" | "items" | "contains" | "minItems" | "maxItems" | "uniqueItems" => self.check_array(&check),
+				"properties" | "patternProperties" | "additionalProperties" | "propertyNames" | "required" | "dependentRequired" | "minProperties" | "maxProperties" => self.check_object(&check),
+				_ => Vec::new()
+			});
+		}
+		errors
+	}
+
+	/// Check a keyword that applies to any instance type, including the combining keywords.
+	fn check_applicator(&self, check:&KeywordCheck) -> Vec<JsonSchemaError> {
+		let KeywordCheck { schema, keyword, value, instance, instance_path, schema_path, keyword_path, references } = *check;
+		let error = |message:String| JsonSchemaError::new(instance_path, keyword_path, message);
+		let mut errors:Vec<JsonSchemaError> = Vec::new();
+		match keyword {
+			"type" => {
+				let types:Vec<&str> = match value {
+					Json::Array(types) => types.iter().filter_map(|value| value.as_str()).collect(),
+					value => value.as_str().into_iter().collect()
+				};
+				if !types.iter().any(|type_name| matches_type(instance, type_name)) {
+					errors.push(error(format!("expected type {}, found {}", types.join(" or "), type_name(instance))));
+				}
+			},
+			"const" if !instance.equals(value) => errors.push(error(format!("should be {value}"))),
+			"enum" if !value.as_array().map(|options| options.iter().any(|option| instance.equals(option))).unwrap_or(true) => errors.push(error(format!("should be one of {value}"))),
+			"$ref" => {
+				let reference:&str = value.as_str().unwrap_or_default();
+				match reference.strip_prefix('#').and_then(|pointer| self.schema.pointer(pointer)) {
+					_ if references.contains(&reference) => errors.push(error(format!("reference '{reference}' refers back to itself without a nested value"))),
+					Some(target) => errors.extend(self.check(target, instance, instance_path, keyword_path, &[references, &[reference]].concat())),
+					None => errors.push(error(format!("could not resolve reference '{reference}'")))
+				}
+			},
+
+			// Combining schemas.
+			"allOf" => for (index, sub_schema) in value.as_array().into_iter().flatten().enumerate() {
+				errors.extend(self.check(sub_schema, instance, instance_path, &child_path(keyword_path, index), references));
+			},
+			"anyOf" => {
+				let sub_schemas:&[Json] = value.as_array().map(|sub_schemas| sub_schemas.as_slice()).unwrap_or_default();
+				if !sub_schemas.iter().enumerate().any(|(index, sub_schema)| self.check(sub_schema, instance, instance_path, &child_path(keyword_path, index), references).is_empty()) {
+					errors.push(error("should match at least one schema in anyOf".to_string()));
+				}
+			},
+			"oneOf" => {
+				let sub_schemas:&[Json] = value.as_array().map(|sub_schemas| sub_schemas.as_slice()).unwrap_or_default();
+				let matches:usize = sub_schemas.iter().enumerate().filter(|(index, sub_schema)| self.check(sub_schema, instance, instance_path, &child_path(keyword_path, index), references).is_empty()).count();
+				if matches != 1 {
+					errors.push(error(format!("should match exactly one schema in oneOf, matched {matches}")));
+				}
+			},
+			"not" if self.check(value, instance, instance_path, keyword_path, references).is_empty() => errors.push(error("should not match the schema in not".to_string())),
+			"if" => {
+				let branch:&str = if self.check(value, instance, instance_path, keyword_path, references).is_empty() { "then" } else { "else" };
+				if let Some(branch_schema) = schema.get(branch) {
+					errors.extend(self.check(branch_schema, instance, instance_path, &child_path(schema_path, branch), references));
+				}
+			},
+			_ => {}
+		}
+		errors
+	}
+
+	/// Check a number or string keyword.
+	fn check_scalar(&self, check:&KeywordCheck) -> Vec<JsonSchemaError> {
+		let KeywordCheck { keyword, value, instance, instance_path, keyword_path, .. } = *check;
+		let error = |message:String| JsonSchemaError::new(instance_path, keyword_path, message);
+		let mut errors:Vec<JsonSchemaError> = Vec::new();
+		match keyword {
+
+			// Numbers.
+			"minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" | "multipleOf" if instance.as_f64().is_some() => {
+				let (number, limit) = (instance.as_f64().unwrap(), value.as_f64().unwrap_or(f64::NAN));
+				match keyword {
+					"minimum" if number < limit => errors.push(error(format!("should be at least {value}"))),
+					"maximum" if number > limit => errors.push(error(format!("should be at most {value}"))),
+					"exclusiveMinimum" if number <= limit => errors.push(error(format!("should be more than {value}"))),
+					"exclusiveMaximum" if number >= limit => errors.push(error(format!("should be less than {value}"))),
+					"multipleOf" if !is_multiple_of(instance, value) => errors.push(error(format!("should be a multiple of {value}"))),
+					_ => {}
+				}
+			},
+
+			// Strings.
+			"minLength" | "maxLength" | "pattern" if instance.as_str().is_some() => {
+				let text:&str = instance.as_str().unwrap();
+				match keyword {
+					"minLength" if (text.chars().count() as f64) < value.as_f64().unwrap_or(0.0) => errors.push(error(format!("should be at least {value} characters long"))),
+					"maxLength" if (text.chars().count() as f64) > value.as_f64().unwrap_or(f64::INFINITY) => errors.push(error(format!("should be at most {value} characters long"))),
+					"pattern" if !value.as_str().and_then(|pattern| self.regexes.get(pattern)).map(|regex| regex.is_match(text)).unwrap_or(true) => errors.push(error(format!("should match pattern {value}"))),
+					_ => {}
+				}
+			},
+			_ => {}
+		}
+		errors
+	}
+
+	/// Check an array keyword.
+	fn check_array(&self, check:&KeywordCheck) -> Vec<JsonSchemaError> {
+		let KeywordCheck { schema, keyword, value, instance, instance_path, keyword_path, .. } = *check;
+		let error = |message:String| JsonSchemaError::new(instance_path, keyword_path, message);
+		let mut errors:Vec<JsonSchemaError> = Vec::new();
+		match keyword {
+			"prefixItems" | "items" | "contains" | "minItems" | "maxItems" | "uniqueItems" if instance.as_array().is_some() => {
+				let items:&Vec<Json> = instance.as_array().unwrap();
+				let prefix_length:usize = schema.get("prefixItems").and_then(|prefix| prefix.as_array()).map(|prefix| prefix.len()).unwrap_or(0);
+				match keyword {
+					"prefixItems" => for (index, (item, item_schema)) in items.iter().zip(value.as_array().into_iter().flatten()).enumerate() {
+						errors.extend(self.check(item_schema, item, &child_path(instance_path, index), &child_path(keyword_path, index), &[]));
+					},
+					"items" => for (index, item) in items.iter().enumerate().skip(prefix_length) {
+						errors.extend(self.check(value, item, &child_path(instance_path, index), keyword_path, &[]));
+					},
+					"contains" => {
+						let matches:usize = items.iter().enumerate().filter(|(index, item)| self.check(value, item, &child_path(instance_path, *index), keyword_path, &[]).is_empty()).count();
+						let min_contains:f64 = schema.get("minContains").and_then(|min| min.as_f64()).unwrap_or(1.0);
+						let max_contains:f64 = schema.get("maxContains").and_then(|max| max.as_f64()).unwrap_or(f64::INFINITY);
+						if (matches as f64) < min_contains || (matches as f64) > max_contains {
+							errors.push(error(format!("should contain between {min_contains} and {max_contains} matching items, found {matches}")));
+						}
+					},
+					"minItems" if (items.len() as f64) < value.as_f64().unwrap_or(0.0) => errors.push(error(format!("should have at least {value} items"))),
+					"maxItems" if (items.len() as f64) > value.as_f64().unwrap_or(f64::INFINITY) => errors.push(error(format!("should have at most {value} items"))),
+					"uniqueItems" if value.as_bool() == Some(true) && items.iter().enumerate().any(|(index, item)| items[..index].iter().any(|other| item.equals(other))) => errors.push(error("items should be unique".to_string())),
+					_ => {}
+				}
+			},
+			_ => {}
+		}
+		errors
+	}
+
+	/// Check an object keyword.
+	fn check_object(&self, check:&KeywordCheck) -> Vec<JsonSchemaError> {
+		let KeywordCheck { schema, keyword, value, instance, instance_path, keyword_path, .. } = *check;
+		let error = |message:String| JsonSchemaError::new(instance_path, keyword_path, message);
+		let mut errors:Vec<JsonSchemaError> = Vec::new();
+		match keyword {
+			"properties" | "patternProperties" | "additionalProperties" | "propertyNames" | "required" | "dependentRequired" | "minProperties" | "maxProperties" if instance.as_object().is_some() => {
+				let properties:Vec<(String, &Json)> = instance.as_object().unwrap().iter().map(|(key, value)| (key.as_str().map(|key| key.to_string()).unwrap_or_else(|| key.to_string()), value)).collect();
+				match keyword {
+					"properties" => for (name, property) in &properties {
+						if let Some(property_schema) = value.get(name) {
+							errors.extend(self.check(property_schema, property, &child_path(instance_path, name), &child_path(keyword_path, name), &[]));
+						}
+					},
+					"patternProperties" => for (pattern, property_schema) in value.as_object().into_iter().flatten() {
+						let Some(regex) = pattern.as_str().and_then(|pattern| self.regexes.get(pattern)) else { continue; };
+						for (name, property) in properties.iter().filter(|(name, _)| regex.is_match(name)) {
+							errors.extend(self.check(property_schema, property, &child_path(instance_path, name), &child_path(keyword_path, pattern.as_str().unwrap()), &[]));
+						}
+					},
+					"additionalProperties" => for (name, property) in &properties {
+						let is_listed:bool = schema.get("properties").and_then(|listed| listed.get(name)).is_some();
+						let is_patterned:bool = schema.get("patternProperties").and_then(|patterns| patterns.as_object()).into_iter().flatten().any(|(pattern, _)| pattern.as_str().and_then(|pattern| self.regexes.get(pattern)).map(|regex| regex.is_match(name)).unwrap_or(false));
+						if !is_listed && !is_patterned {
+							errors.extend(self.check(value, property, &child_path(instance_path, name), keyword_path, &[]));
+						}
+					},
+					"propertyNames" => for (name, _) in &properties {
+						errors.extend(self.check(value, &Json::String(name.clone()), &child_path(instance_path, name), keyword_path, &[]));
+					},
+					"required" => for required in value.as_array().into_iter().flatten().filter_map(|required| required.as_str()) {
+						if !properties.iter().any(|(name, _)| name == required) {
+							errors.push(error(format!("missing required property '{required}'")));
+						}
+					},
+					"dependentRequired" => for (name, dependencies) in value.as_object().into_iter().flatten() {
+						if !properties.iter().any(|(property, _)| Some(property.as_str()) == name.as_str()) {
+							continue;
+						}
+						for dependency in dependencies.as_array().into_iter().flatten().filter_map(|dependency| dependency.as_str()) {
+							if !properties.iter().any(|(property, _)| property == dependency) {
+								errors.push(error(format!("missing property '{dependency}', required by '{}'", name.as_str().unwrap_or_default())));
+							}
+						}
+					},
+					"minProperties" if (properties.len() as f64) < value.as_f64().unwrap_or(0.0) => errors.push(error(format!("should have at least {value} properties"))),
+					"maxProperties" if (properties.len() as f64) > value.as_f64().unwrap_or(f64::INFINITY) => errors.push(error(format!("should have at most {value} properties"))),
+					_ => {}
+				}
+			},
+			_ => {}
+		}
+		errors
+	}
+}
+
+
+
+/// A keyword of a (sub-)schema to check an instance against.
+#[derive(Clone, Copy)]
+struct KeywordCheck<'a> {
+	schema:&'a Json,
+	keyword:&'a str,
+	value:&'a Json,
+	instance:&'a Json,
+	instance_path:&'a [String],
+	schema_path:&'a [String],
+	keyword_path:&'a [String],
+	references:&'a [&'a str]
+}
+
+
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct JsonSchemaError {
+	pub instance_path:String,
+	pub schema_path:String,
+	pub message:String
+}
+impl JsonSchemaError {
+
+	/// Create a new error from reference tokens of the instance and schema locations.
+	fn new(instance_path:&[String], schema_path:&[String], message:String) -> JsonSchemaError {
+		JsonSchemaError {
+			instance_path: Json::build_pointer(instance_path),
+			schema_path: format!("#{}", Json::build_pointer(schema_path)),
+			message
+		}
+	}
+}
+impl Display for JsonSchemaError {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "Invalid json at '{}', {} (schema '{}').", self.instance_path, self.message, self.schema_path)
+	}
+}
+impl Error for JsonSchemaError {}
+
+
+
+/// Create the reference tokens of a child location.
+fn child_path<T:ToString>(path:&[String], token:T) -> Vec<String> {
+	let mut path:Vec<String> = path.to_vec();
+	path.push(token.to_string());
+	path
+}
+
+/// Get the JSON Schema type name of a value.
+fn type_name(value:&Json) -> &'static str {
+	match value {
+		Json::Dict(_) => "object",
+		Json::Array(_) => "array",
+		Json::String(_) => "string",
+		Json::Integer(_) => "integer",
+		Json::Float(_) | Json::Number(_) => "number",
+		Json::Bool(_) => "boolean",
+		Json::Null => "null"
+	}
+}
+
+/// Whether or not a value matches a JSON Schema type name. Numbers with a zero fraction count as integers.
+fn matches_type(value:&Json, type_name:&str) -> bool {
+	match (type_name, value) {
+		("object", Json::Dict(_)) | ("array", Json::Array(_)) | ("string", Json::String(_)) | ("boolean", Json::Bool(_)) | ("null", Json::Null) => true,
+		("number", Json::Integer(_) | Json::Float(_) | Json::Number(_)) => true,
+		("integer", Json::Integer(_)) => true,
+		("integer", Json::Float(value)) => value.fract() == 0.0,
+		("integer", Json::Number(value)) => value.as_i128().is_some() || value.as_f64().fract() == 0.0,
+		_ => false
+	}
+}
+
+/// Whether or not a number is a multiple of another. Integers are compared exactly.
+fn is_multiple_of(value:&Json, divisor:&Json) -> bool {
+	if let (Some(value), Some(divisor)) = (value.as_i64(), divisor.as_i64()) {
+		return value.checked_rem(divisor).map(|remainder| remainder == 0).unwrap_or(divisor == -1);
+	}
+	let quotient:f64 = value.as_f64().unwrap_or(f64::NAN) / divisor.as_f64().unwrap_or(f64::NAN);
+	quotient.is_finite() && (quotient - quotient.round()).abs() < 1e-9
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonSchema, JsonSchemaError };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn service_schema() -> JsonSchema {
+		JsonSchema::new(Json::new_strict(r##"{
+			"$schema": "https://json-schema.org/draft/2020-12/schema",
+			"type": "object",
+			"required": ["name", "port"],
+			"properties": {
+				"name": { "type": "string", "minLength": 1, "pattern": "^[a-z][a-z0-9-]*$" },
+				"port": { "type": "integer", "minimum": 1, "maximum": 65535 },
+				"mode": { "enum": ["dev", "prod"] },
+				"version": { "const": 2 },
+				"replicas": { "type": "integer", "multipleOf": 2, "exclusiveMinimum": 0 },
+				"hosts": { "type": "array", "items": { "$ref": "#/$defs/host" }, "minItems": 1, "uniqueItems": true },
+				"limits": { "type": "object", "additionalProperties": { "type": "number" } },
+				"tls": { "oneOf": [{ "type": "boolean" }, { "$ref": "#/$defs/tls" }] }
+			},
+			"additionalProperties": false,
+			"$defs": {
+				"host": { "type": "string", "anyOf": [{ "format": "hostname", "pattern": "\\." }, { "const": "localhost" }] },
+				"tls": { "type": "object", "required": ["cert"], "properties": { "cert": { "type": "string" } } }
+			}
+		}"##).unwrap()).unwrap()
+	}
+
+	fn error_locations(schema:&JsonSchema, instance:&str) -> Vec<(String, String)> {
+		schema.validate(&Json::new_strict(instance).unwrap()).err().unwrap_or_default().into_iter().map(|error| (error.instance_path, error.schema_path)).collect()
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_valid_instance() {
+		let schema:JsonSchema = service_schema();
+		let instance:Json = Json::new_strict(r#"{"name": "api-1", "port": 8080, "mode": "prod", "version": 2.0, "replicas": 4, "hosts": ["a.example", "localhost"], "limits": {"cpu": 0.5}, "tls": {"cert": "x.pem"}}"#).unwrap();
+		assert_eq!(schema.validate(&instance), Ok(()));
+		assert!(schema.is_valid(&Json::new_strict(r#"{"name": "a", "port": 1, "tls": false}"#).unwrap()));
+	}
+
+	#[test]
+	fn test_error_paths() {
+		let schema:JsonSchema = service_schema();
+		assert_eq!(error_locations(&schema, r#"{"port": 0}"#), vec![
+			("".to_string(), "#/required".to_string()),
+			("/port".to_string(), "#/properties/port/minimum".to_string())
+		]);
+		assert_eq!(error_locations(&schema, r#"{"name": "Api", "port": 80.5, "extra": 1}"#), vec![
+			("/name".to_string(), "#/properties/name/pattern".to_string()),
+			("/port".to_string(), "#/properties/port/type".to_string()),
+			("/extra".to_string(), "#/additionalProperties".to_string())
+		]);
+		assert_eq!(error_locations(&schema, r#"{"name": "a", "port": 1, "mode": "test", "version": 3, "replicas": 3, "hosts": ["a.b", "a.b", "nohost"], "limits": {"cpu": "1"}, "tls": {}}"#), vec![
+			("/mode".to_string(), "#/properties/mode/enum".to_string()),
+			("/version".to_string(), "#/properties/version/const".to_string()),
+			("/replicas".to_string(), "#/properties/replicas/multipleOf".to_string()),
+			("/hosts/2".to_string(), "#/properties/hosts/items/$ref/anyOf".to_string()),
+			("/hosts".to_string(), "#/properties/hosts/uniqueItems".to_string()),
+			("/limits/cpu".to_string(), "#/properties/limits/additionalProperties/type".to_string()),
+			("/tls".to_string(), "#/properties/tls/oneOf".to_string())
+		]);
+	}
+
+	#[test]
+	fn test_applicators() {
+		let schema:JsonSchema = JsonSchema::new(Json::new_strict(r#"{
+			"prefixItems": [{ "type": "string" }],
+			"items": { "type": "integer" },
+			"contains": { "const": 0 },
+			"if": { "minItems": 3 },
+			"then": { "maxItems": 4 },
+			"else": { "not": { "contains": { "const": 1 } } }
+		}"#).unwrap()).unwrap();
+		assert!(schema.is_valid(&Json::new_strict(r#"["a", 0, 5]"#).unwrap()));
+		assert!(!schema.is_valid(&Json::new_strict(r#"["a", 0, 5, 6, 7]"#).unwrap()));
+		assert!(!schema.is_valid(&Json::new_strict(r#"["a", 1]"#).unwrap()));
+		assert!(!schema.is_valid(&Json::new_strict(r#"[0, 0]"#).unwrap()));
+		assert!(schema.is_valid(&Json::new_strict(r#"{"not": "an array"}"#).unwrap()));
+		assert!(JsonSchema::new(Json::Bool(false)).unwrap().validate(&Json::Null).is_err());
+	}
+
+	#[test]
+	fn test_recursive_schema() {
+		let schema:JsonSchema = JsonSchema::new(Json::new_strict(r##"{"$defs": {"node": {"type": "object", "properties": {"next": {"$ref": "#/$defs/node"}}}}, "$ref": "#/$defs/node"}"##).unwrap()).unwrap();
+		let nested = |depth:usize, leaf:&str| Json::new_strict(&("{\"next\":".repeat(depth) + leaf + &"}".repeat(depth))).unwrap();
+		assert!(schema.is_valid(&nested(200, "{}")));
+		let errors:Vec<JsonSchemaError> = schema.validate(&nested(200, "1")).unwrap_err();
+		assert_eq!(errors.len(), 1);
+		assert_eq!(errors[0].instance_path, "/next".repeat(200));
+	}
+
+	#[test]
+	fn test_invalid_schemas() {
+		assert!(JsonSchema::new(Json::Integer(1)).is_err());
+		assert!(JsonSchema::new(Json::new_strict(r#"{"pattern": "("}"#).unwrap()).is_err());
+		assert!(JsonSchema::new(Json::new_strict(r#"{"const": {"pattern": "("}}"#).unwrap()).is_ok());
+		assert_eq!(JsonSchema::new(Json::new_strict(r#"{"multipleOf": 0}"#).unwrap()).unwrap_err().to_string(), "Invalid json schema, 'multipleOf' should be positive, found 0.");
+		assert!(JsonSchema::new(Json::new_strict(r#"{"items": {"multipleOf": -1.5}}"#).unwrap()).is_err());
+		assert!(JsonSchema::new(Json::new_strict(r#"{"properties": {"multipleOf": {"type": "string"}}}"#).unwrap()).is_ok());
+		let errors:Vec<JsonSchemaError> = JsonSchema::new(Json::new_strict(r##"{"$ref": "#"}"##).unwrap()).unwrap().validate(&Json::Null).unwrap_err();
+		assert_eq!(errors[0].to_string(), "Invalid json at '', reference '#' refers back to itself without a nested value (schema '#/$ref/$ref').");
+		let errors:Vec<JsonSchemaError> = JsonSchema::new(Json::new_strict(r##"{"allOf": [{"$ref": "#"}, {"$ref": "#"}]}"##).unwrap()).unwrap().validate(&Json::Null).unwrap_err();
+		assert_eq!(errors.len(), 4);
+		let errors:Vec<JsonSchemaError> = JsonSchema::new(Json::new_strict(r##"{"$ref": "#/$defs/missing"}"##).unwrap()).unwrap().validate(&Json::Null).unwrap_err();
+		assert_eq!(errors[0].to_string(), "Invalid json at '', could not resolve reference '#/$defs/missing' (schema '#/$ref').");
+	}
+}
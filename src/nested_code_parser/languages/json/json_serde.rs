@@ -0,0 +1,559 @@
+use super::JsonError;
+use std::{ error::Error, fmt::{ self, Display, Formatter } };
+#[cfg(feature = "serde")]
+pub use serde_bridge::*;
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum JsonSerdeError {
+	Parse(JsonError),
+	Custom(String)
+}
+impl Display for JsonSerdeError {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		match self {
+			JsonSerdeError::Parse(error) => write!(f, "{error}"),
+			JsonSerdeError::Custom(message) => write!(f, "Could not map JSON, {message}")
+		}
+	}
+}
+impl Error for JsonSerdeError {}
+impl From<JsonError> for JsonSerdeError {
+	fn from(error:JsonError) -> Self {
+		JsonSerdeError::Parse(error)
+	}
+}
+
+
+
+// The serde bridge is only compiled with the "serde" feature. It is gated here instead of in mod.rs, as mod.rs is auto-exported.
+#[cfg(feature = "serde")]
+mod serde_bridge {
+	use super::{ super::{ Json, JsonFlavor, JsonNumber }, JsonSerdeError };
+	use serde::{ de::{ self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor }, forward_to_deserialize_any, ser::{ self, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple, SerializeTupleStruct, SerializeTupleVariant }, Deserialize, Deserializer, Serialize, Serializer };
+	use std::{ fmt::{ self, Display, Formatter }, vec::IntoIter };
+
+
+
+	/// Parse RFC 8259 JSON contents straight into a type implementing serde's Deserialize. Numbers are read lossless, so integers beyond i64 keep their exact value.
+	pub fn from_str<T:DeserializeOwned>(contents:&str) -> Result<T, JsonSerdeError> {
+		from_json(Json::parse_lossless(contents, JsonFlavor::Strict)?)
+	}
+
+	/// Serialize a type implementing serde's Serialize straight into compact JSON text.
+	pub fn to_string<T:Serialize + ?Sized>(value:&T) -> Result<String, JsonSerdeError> {
+		Ok(to_json(value)?.to_string())
+	}
+
+	/// Serialize a type implementing serde's Serialize straight into pretty JSON text.
+	pub fn to_string_pretty<T:Serialize + ?Sized>(value:&T) -> Result<String, JsonSerdeError> {
+		Ok(to_json(value)?.to_pretty_string())
+	}
+
+	/// Convert a json value into a type implementing serde's Deserialize.
+	pub fn from_json<T:DeserializeOwned>(json:Json) -> Result<T, JsonSerdeError> {
+		T::deserialize(json)
+	}
+
+	/// Convert a type implementing serde's Serialize into a json value.
+	pub fn to_json<T:Serialize + ?Sized>(value:&T) -> Result<Json, JsonSerdeError> {
+		value.serialize(JsonValueSerializer)
+	}
+
+
+
+	impl ser::Error for JsonSerdeError {
+		fn custom<T:Display>(message:T) -> Self {
+			JsonSerdeError::Custom(message.to_string())
+		}
+	}
+	impl de::Error for JsonSerdeError {
+		fn custom<T:Display>(message:T) -> Self {
+			JsonSerdeError::Custom(message.to_string())
+		}
+	}
+
+
+
+	/* JSON AS SERDE DATA */
+
+	impl Serialize for Json {
+		fn serialize<S:Serializer>(&self, serializer:S) -> Result<S::Ok, S::Error> {
+			match self {
+				Json::Dict(entries) => {
+					let mut map = serializer.serialize_map(Some(entries.len()))?;
+					for (key, value) in entries {
+						map.serialize_entry(key, value)?;
+					}
+					map.end()
+				},
+				Json::Array(items) => {
+					let mut seq = serializer.serialize_seq(Some(items.len()))?;
+					for item in items {
+						seq.serialize_element(item)?;
+					}
+					seq.end()
+				},
+				Json::String(value) => serializer.serialize_str(value),
+				Json::Integer(value) => serializer.serialize_i64(*value),
+				Json::Float(value) => serializer.serialize_f64(*value),
+				Json::Number(value) if !value.is_integer() => serializer.serialize_f64(value.as_f64()),
+				Json::Number(value) => match (value.as_i64(), value.as_u64(), value.as_i128()) {
+					(Some(integer), _, _) => serializer.serialize_i64(integer),
+					(_, Some(integer), _) => serializer.serialize_u64(integer),
+					(_, _, Some(integer)) => serializer.serialize_i128(integer),
+					_ => serializer.serialize_f64(value.as_f64())
+				},
+				Json::Bool(value) => serializer.serialize_bool(*value),
+				Json::Null => serializer.serialize_unit()
+			}
+		}
+	}
+	impl<'de> Deserialize<'de> for Json {
+		fn deserialize<D:Deserializer<'de>>(deserializer:D) -> Result<Json, D::Error> {
+			deserializer.deserialize_any(JsonVisitor)
+		}
+	}
+
+	struct JsonVisitor;
+	impl<'de> Visitor<'de> for JsonVisitor {
+		type Value = Json;
+
+		fn expecting(&self, f:&mut Formatter<'_>) -> fmt::Result {
+			write!(f, "any json value")
+		}
+
+		fn visit_bool<E:de::Error>(self, value:bool) -> Result<Json, E> {
+			Ok(Json::Bool(value))
+		}
+
+		fn visit_i64<E:de::Error>(self, value:i64) -> Result<Json, E> {
+			Ok(Json::Integer(value))
+		}
+
+		fn visit_u64<E:de::Error>(self, value:u64) -> Result<Json, E> {
+			Ok(i64::try_from(value).map(Json::Integer).unwrap_or(Json::Number(JsonNumber::from(value))))
+		}
+
+		fn visit_i128<E:de::Error>(self, value:i128) -> Result<Json, E> {
+			Ok(i64::try_from(value).map(Json::Integer).unwrap_or(Json::Number(JsonNumber::from(value))))
+		}
+
+		fn visit_u128<E:de::Error>(self, value:u128) -> Result<Json, E> {
+			Ok(i64::try_from(value).map(Json::Integer).unwrap_or(Json::Number(JsonNumber::from(value))))
+		}
+
+		fn visit_f64<E:de::Error>(self, value:f64) -> Result<Json, E> {
+			Ok(Json::Float(value))
+		}
+
+		fn visit_str<E:de::Error>(self, value:&str) -> Result<Json, E> {
+			Ok(Json::String(value.to_string()))
+		}
+
+		fn visit_string<E:de::Error>(self, value:String) -> Result<Json, E> {
+			Ok(Json::String(value))
+		}
+
+		fn visit_unit<E:de::Error>(self) -> Result<Json, E> {
+			Ok(Json::Null)
+		}
+
+		fn visit_none<E:de::Error>(self) -> Result<Json, E> {
+			Ok(Json::Null)
+		}
+
+		fn visit_some<D:Deserializer<'de>>(self, deserializer:D) -> Result<Json, D::Error> {
+			Json::deserialize(deserializer)
+		}
+
+		fn visit_seq<A:SeqAccess<'de>>(self, mut seq:A) -> Result<Json, A::Error> {
+			let mut items:Vec<Json> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+			while let Some(item) = seq.next_element()? {
+				items.push(item);
+			}
+			Ok(Json::Array(items))
+		}
+
+		fn visit_map<A:MapAccess<'de>>(self, mut map:A) -> Result<Json, A::Error> {
+			let mut entries:Vec<(Json, Json)> = Vec::with_capacity(map.size_hint().unwrap_or(0));
+			while let Some(entry) = map.next_entry()? {
+				entries.push(entry);
+			}
+			Ok(Json::Dict(entries))
+		}
+	}
+
+
+
+	/* SERIALIZER */
+
+	/// Serializes any serde data into a json value. Enums are externally tagged, map keys are turned into strings.
+	struct JsonValueSerializer;
+	impl Serializer for JsonValueSerializer {
+		type Ok = Json;
+		type Error = JsonSerdeError;
+		type SerializeSeq = ArraySerializer;
+		type SerializeTuple = ArraySerializer;
+		type SerializeTupleStruct = ArraySerializer;
+		type SerializeTupleVariant = ArraySerializer;
+		type SerializeMap = DictSerializer;
+		type SerializeStruct = DictSerializer;
+		type SerializeStructVariant = DictSerializer;
+
+		fn serialize_bool(self, value:bool) -> Result<Json, JsonSerdeError> { Ok(Json::Bool(value)) }
+		fn serialize_i8(self, value:i8) -> Result<Json, JsonSerdeError> { Ok(Json::Integer(value as i64)) }
+		fn serialize_i16(self, value:i16) -> Result<Json, JsonSerdeError> { Ok(Json::Integer(value as i64)) }
+		fn serialize_i32(self, value:i32) -> Result<Json, JsonSerdeError> { Ok(Json::Integer(value as i64)) }
+		fn serialize_i64(self, value:i64) -> Result<Json, JsonSerdeError> { Ok(Json::Integer(value)) }
+		fn serialize_u8(self, value:u8) -> Result<Json, JsonSerdeError> { Ok(Json::Integer(value as i64)) }
+		fn serialize_u16(self, value:u16) -> Result<Json, JsonSerdeError> { Ok(Json::Integer(value as i64)) }
+		fn serialize_u32(self, value:u32) -> Result<Json, JsonSerdeError> { Ok(Json::Integer(value as i64)) }
+		fn serialize_i128(self, value:i128) -> Result<Json, JsonSerdeError> { Ok(i64::try_from(value).map(Json::Integer).unwrap_or(Json::Number(JsonNumber::from(value)))) }
+		fn serialize_u64(self, value:u64) -> Result<Json, JsonSerdeError> { Ok(i64::try_from(value).map(Json::Integer).unwrap_or(Json::Number(JsonNumber::from(value)))) }
+		fn serialize_u128(self, value:u128) -> Result<Json, JsonSerdeError> { Ok(i64::try_from(value).map(Json::Integer).unwrap_or(Json::Number(JsonNumber::from(value)))) }
+		fn serialize_f32(self, value:f32) -> Result<Json, JsonSerdeError> { Ok(Json::Float(value as f64)) }
+		fn serialize_f64(self, value:f64) -> Result<Json, JsonSerdeError> { Ok(Json::Float(value)) }
+		fn serialize_char(self, value:char) -> Result<Json, JsonSerdeError> { Ok(Json::String(value.to_string())) }
+		fn serialize_str(self, value:&str) -> Result<Json, JsonSerdeError> { Ok(Json::String(value.to_string())) }
+		fn serialize_bytes(self, value:&[u8]) -> Result<Json, JsonSerdeError> { Ok(Json::Array(value.iter().map(|byte| Json::Integer(*byte as i64)).collect())) }
+		fn serialize_none(self) -> Result<Json, JsonSerdeError> { Ok(Json::Null) }
+		fn serialize_some<T:Serialize + ?Sized>(self, value:&T) -> Result<Json, JsonSerdeError> { value.serialize(self) }
+		fn serialize_unit(self) -> Result<Json, JsonSerdeError> { Ok(Json::Null) }
+		fn serialize_unit_struct(self, _name:&'static str) -> Result<Json, JsonSerdeError> { Ok(Json::Null) }
+		fn serialize_unit_variant(self, _name:&'static str, _index:u32, variant:&'static str) -> Result<Json, JsonSerdeError> { Ok(Json::String(variant.to_string())) }
+		fn serialize_newtype_struct<T:Serialize + ?Sized>(self, _name:&'static str, value:&T) -> Result<Json, JsonSerdeError> { value.serialize(self) }
+
+		fn serialize_newtype_variant<T:Serialize + ?Sized>(self, _name:&'static str, _index:u32, variant:&'static str, value:&T) -> Result<Json, JsonSerdeError> {
+			Ok(Json::Dict(vec![(Json::String(variant.to_string()), value.serialize(self)?)]))
+		}
+
+		fn serialize_seq(self, length:Option<usize>) -> Result<ArraySerializer, JsonSerdeError> {
+			Ok(ArraySerializer { variant: None, items: Vec::with_capacity(length.unwrap_or(0)) })
+		}
+
+		fn serialize_tuple(self, length:usize) -> Result<ArraySerializer, JsonSerdeError> {
+			self.serialize_seq(Some(length))
+		}
+
+		fn serialize_tuple_struct(self, _name:&'static str, length:usize) -> Result<ArraySerializer, JsonSerdeError> {
+			self.serialize_seq(Some(length))
+		}
+
+		fn serialize_tuple_variant(self, _name:&'static str, _index:u32, variant:&'static str, length:usize) -> Result<ArraySerializer, JsonSerdeError> {
+			Ok(ArraySerializer { variant: Some(variant), items: Vec::with_capacity(length) })
+		}
+
+		fn serialize_map(self, length:Option<usize>) -> Result<DictSerializer, JsonSerdeError> {
+			Ok(DictSerializer { variant: None, entries: Vec::with_capacity(length.unwrap_or(0)), next_key: None })
+		}
+
+		fn serialize_struct(self, _name:&'static str, length:usize) -> Result<DictSerializer, JsonSerdeError> {
+			self.serialize_map(Some(length))
+		}
+
+		fn serialize_struct_variant(self, _name:&'static str, _index:u32, variant:&'static str, length:usize) -> Result<DictSerializer, JsonSerdeError> {
+			Ok(DictSerializer { variant: Some(variant), entries: Vec::with_capacity(length), next_key: None })
+		}
+	}
+
+	/// Wrap the contents of an enum variant in a dict with the variant name as only key.
+	fn tag_variant(variant:Option<&'static str>, contents:Json) -> Json {
+		match variant {
+			Some(variant) => Json::Dict(vec![(Json::String(variant.to_string()), contents)]),
+			None => contents
+		}
+	}
+
+	struct ArraySerializer {
+		variant:Option<&'static str>,
+		items:Vec<Json>
+	}
+	impl SerializeSeq for ArraySerializer {
+		type Ok = Json;
+		type Error = JsonSerdeError;
+
+		fn serialize_element<T:Serialize + ?Sized>(&mut self, value:&T) -> Result<(), JsonSerdeError> {
+			self.items.push(to_json(value)?);
+			Ok(())
+		}
+
+		fn end(self) -> Result<Json, JsonSerdeError> {
+			Ok(tag_variant(self.variant, Json::Array(self.items)))
+		}
+	}
+	impl SerializeTuple for ArraySerializer {
+		type Ok = Json;
+		type Error = JsonSerdeError;
+
+		fn serialize_element<T:Serialize + ?Sized>(&mut self, value:&T) -> Result<(), JsonSerdeError> {
+			SerializeSeq::serialize_element(self, value)
+		}
+
+		fn end(self) -> Result<Json, JsonSerdeError> {
+			SerializeSeq::end(self)
+		}
+	}
+	impl SerializeTupleStruct for ArraySerializer {
+		type Ok = Json;
+		type Error = JsonSerdeError;
+
+		fn serialize_field<T:Serialize + ?Sized>(&mut self, value:&T) -> Result<(), JsonSerdeError> {
+			SerializeSeq::serialize_element(self, value)
+		}
+
+		fn end(self) -> Result<Json, JsonSerdeError> {
+			SerializeSeq::end(self)
+		}
+	}
+	impl SerializeTupleVariant for ArraySerializer {
+		type Ok = Json;
+		type Error = JsonSerdeError;
+
+		fn serialize_field<T:Serialize + ?Sized>(&mut self, value:&T) -> Result<(), JsonSerdeError> {
+			SerializeSeq::serialize_element(self, value)
+		}
+
+		fn end(self) -> Result<Json, JsonSerdeError> {
+			SerializeSeq::end(self)
+		}
+	}
+
+	struct DictSerializer {
+		variant:Option<&'static str>,
+		entries:Vec<(Json, Json)>,
+		next_key:Option<Json>
+	}
+	impl SerializeMap for DictSerializer {
+		type Ok = Json;
+		type Error = JsonSerdeError;
+
+		fn serialize_key<T:Serialize + ?Sized>(&mut self, key:&T) -> Result<(), JsonSerdeError> {
+			self.next_key = Some(match to_json(key)? {
+				key @ Json::String(_) => key,
+				key @ (Json::Integer(_) | Json::Float(_) | Json::Bool(_)) => Json::String(key.to_string()),
+				key => return Err(JsonSerdeError::Custom(format!("map key {key} should be a string, number or bool.")))
+			});
+			Ok(())
+		}
+
+		fn serialize_value<T:Serialize + ?Sized>(&mut self, value:&T) -> Result<(), JsonSerdeError> {
+			let key:Json = self.next_key.take().ok_or(JsonSerdeError::Custom("map value serialized before its key.".to_string()))?;
+			self.entries.push((key, to_json(value)?));
+			Ok(())
+		}
+
+		fn end(self) -> Result<Json, JsonSerdeError> {
+			Ok(tag_variant(self.variant, Json::Dict(self.entries)))
+		}
+	}
+	impl SerializeStruct for DictSerializer {
+		type Ok = Json;
+		type Error = JsonSerdeError;
+
+		fn serialize_field<T:Serialize + ?Sized>(&mut self, key:&'static str, value:&T) -> Result<(), JsonSerdeError> {
+			self.entries.push((Json::String(key.to_string()), to_json(value)?));
+			Ok(())
+		}
+
+		fn end(self) -> Result<Json, JsonSerdeError> {
+			SerializeMap::end(self)
+		}
+	}
+	impl SerializeStructVariant for DictSerializer {
+		type Ok = Json;
+		type Error = JsonSerdeError;
+
+		fn serialize_field<T:Serialize + ?Sized>(&mut self, key:&'static str, value:&T) -> Result<(), JsonSerdeError> {
+			SerializeStruct::serialize_field(self, key, value)
+		}
+
+		fn end(self) -> Result<Json, JsonSerdeError> {
+			SerializeMap::end(self)
+		}
+	}
+
+
+
+	/* DESERIALIZER */
+
+	impl<'de> Deserializer<'de> for Json {
+		type Error = JsonSerdeError;
+
+		fn deserialize_any<V:Visitor<'de>>(self, visitor:V) -> Result<V::Value, JsonSerdeError> {
+			match self {
+				Json::Dict(entries) => visitor.visit_map(DictAccess { entries: entries.into_iter(), value: None }),
+				Json::Array(items) => visitor.visit_seq(ArrayAccess { items: items.into_iter() }),
+				Json::String(value) => visitor.visit_string(value),
+				Json::Integer(value) => visitor.visit_i64(value),
+				Json::Float(value) => visitor.visit_f64(value),
+				Json::Number(value) if !value.is_integer() => visitor.visit_f64(value.as_f64()),
+				Json::Number(value) => match (value.as_i64(), value.as_u64(), value.as_i128()) {
+					(Some(integer), _, _) => visitor.visit_i64(integer),
+					(_, Some(integer), _) => visitor.visit_u64(integer),
+					(_, _, Some(integer)) => visitor.visit_i128(integer),
+					_ => visitor.visit_f64(value.as_f64())
+				},
+				Json::Bool(value) => visitor.visit_bool(value),
+				Json::Null => visitor.visit_unit()
+			}
+		}
+
+		fn deserialize_option<V:Visitor<'de>>(self, visitor:V) -> Result<V::Value, JsonSerdeError> {
+			match self {
+				Json::Null => visitor.visit_none(),
+				json => visitor.visit_some(json)
+			}
+		}
+
+		fn deserialize_newtype_struct<V:Visitor<'de>>(self, _name:&'static str, visitor:V) -> Result<V::Value, JsonSerdeError> {
+			visitor.visit_newtype_struct(self)
+		}
+
+		fn deserialize_enum<V:Visitor<'de>>(self, _name:&'static str, _variants:&'static [&'static str], visitor:V) -> Result<V::Value, JsonSerdeError> {
+			match self {
+				Json::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+				Json::Dict(mut entries) if entries.len() == 1 => {
+					let (variant, contents) = entries.remove(0);
+					visitor.visit_enum(VariantDeserializer { variant, contents })
+				},
+				json => Err(JsonSerdeError::Custom(format!("expected an enum variant name or a dict with one entry, found {json}.")))
+			}
+		}
+
+		forward_to_deserialize_any! {
+			bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+		}
+	}
+	impl IntoDeserializer<'_, JsonSerdeError> for Json {
+		type Deserializer = Json;
+
+		fn into_deserializer(self) -> Json {
+			self
+		}
+	}
+
+	struct ArrayAccess {
+		items:IntoIter<Json>
+	}
+	impl<'de> SeqAccess<'de> for ArrayAccess {
+		type Error = JsonSerdeError;
+
+		fn next_element_seed<T:DeserializeSeed<'de>>(&mut self, seed:T) -> Result<Option<T::Value>, JsonSerdeError> {
+			self.items.next().map(|item| seed.deserialize(item)).transpose()
+		}
+
+		fn size_hint(&self) -> Option<usize> {
+			Some(self.items.len())
+		}
+	}
+
+	struct DictAccess {
+		entries:IntoIter<(Json, Json)>,
+		value:Option<Json>
+	}
+	impl<'de> MapAccess<'de> for DictAccess {
+		type Error = JsonSerdeError;
+
+		fn next_key_seed<K:DeserializeSeed<'de>>(&mut self, seed:K) -> Result<Option<K::Value>, JsonSerdeError> {
+			match self.entries.next() {
+				Some((key, value)) => {
+					self.value = Some(value);
+					seed.deserialize(KeyDeserializer(key)).map(Some)
+				},
+				None => Ok(None)
+			}
+		}
+
+		fn next_value_seed<V:DeserializeSeed<'de>>(&mut self, seed:V) -> Result<V::Value, JsonSerdeError> {
+			seed.deserialize(self.value.take().ok_or(JsonSerdeError::Custom("dict value requested before its key.".to_string()))?)
+		}
+
+		fn size_hint(&self) -> Option<usize> {
+			Some(self.entries.len())
+		}
+	}
+
+	/// Deserializes dict keys. Keys are strings in json, so numeric and bool keys are parsed from their text when the target type asks for them.
+	struct KeyDeserializer(Json);
+	impl KeyDeserializer {
+
+		/// Get the key parsed as number or bool if its text is one.
+		fn parsed(self) -> Json {
+			match self.0 {
+				Json::String(key) => Json::new_strict(&key).ok().filter(|parsed| matches!(parsed, Json::Integer(_) | Json::Float(_) | Json::Bool(_))).unwrap_or(Json::String(key)),
+				key => key
+			}
+		}
+	}
+	macro_rules! deserialize_parsed_key {
+		($($method:ident)*) => {
+			$(
+				fn $method<V:Visitor<'de>>(self, visitor:V) -> Result<V::Value, JsonSerdeError> {
+					self.parsed().deserialize_any(visitor)
+				}
+			)*
+		};
+	}
+	impl<'de> Deserializer<'de> for KeyDeserializer {
+		type Error = JsonSerdeError;
+
+		fn deserialize_any<V:Visitor<'de>>(self, visitor:V) -> Result<V::Value, JsonSerdeError> {
+			self.0.deserialize_any(visitor)
+		}
+
+		fn deserialize_option<V:Visitor<'de>>(self, visitor:V) -> Result<V::Value, JsonSerdeError> {
+			self.0.deserialize_option(visitor)
+		}
+
+		fn deserialize_newtype_struct<V:Visitor<'de>>(self, _name:&'static str, visitor:V) -> Result<V::Value, JsonSerdeError> {
+			visitor.visit_newtype_struct(self)
+		}
+
+		fn deserialize_enum<V:Visitor<'de>>(self, name:&'static str, variants:&'static [&'static str], visitor:V) -> Result<V::Value, JsonSerdeError> {
+			self.0.deserialize_enum(name, variants, visitor)
+		}
+
+		deserialize_parsed_key! { deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 }
+
+		forward_to_deserialize_any! {
+			char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+		}
+	}
+
+	struct VariantDeserializer {
+		variant:Json,
+		contents:Json
+	}
+	impl<'de> EnumAccess<'de> for VariantDeserializer {
+		type Error = JsonSerdeError;
+		type Variant = Json;
+
+		fn variant_seed<V:DeserializeSeed<'de>>(self, seed:V) -> Result<(V::Value, Json), JsonSerdeError> {
+			Ok((seed.deserialize(self.variant)?, self.contents))
+		}
+	}
+	impl<'de> VariantAccess<'de> for Json {
+		type Error = JsonSerdeError;
+
+		fn unit_variant(self) -> Result<(), JsonSerdeError> {
+			match self {
+				Json::Null => Ok(()),
+				json => Err(JsonSerdeError::Custom(format!("expected a unit variant, found {json}.")))
+			}
+		}
+
+		fn newtype_variant_seed<T:DeserializeSeed<'de>>(self, seed:T) -> Result<T::Value, JsonSerdeError> {
+			seed.deserialize(self)
+		}
+
+		fn tuple_variant<V:Visitor<'de>>(self, _length:usize, visitor:V) -> Result<V::Value, JsonSerdeError> {
+			self.deserialize_seq(visitor)
+		}
+
+		fn struct_variant<V:Visitor<'de>>(self, _fields:&'static [&'static str], visitor:V) -> Result<V::Value, JsonSerdeError> {
+			self.deserialize_map(visitor)
+		}
+	}
+}
@@ -0,0 +1,87 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+	use crate::{ from_json, from_str, to_json, to_string, Json, JsonError, JsonSerdeError };
+	use serde::{ Deserialize, Serialize };
+	use std::collections::BTreeMap;
+
+
+
+	/* HELPER FUNCTIONS */
+
+	#[derive(Serialize, Deserialize, PartialEq, Debug)]
+	struct Config {
+		name:String,
+		version:u32,
+		ratio:f64,
+		enabled:bool,
+		tags:Vec<String>,
+		parent:Option<Box<Config>>,
+		mode:Mode,
+		ports:BTreeMap<u16, String>
+	}
+
+	#[derive(Serialize, Deserialize, PartialEq, Debug)]
+	enum Mode {
+		Off,
+		Level(u8),
+		Range(i32, i32),
+		Custom { label:String }
+	}
+
+	fn example_config() -> Config {
+		Config {
+			name: "main".to_string(),
+			version: 3,
+			ratio: 0.5,
+			enabled: true,
+			tags: vec!["a".to_string(), "b\"c".to_string()],
+			parent: Some(Box::new(Config { name: "base".to_string(), version: 1, ratio: 1.0, enabled: false, tags: Vec::new(), parent: None, mode: Mode::Off, ports: BTreeMap::new() })),
+			mode: Mode::Custom { label: "x".to_string() },
+			ports: BTreeMap::from([(80, "http".to_string()), (443, "https".to_string())])
+		}
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_struct_round_trip() {
+		let config:Config = example_config();
+		let text:String = to_string(&config).unwrap();
+		assert_eq!(&text[..68], r#"{"name":"main","version":3,"ratio":0.5,"enabled":true,"tags":["a","b"#);
+		assert!(text.ends_with(r#""mode":{"Custom":{"label":"x"}},"ports":{"80":"http","443":"https"}}"#));
+		assert_eq!(from_str::<Config>(&text).unwrap(), config);
+	}
+
+	#[test]
+	fn test_enum_representation() {
+		for (mode, expected) in [(Mode::Off, r#""Off""#), (Mode::Level(2), r#"{"Level":2}"#), (Mode::Range(-1, 1), r#"{"Range":[-1,1]}"#)] {
+			assert_eq!(to_string(&mode).unwrap(), expected);
+			assert_eq!(from_str::<Mode>(expected).unwrap(), mode);
+		}
+		assert!(from_str::<Mode>(r#"{"Unknown":1}"#).is_err());
+	}
+
+	#[test]
+	fn test_json_value() {
+		let json:Json = Json::new(r#"{"a": [1, 2.5, null, true], "b": "text"}"#).unwrap();
+		assert_eq!(to_json(&json).unwrap(), json);
+		assert_eq!(from_json::<Json>(json.clone()).unwrap(), json);
+		assert_eq!(from_json::<BTreeMap<String, Json>>(json.clone()).unwrap()["b"], Json::String("text".to_string()));
+		assert_eq!(from_str::<(i64, f64, Option<u8>)>("[1, 2, null]").unwrap(), (1, 2.0, None));
+		assert_eq!(from_str::<(u64, i128)>("[18446744073709551615, -170141183460469231731687303715884105728]").unwrap(), (u64::MAX, i128::MIN));
+	}
+
+	#[test]
+	fn test_errors() {
+		assert!(matches!(from_str::<Config>("{\"name\": "), Err(JsonSerdeError::Parse(_))));
+		assert!(matches!(from_str::<Vec<i32>>("[1] garbage"), Err(JsonSerdeError::Parse(JsonError::TrailingContents(_)))));
+		assert!(matches!(from_str::<String>("'abc'"), Err(JsonSerdeError::Parse(_))));
+		assert!(matches!(from_str::<Vec<i32>>("[1, 2,]"), Err(JsonSerdeError::Parse(_))));
+		assert!(matches!(from_str::<bool>("True"), Err(JsonSerdeError::Parse(_))));
+		assert!(matches!(from_str::<u8>("300"), Err(JsonSerdeError::Custom(_))));
+		assert!(from_str::<Config>(r#"{"name": "main"}"#).unwrap_err().to_string().contains("missing field"));
+		assert!(to_string(&BTreeMap::from([(vec![1], 1)])).is_err());
+	}
+}
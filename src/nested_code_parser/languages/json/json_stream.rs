@@ -0,0 +1,455 @@
+use super::{ json::MAX_NESTING_DEPTH, Json, JsonError, JsonFlavor, JsonNumber, JsonPosition };
+use std::{ collections::VecDeque, error::Error, io::{ self, BufRead, BufReader, Read } };
+
+
+
+const EXCERPT_LENGTH:usize = 40;
+const WHITESPACE_BYTES:[u8; 4] = [b' ', b'\t', b'\n', b'\r'];
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum JsonEvent {
+	StartDict,
+	EndDict,
+	StartArray,
+	EndArray,
+	Key(String),
+	Value(Json)
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum FrameState { First, AfterKey, AfterValue, AfterComma }
+
+/// An open dict or array, with the pointer token of its current entry.
+struct Frame {
+	is_dict:bool,
+	state:FrameState,
+	length:usize,
+	token:String
+}
+
+
+
+/// Pull parser that reads strict json from a source as a sequence of events, without keeping the document in memory.
+pub struct JsonStreamReader<T:Read> {
+	source:BufReader<T>,
+	lossless_numbers:bool,
+	frames:Vec<Frame>,
+	path_depth:usize,
+	started:bool,
+	finished:bool,
+	offset:usize,
+	line:usize,
+	column:usize,
+	recent:VecDeque<u8>
+}
+impl<T:Read> JsonStreamReader<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new stream reader.
+	pub fn new(source:T) -> JsonStreamReader<T> {
+		JsonStreamReader {
+			source: BufReader::new(source),
+			lossless_numbers: false,
+			frames: Vec::new(),
+			path_depth: 0,
+			started: false,
+			finished: false,
+			offset: 0,
+			line: 1,
+			column: 1,
+			recent: VecDeque::with_capacity(EXCERPT_LENGTH)
+		}
+	}
+
+	/// Return self with numbers read as JsonNumber, keeping them exactly as written.
+	pub fn lossless_numbers(mut self) -> Self {
+		self.lossless_numbers = true;
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the amount of dicts and arrays the reader is currently in.
+	pub fn depth(&self) -> usize {
+		self.frames.len()
+	}
+
+	/// Get the JSON Pointer of the value the last event belongs to. For keys this is the value of the key.
+	pub fn path(&self) -> String {
+		Json::build_pointer(&self.frames[..self.path_depth].iter().map(|frame| frame.token.as_str()).collect::<Vec<&str>>())
+	}
+
+	/// Get the byte offset of the reader in the source.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Read the next event. Returns None when the document has ended.
+	pub fn next_event(&mut self) -> Result<Option<JsonEvent>, JsonError> {
+		loop {
+			self.skip_whitespace()?;
+			let byte:Option<u8> = self.peek()?;
+			let Some(frame) = self.frames.last() else {
+
+				// Root value.
+				if self.started {
+					return match byte {
+						None => Ok(None),
+						Some(_) => Err(self.error(JsonError::TrailingContents))
+					};
+				}
+				self.started = true;
+				if byte.is_none() {
+					return Err(self.error(JsonError::Empty));
+				}
+				self.path_depth = 0;
+				return self.value().map(Some);
+			};
+
+			// Container contents.
+			let (is_dict, state) = (frame.is_dict, frame.state);
+			let close:u8 = if is_dict { b'}' } else { b']' };
+			match (state, byte) {
+				(_, None) => return Err(self.error(if is_dict { JsonError::UnterminatedDict } else { JsonError::UnterminatedArray })),
+				(FrameState::First | FrameState::AfterValue, Some(byte)) if byte == close => {
+					self.advance();
+					self.frames.pop();
+					self.path_depth = self.frames.len();
+					return Ok(Some(if is_dict { JsonEvent::EndDict } else { JsonEvent::EndArray }));
+				},
+				(FrameState::AfterComma, Some(byte)) if byte == close => return Err(self.error(JsonError::TrailingComma)),
+				(FrameState::AfterValue, Some(b',')) => {
+					self.advance();
+					self.frames.last_mut().unwrap().state = FrameState::AfterComma;
+				},
+				(FrameState::AfterValue, Some(_)) => return Err(self.error(JsonError::MissingComma)),
+				(FrameState::First | FrameState::AfterComma, Some(b'"')) if is_dict => {
+					let key:String = self.string()?;
+					let frame:&mut Frame = self.frames.last_mut().unwrap();
+					frame.state = FrameState::AfterKey;
+					frame.token = key.clone();
+					self.path_depth = self.frames.len();
+					return Ok(Some(JsonEvent::Key(key)));
+				},
+				(FrameState::First | FrameState::AfterComma, Some(_)) if is_dict => return Err(self.error(JsonError::InvalidKey)),
+				(FrameState::AfterKey, Some(b':')) => {
+					self.advance();
+					self.skip_whitespace()?;
+					self.frames.last_mut().unwrap().state = FrameState::AfterValue;
+					self.path_depth = self.frames.len();
+					return self.value().map(Some);
+				},
+				(FrameState::AfterKey, Some(_)) => return Err(self.error(JsonError::MissingColon)),
+				(_, Some(_)) => {
+					let frame:&mut Frame = self.frames.last_mut().unwrap();
+					frame.token = frame.length.to_string();
+					frame.length += 1;
+					frame.state = FrameState::AfterValue;
+					self.path_depth = self.frames.len();
+					return self.value().map(Some);
+				}
+			}
+		}
+	}
+
+	/// Read the next value as json. Returns None when the document has ended.
+	pub fn read_value(&mut self) -> Result<Option<Json>, JsonError> {
+		match self.next_event()? {
+			Some(event) => self.value_from(event).map(Some),
+			None => Ok(None)
+		}
+	}
+
+	/// Skip the next value, without keeping its contents.
+	pub fn skip_value(&mut self) -> Result<(), JsonError> {
+		let depth:usize = self.frames.len();
+		if let Some(JsonEvent::Key(_)) = self.next_event()? {
+			self.next_event()?;
+		}
+		self.finish_depth(depth)
+	}
+
+	/// Read the value an RFC 6901 JSON Pointer refers to in the next value, skipping all other contents. Returns None when the pointer does not exist. The rest of the value is not read when the pointer is found.
+	pub fn extract(&mut self, pointer:&str) -> Result<Option<Json>, Box<dyn Error>> {
+		let tokens:Vec<String> = Json::parse_pointer(pointer)?;
+		let depth:usize = self.frames.len();
+		let Some(mut event) = self.next_event()? else {
+			return Ok(None);
+		};
+		'levels: for token in &tokens {
+			if !matches!(event, JsonEvent::StartDict | JsonEvent::StartArray) {
+				self.finish_depth(depth)?;
+				return Ok(None);
+			}
+			loop {
+				let child:JsonEvent = self.next_event()?.unwrap();
+				match child {
+					JsonEvent::EndDict | JsonEvent::EndArray => {
+						self.finish_depth(depth)?;
+						return Ok(None);
+					},
+					JsonEvent::Key(key) if &key == token => {
+						event = self.next_event()?.unwrap();
+						continue 'levels;
+					},
+					JsonEvent::Key(_) => self.skip_value()?,
+					child if &self.frames[self.path_depth - 1].token == token => {
+						event = child;
+						continue 'levels;
+					},
+					child => self.skip_from(child)?
+				}
+			}
+		}
+		Ok(Some(self.value_from(event)?))
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Build a json value from the event it starts with. Dicts and arrays are built with a stack instead of recursion, values nested deeper than `MAX_NESTING_DEPTH` fail with a nesting error.
+	fn value_from(&mut self, event:JsonEvent) -> Result<Json, JsonError> {
+		if matches!(event, JsonEvent::Key(_) | JsonEvent::EndDict | JsonEvent::EndArray) {
+			return Err(self.error(JsonError::UnexpectedToken));
+		}
+
+		// Open containers, with the key they are stored at in their parent dict.
+		let mut open:Vec<(Option<String>, Json)> = Vec::new();
+		let mut key:Option<String> = None;
+		let mut event:JsonEvent = event;
+		loop {
+			let value:Json = match event {
+				JsonEvent::Key(name) => {
+					key = Some(name);
+					event = self.next_event()?.unwrap();
+					continue;
+				},
+				JsonEvent::StartDict | JsonEvent::StartArray => {
+					if open.len() == MAX_NESTING_DEPTH {
+						return Err(self.error(JsonError::NestingTooDeep));
+					}
+					open.push((key.take(), if event == JsonEvent::StartDict { Json::Dict(Vec::new()) } else { Json::Array(Vec::new()) }));
+					event = self.next_event()?.unwrap();
+					continue;
+				},
+				JsonEvent::EndDict | JsonEvent::EndArray => {
+					let (container_key, container) = open.pop().unwrap();
+					key = container_key;
+					container
+				},
+				JsonEvent::Value(value) => value
+			};
+
+			// Add the finished value to its parent, or return it when it is the value that was started with.
+			match open.last_mut() {
+				None => return Ok(value),
+				Some((_, Json::Dict(entries))) => entries.push((Json::String(key.take().unwrap_or_default()), value)),
+				Some((_, Json::Array(items))) => items.push(value),
+				Some(_) => {}
+			}
+			event = self.next_event()?.unwrap();
+		}
+	}
+
+	/// Skip the rest of a value from the event it starts with.
+	fn skip_from(&mut self, event:JsonEvent) -> Result<(), JsonError> {
+		match event {
+			JsonEvent::StartDict | JsonEvent::StartArray => self.finish_depth(self.frames.len() - 1),
+			_ => Ok(())
+		}
+	}
+
+	/// Read events until the reader is back at the given depth.
+	fn finish_depth(&mut self, depth:usize) -> Result<(), JsonError> {
+		while self.frames.len() > depth {
+			self.next_event()?;
+		}
+		Ok(())
+	}
+
+	/// Read a value at the current position.
+	fn value(&mut self) -> Result<JsonEvent, JsonError> {
+		match self.peek()? {
+			Some(b'{') => {
+				self.advance();
+				self.frames.push(Frame { is_dict: true, state: FrameState::First, length: 0, token: String::new() });
+				Ok(JsonEvent::StartDict)
+			},
+			Some(b'[') => {
+				self.advance();
+				self.frames.push(Frame { is_dict: false, state: FrameState::First, length: 0, token: String::new() });
+				Ok(JsonEvent::StartArray)
+			},
+			Some(b'"') => Ok(JsonEvent::Value(Json::String(self.string()?))),
+			Some(b'-' | b'0'..=b'9') => Ok(JsonEvent::Value(self.number()?)),
+			Some(b'a'..=b'z') => {
+				let position:JsonPosition = self.current_position();
+				let mut word:Vec<u8> = Vec::new();
+				while let Some(byte @ b'a'..=b'z') = self.peek()? {
+					word.push(byte);
+					self.advance();
+				}
+				match word.as_slice() {
+					b"true" => Ok(JsonEvent::Value(Json::Bool(true))),
+					b"false" => Ok(JsonEvent::Value(Json::Bool(false))),
+					b"null" => Ok(JsonEvent::Value(Json::Null)),
+					_ => Err(JsonError::UnexpectedToken(position))
+				}
+			},
+			None | Some(b',' | b']' | b'}' | b':') => Err(self.error(JsonError::MissingValue)),
+			Some(_) => Err(self.error(JsonError::UnexpectedToken))
+		}
+	}
+
+	/// Read a string at the current position and decode it.
+	fn string(&mut self) -> Result<String, JsonError> {
+		let start:JsonPosition = self.current_position();
+		self.advance();
+		let mut bytes:Vec<u8> = Vec::new();
+		loop {
+			match self.peek()? {
+				None => return Err(JsonError::UnterminatedString(start)),
+				Some(b'"') => break,
+				Some(byte) if byte < 0x20 => return Err(self.error(JsonError::ControlCharacter)),
+				Some(b'\\') => {
+					let escape:JsonPosition = self.current_position();
+					self.advance();
+					bytes.push(b'\\');
+					match self.peek()? {
+						Some(byte @ (b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't')) => {
+							bytes.push(byte);
+							self.advance();
+						},
+						Some(b'u') => {
+							bytes.push(b'u');
+							self.advance();
+							for _ in 0..4 {
+								match self.peek()? {
+									Some(byte) if byte.is_ascii_hexdigit() => {
+										bytes.push(byte);
+										self.advance();
+									},
+									_ => return Err(JsonError::InvalidEscape(escape))
+								}
+							}
+						},
+						_ => return Err(JsonError::InvalidEscape(escape))
+					}
+				},
+				Some(byte) => {
+					bytes.push(byte);
+					self.advance();
+				}
+			}
+		}
+		self.advance();
+		match String::from_utf8(bytes) {
+			Ok(inner_contents) => Ok(Json::decode_string(&inner_contents, JsonFlavor::Strict)),
+			Err(_) => Err(JsonError::InvalidEncoding(start))
+		}
+	}
+
+	/// Read a number at the current position.
+	fn number(&mut self) -> Result<Json, JsonError> {
+		let start:JsonPosition = self.current_position();
+		let mut lexeme:String = String::new();
+		while let Some(byte @ (b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) = self.peek()? {
+			lexeme.push(byte as char);
+			self.advance();
+		}
+		let number:JsonNumber = JsonNumber::new(&lexeme).map_err(|_| JsonError::InvalidNumber(start.clone()))?;
+		if self.lossless_numbers {
+			return Ok(Json::Number(number));
+		}
+
+		// Integers too large for an i64 are stored as float.
+		match lexeme.parse::<i64>() {
+			Ok(value) if number.is_integer() => Ok(Json::Integer(value)),
+			_ => lexeme.parse::<f64>().map(Json::Float).map_err(|_| JsonError::InvalidNumber(start))
+		}
+	}
+
+	/// Skip white-space at the current position.
+	fn skip_whitespace(&mut self) -> Result<(), JsonError> {
+		while let Some(byte) = self.peek()? {
+			if !WHITESPACE_BYTES.contains(&byte) {
+				break;
+			}
+			self.advance();
+		}
+		Ok(())
+	}
+
+	/// Get the byte at the current position without reading it.
+	fn peek(&mut self) -> Result<Option<u8>, JsonError> {
+		loop {
+			match self.source.fill_buf() {
+				Ok(buffer) => return Ok(buffer.first().copied()),
+				Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+				Err(_) => return Err(self.error(JsonError::ReadFailed))
+			}
+		}
+	}
+
+	/// Read the byte at the current position, keeping track of the line and column.
+	fn advance(&mut self) {
+		let Some(byte) = self.source.fill_buf().ok().and_then(|buffer| buffer.first().copied()) else {
+			return;
+		};
+		self.source.consume(1);
+		self.offset += 1;
+		if byte == b'\n' {
+			self.line += 1;
+			self.column = 1;
+			self.recent.clear();
+			return;
+		}
+		if byte & 0xC0 != 0x80 {
+			self.column += 1;
+		}
+		if self.recent.len() == EXCERPT_LENGTH {
+			self.recent.pop_front();
+		}
+		self.recent.push_back(byte);
+	}
+
+	/// Get the current position. The excerpt only contains the part of the line before the position, as the rest has not been read yet.
+	fn current_position(&self) -> JsonPosition {
+		let recent:Vec<u8> = self.recent.iter().copied().collect();
+		JsonPosition {
+			offset: self.offset,
+			line: self.line,
+			column: self.column,
+			excerpt: String::from_utf8_lossy(&recent).trim_start_matches(char::REPLACEMENT_CHARACTER).to_string()
+		}
+	}
+
+	/// Create an error of the given kind at the current position.
+	fn error(&self, kind:fn(JsonPosition) -> JsonError) -> JsonError {
+		kind(self.current_position())
+	}
+}
+impl<T:Read> Iterator for JsonStreamReader<T> {
+	type Item = Result<JsonEvent, JsonError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished {
+			return None;
+		}
+		let event:Result<Option<JsonEvent>, JsonError> = self.next_event();
+		self.finished = !matches!(event, Ok(Some(_)));
+		event.transpose()
+	}
+}
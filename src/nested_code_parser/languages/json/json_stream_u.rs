@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonError, JsonEvent, JsonStreamReader };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	const EXPORT:&str = r#"{
+		"meta": {"version": 3, "tags": ["a", "b\né"]},
+		"records": [
+			{"id": 1, "payload": {"large": [1, 2, 3]}},
+			{"id": 12345678901234567890, "payload": null, "score": -1.5e2}
+		],
+		"a/b": true
+	}"#;
+
+	fn events(source:&str) -> Result<Vec<JsonEvent>, JsonError> {
+		JsonStreamReader::new(source.as_bytes()).collect()
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_events() {
+		assert_eq!(events(r#"{"a": [1, {"b": null}], "c": "d"}"#), Ok(vec![
+			JsonEvent::StartDict,
+			JsonEvent::Key("a".to_string()),
+			JsonEvent::StartArray,
+			JsonEvent::Value(Json::Integer(1)),
+			JsonEvent::StartDict,
+			JsonEvent::Key("b".to_string()),
+			JsonEvent::Value(Json::Null),
+			JsonEvent::EndDict,
+			JsonEvent::EndArray,
+			JsonEvent::Key("c".to_string()),
+			JsonEvent::Value(Json::String("d".to_string())),
+			JsonEvent::EndDict
+		]));
+		assert_eq!(events(" 12 "), Ok(vec![JsonEvent::Value(Json::Integer(12))]));
+
+		// Reading the whole document matches the regular parser.
+		let mut reader:JsonStreamReader<&[u8]> = JsonStreamReader::new(EXPORT.as_bytes());
+		assert_eq!(reader.read_value().unwrap(), Some(Json::new_strict(EXPORT).unwrap()));
+		assert_eq!(reader.read_value().unwrap(), None);
+	}
+
+	#[test]
+	fn test_paths() {
+		let mut reader:JsonStreamReader<&[u8]> = JsonStreamReader::new(EXPORT.as_bytes());
+		let mut paths:Vec<String> = Vec::new();
+		while let Some(event) = reader.next_event().unwrap() {
+			if let JsonEvent::Value(_) = event {
+				paths.push(reader.path());
+			}
+		}
+		assert_eq!(paths, vec!["/meta/version", "/meta/tags/0", "/meta/tags/1", "/records/0/id", "/records/0/payload/large/0", "/records/0/payload/large/1", "/records/0/payload/large/2", "/records/1/id", "/records/1/payload", "/records/1/score", "/a~1b"]);
+	}
+
+	#[test]
+	fn test_extract() {
+		let extract = |pointer:&str| JsonStreamReader::new(EXPORT.as_bytes()).extract(pointer).unwrap();
+		assert_eq!(extract("/meta/tags/1"), Some(Json::String("b\n\u{e9}".to_string())));
+		assert_eq!(extract("/records/1/id"), Some(Json::Float(12345678901234567890.0)));
+		assert_eq!(extract("/records/0/payload"), Json::new(r#"{"large": [1, 2, 3]}"#).ok());
+		assert_eq!(extract("/a~1b"), Some(Json::Bool(true)));
+		assert_eq!(extract(""), Json::new_strict(EXPORT).ok());
+		assert_eq!(extract("/records/2"), None);
+		assert_eq!(extract("/records/01"), None);
+		assert_eq!(extract("/meta/version/x"), None);
+		assert!(JsonStreamReader::new(EXPORT.as_bytes()).extract("meta").is_err());
+
+		let mut reader:JsonStreamReader<&[u8]> = JsonStreamReader::new(EXPORT.as_bytes()).lossless_numbers();
+		assert_eq!(reader.extract("/records/1/score").unwrap().unwrap().to_string(), "-1.5e2");
+		assert_eq!(reader.next_event().unwrap(), Some(JsonEvent::EndDict));
+		assert_eq!(reader.path(), "/records/1");
+
+		// Skipping values reads past them without returning them.
+		let mut reader:JsonStreamReader<&[u8]> = JsonStreamReader::new(r#"[{"a": [1, [2]]}, 3]"#.as_bytes());
+		assert_eq!(reader.next_event().unwrap(), Some(JsonEvent::StartArray));
+		reader.skip_value().unwrap();
+		assert_eq!(reader.read_value().unwrap(), Some(Json::Integer(3)));
+		assert_eq!(reader.depth(), 1);
+	}
+
+	#[test]
+	fn test_nesting_depth() {
+		let nested = |depth:usize| "[".repeat(depth) + &"]".repeat(depth);
+		assert_eq!(JsonStreamReader::new(nested(512).as_bytes()).read_value().unwrap(), Json::new_strict(&nested(512)).ok());
+		assert!(matches!(JsonStreamReader::new(nested(200000).as_bytes()).read_value(), Err(JsonError::NestingTooDeep(_))));
+		assert!(JsonStreamReader::new(format!("{{\"a\": {}}}", nested(200000)).as_bytes()).extract("/a").is_err());
+
+		// Events and skipping are not limited, as they do not keep the document.
+		assert_eq!(JsonStreamReader::new(nested(200000).as_bytes()).count(), 400000);
+		let source:String = format!("[{}, 1]", nested(200000));
+		let mut reader:JsonStreamReader<&[u8]> = JsonStreamReader::new(source.as_bytes());
+		reader.next_event().unwrap();
+		reader.skip_value().unwrap();
+		assert_eq!(reader.read_value().unwrap(), Some(Json::Integer(1)));
+	}
+
+	#[test]
+	fn test_errors() {
+		let error = |source:&str| events(source).unwrap_err();
+		assert!(matches!(error(""), JsonError::Empty(_)));
+		assert!(matches!(error("[1, 2"), JsonError::UnterminatedArray(_)));
+		assert!(matches!(error("{\"a\": 1"), JsonError::UnterminatedDict(_)));
+		assert!(matches!(error("[1, 2,]"), JsonError::TrailingComma(_)));
+		assert!(matches!(error("[1 2]"), JsonError::MissingComma(_)));
+		assert!(matches!(error("{\"a\" 1}"), JsonError::MissingColon(_)));
+		assert!(matches!(error("{\"a\": }"), JsonError::MissingValue(_)));
+		assert!(matches!(error("{a: 1}"), JsonError::InvalidKey(_)));
+		assert!(matches!(error("[01]"), JsonError::InvalidNumber(_)));
+		assert!(matches!(error("[tru]"), JsonError::UnexpectedToken(_)));
+		assert!(matches!(error("\"a\\x\""), JsonError::InvalidEscape(_)));
+		assert!(matches!(error("\"a\tb\""), JsonError::ControlCharacter(_)));
+		assert!(matches!(error("\"abc"), JsonError::UnterminatedString(_)));
+		assert!(matches!(error("1 2"), JsonError::TrailingContents(_)));
+
+		let error:JsonError = error("{\n\t\"key\": [1, 2 3]\n}");
+		assert!(matches!(error, JsonError::MissingComma(_)));
+		assert_eq!((error.line(), error.column(), error.excerpt()), (2, 15, "\t\"key\": [1, 2 "));
+		let mut reader:JsonStreamReader<&[u8]> = JsonStreamReader::new(b"[1, x, 2]".as_slice());
+		assert_eq!(reader.by_ref().count(), 3);
+		assert!(reader.next().is_none());
+	}
+}
@@ -0,0 +1,558 @@
+use super::Json;
+use std::{ collections::HashSet, error::Error };
+
+
+
+impl Json {
+
+	/* TOML METHODS */
+
+	/// Parse TOML contents to a dict. Tables and inline tables become dicts, arrays of tables become arrays of dicts. Dates and times are kept as the strings they were written as.
+	pub fn from_toml(contents:&str) -> Result<Json, Box<dyn Error>> {
+		TomlReader::new(contents).read()
+	}
+
+	/// Convert a dict to TOML contents. Dicts become tables and arrays of dicts become arrays of tables, when nested in other arrays they are written inline instead. Null and integers beyond 64 bits can not be represented in TOML and return an error.
+	pub fn to_toml(&self) -> Result<String, Box<dyn Error>> {
+		let entries:&Vec<(Json, Json)> = self.as_object().ok_or_else(|| conversion_error(&[], "the root should be a dict"))?;
+		let mut output:String = String::new();
+		write_table(entries, &mut Vec::new(), &mut output)?;
+		Ok(output)
+	}
+}
+
+
+
+/// Write the values of a table, followed by its sub-tables.
+fn write_table(entries:&[(Json, Json)], path:&mut Vec<String>, output:&mut String) -> Result<(), Box<dyn Error>> {
+	for (key, value) in entries.iter().filter(|(_, value)| !is_table(value) && !is_table_array(value)) {
+		path.push(Json::key_string(key));
+		*output += &format!("{} = {}\n", toml_key(&path[path.len() - 1]), inline_value(value, path)?);
+		path.pop();
+	}
+	for (key, value) in entries {
+		path.push(Json::key_string(key));
+		let header:String = path.iter().map(|key| toml_key(key)).collect::<Vec<String>>().join(".");
+		match value {
+			Json::Dict(entries) if is_table(value) => {
+				*output += &format!("{}[{header}]\n", if output.is_empty() { "" } else { "\n" });
+				write_table(entries, path, output)?;
+			},
+			Json::Array(items) if is_table_array(value) => {
+				for item in items.iter().filter_map(|item| item.as_object()) {
+					*output += &format!("{}[[{header}]]\n", if output.is_empty() { "" } else { "\n" });
+					write_table(item, path, output)?;
+				}
+			},
+			_ => {}
+		}
+		path.pop();
+	}
+	Ok(())
+}
+
+/// Whether or not a value should be written as table.
+fn is_table(value:&Json) -> bool {
+	matches!(value, Json::Dict(_))
+}
+
+/// Whether or not a value should be written as array of tables.
+fn is_table_array(value:&Json) -> bool {
+	matches!(value, Json::Array(items) if !items.is_empty() && items.iter().all(is_table))
+}
+
+/// Get a value written inline.
+fn inline_value(value:&Json, path:&mut Vec<String>) -> Result<String, Box<dyn Error>> {
+	Ok(match value {
+		Json::Dict(entries) if entries.is_empty() => "{}".to_string(),
+		Json::Dict(entries) => {
+			let mut values:Vec<String> = Vec::new();
+			for (key, value) in entries {
+				path.push(Json::key_string(key));
+				values.push(format!("{} = {}", toml_key(&path[path.len() - 1]), inline_value(value, path)?));
+				path.pop();
+			}
+			format!("{{ {} }}", values.join(", "))
+		},
+		Json::Array(items) => {
+			let mut values:Vec<String> = Vec::new();
+			for (index, item) in items.iter().enumerate() {
+				path.push(index.to_string());
+				values.push(inline_value(item, path)?);
+				path.pop();
+			}
+			format!("[{}]", values.join(", "))
+		},
+		Json::String(value) => toml_string(value),
+		Json::Float(value) if value.is_nan() => "nan".to_string(),
+		Json::Float(value) if value.is_infinite() => if *value > 0.0 { "inf" } else { "-inf" }.to_string(),
+		Json::Float(value) => format!("{value:?}"),
+		Json::Number(value) if value.is_integer() && value.as_i64().is_none() => return Err(conversion_error(path, "toml integers should fit in 64 bits")),
+		Json::Null => return Err(conversion_error(path, "toml does not support null")),
+		value => value.to_string()
+	})
+}
+
+/// Get a key as bare key if possible, or as quoted key otherwise.
+fn toml_key(key:&str) -> String {
+	if !key.is_empty() && key.chars().all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-') {
+		key.to_string()
+	} else {
+		toml_string(key)
+	}
+}
+
+/// Get a string as TOML basic string.
+fn toml_string(value:&str) -> String {
+	Json::escape_string(value).replace('\u{7f}', "\\u007f")
+}
+
+/// Create the error for a value that can not be converted to TOML.
+fn conversion_error(path:&[String], reason:&str) -> Box<dyn Error> {
+	format!("Could not convert json at '{}' to toml, {reason}.", Json::build_pointer(path)).into()
+}
+
+
+
+/// Reads TOML contents into json.
+struct TomlReader {
+	chars:Vec<char>,
+	index:usize,
+	line:usize
+}
+impl TomlReader {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new reader.
+	fn new(contents:&str) -> TomlReader {
+		TomlReader { chars: contents.chars().collect(), index: 0, line: 1 }
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Read the document.
+	fn read(mut self) -> Result<Json, Box<dyn Error>> {
+		let mut root:Json = Json::Dict(Vec::new());
+		let mut table:Vec<String> = Vec::new();
+		let mut defined_tables:HashSet<Vec<String>> = HashSet::new();
+		let mut static_values:HashSet<Vec<String>> = HashSet::new();
+		loop {
+			self.skip_blank();
+			match self.peek(0) {
+				None => break,
+				Some('[') => {
+					let is_array:bool = self.peek(1) == Some('[');
+					self.index += if is_array { 2 } else { 1 };
+					self.skip_whitespace();
+					table = self.read_key()?;
+					self.skip_whitespace();
+					self.expect(']')?;
+					self.validate_open(&static_values, &table)?;
+					let (last, parent) = table.split_last().unwrap();
+					let entries:&mut Vec<(Json, Json)> = self.table_mut(&mut root, parent)?;
+					let existing:Option<&mut Json> = entries.iter_mut().find(|(key, _)| key.as_str() == Some(last)).map(|(_, value)| value);
+					if is_array {
+						self.expect(']')?;
+						match existing {
+							Some(Json::Array(items)) => items.push(Json::Dict(Vec::new())),
+							Some(_) => return Err(self.error(&format!("key '{last}' is already defined"))),
+							None => entries.push((Json::String(last.clone()), Json::Array(vec![Json::Dict(Vec::new())])))
+						}
+						defined_tables.retain(|defined| !defined.starts_with(&table));
+						static_values.retain(|defined| !defined.starts_with(&table));
+					} else {
+						if matches!(existing, Some(Json::Array(_))) {
+							return Err(self.error(&format!("table '{}' is already defined as array of tables", table.join("."))));
+						}
+						if !defined_tables.insert(table.clone()) {
+							return Err(self.error(&format!("table '{}' is defined twice", table.join("."))));
+						}
+						self.table_mut(&mut root, &table)?;
+					}
+				},
+				Some(_) => {
+					let keys:Vec<String> = self.read_key()?;
+					self.skip_whitespace();
+					self.expect('=')?;
+					self.skip_whitespace();
+					let value:Json = self.read_value()?;
+					let path:Vec<String> = table.iter().chain(&keys).cloned().collect();
+					self.validate_open(&static_values, &path[..path.len() - 1])?;
+					if matches!(value, Json::Dict(_) | Json::Array(_)) {
+						static_values.insert(path.clone());
+					}
+					self.insert(&mut root, &path, value)?;
+
+					// Tables created by dotted keys can not be defined again by a header.
+					defined_tables.extend((table.len() + 1..path.len()).map(|length| path[..length].to_vec()));
+				}
+			}
+			self.skip_whitespace();
+			self.skip_comment();
+			match self.peek(0) {
+				None | Some('\n' | '\r') => {},
+				Some(char) => return Err(self.error(&format!("expected the end of the line, found '{char}'")))
+			}
+		}
+		Ok(root)
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Create an error at the current line.
+	fn error(&self, reason:&str) -> Box<dyn Error> {
+		format!("Could not parse toml at line {}, {reason}.", self.line).into()
+	}
+
+	/// Get a character ahead of the cursor without consuming it.
+	fn peek(&self, offset:usize) -> Option<char> {
+		self.chars.get(self.index + offset).copied()
+	}
+
+	/// Whether or not the contents at the cursor start with a string.
+	fn starts_with(&self, text:&str) -> bool {
+		text.chars().enumerate().all(|(offset, char)| self.peek(offset) == Some(char))
+	}
+
+	/// Consume the character at the cursor.
+	fn next(&mut self) -> Option<char> {
+		let char:Option<char> = self.peek(0);
+		if char == Some('\n') {
+			self.line += 1;
+		}
+		self.index += 1;
+		char
+	}
+
+	/// Consume the character at the cursor if it is the expected character, or return an error.
+	fn expect(&mut self, expected:char) -> Result<(), Box<dyn Error>> {
+		match self.peek(0) {
+			Some(char) if char == expected => { self.next(); Ok(()) },
+			Some('\n' | '\r') => Err(self.error(&format!("expected '{expected}', found the end of the line"))),
+			Some(char) => Err(self.error(&format!("expected '{expected}', found '{char}'"))),
+			None => Err(self.error(&format!("expected '{expected}', found the end of the contents")))
+		}
+	}
+
+	/// Skip spaces and tabs.
+	fn skip_whitespace(&mut self) {
+		while matches!(self.peek(0), Some(' ' | '\t')) {
+			self.next();
+		}
+	}
+
+	/// Skip a comment up to the end of the line.
+	fn skip_comment(&mut self) {
+		if self.peek(0) == Some('#') {
+			while !matches!(self.peek(0), None | Some('\n')) {
+				self.next();
+			}
+		}
+	}
+
+	/// Skip white-space, line breaks and comments.
+	fn skip_blank(&mut self) {
+		loop {
+			self.skip_whitespace();
+			self.skip_comment();
+			match self.peek(0) {
+				Some('\n' | '\r') => { self.next(); },
+				_ => return
+			}
+		}
+	}
+
+	/// Read a dotted key.
+	fn read_key(&mut self) -> Result<Vec<String>, Box<dyn Error>> {
+		let mut keys:Vec<String> = Vec::new();
+		loop {
+			self.skip_whitespace();
+			keys.push(match self.peek(0) {
+				Some('"') => self.read_basic_string()?,
+				Some('\'') => self.read_literal_string()?,
+				_ => {
+					let mut key:String = String::new();
+					while let Some(char) = self.peek(0).filter(|char| char.is_ascii_alphanumeric() || *char == '_' || *char == '-') {
+						key.push(char);
+						self.next();
+					}
+					if key.is_empty() {
+						return Err(self.error("expected a key"));
+					}
+					key
+				}
+			});
+			self.skip_whitespace();
+			if self.peek(0) != Some('.') {
+				return Ok(keys);
+			}
+			self.next();
+		}
+	}
+
+	/// Read a value.
+	fn read_value(&mut self) -> Result<Json, Box<dyn Error>> {
+		match self.peek(0) {
+			Some('"') if self.starts_with("\"\"\"") => self.read_multiline_string('"').map(Json::String),
+			Some('"') => self.read_basic_string().map(Json::String),
+			Some('\'') if self.starts_with("'''") => self.read_multiline_string('\'').map(Json::String),
+			Some('\'') => self.read_literal_string().map(Json::String),
+			Some('[') => self.read_array(),
+			Some('{') => self.read_inline_table(),
+			Some('\n' | '\r' | '#') | None => Err(self.error("expected a value")),
+			Some(_) => self.read_scalar()
+		}
+	}
+
+	/// Read a single line string with escape sequences.
+	fn read_basic_string(&mut self) -> Result<String, Box<dyn Error>> {
+		self.next();
+		let mut output:String = String::new();
+		loop {
+			match self.next() {
+				Some('"') => return Ok(output),
+				Some('\\') => self.read_escape(&mut output)?,
+				Some('\n' | '\r') | None => return Err(self.error("string is not terminated")),
+				Some(char) => output.push(char)
+			}
+		}
+	}
+
+	/// Read a single line string without escape sequences.
+	fn read_literal_string(&mut self) -> Result<String, Box<dyn Error>> {
+		self.next();
+		let mut output:String = String::new();
+		loop {
+			match self.next() {
+				Some('\'') => return Ok(output),
+				Some('\n' | '\r') | None => return Err(self.error("string is not terminated")),
+				Some(char) => output.push(char)
+			}
+		}
+	}
+
+	/// Read a multi-line string. Escape sequences are only used when the quote is a double quote. A line break directly after the opening quotes is not part of the string.
+	fn read_multiline_string(&mut self, quote:char) -> Result<String, Box<dyn Error>> {
+		self.index += 3;
+		if self.starts_with("\r\n") {
+			self.index += 1;
+		}
+		if self.peek(0) == Some('\n') {
+			self.next();
+		}
+		let mut output:String = String::new();
+		loop {
+			match self.peek(0) {
+				Some(char) if char == quote && (0..3).all(|offset| self.peek(offset) == Some(quote)) => {
+
+					// Up to two quotes directly before the closing quotes are part of the string.
+					let quote_count:usize = (0..5).take_while(|offset| self.peek(*offset) == Some(quote)).count();
+					output.extend(std::iter::repeat_n(quote, quote_count - 3));
+					self.index += quote_count;
+					return Ok(output);
+				},
+				Some('\\') if quote == '"' => {
+					self.next();
+					let mut lookahead:usize = 0;
+					while matches!(self.peek(lookahead), Some(' ' | '\t' | '\r')) {
+						lookahead += 1;
+					}
+
+					// A backslash at the end of a line trims all white-space up to the next content.
+					if self.peek(lookahead) == Some('\n') {
+						while matches!(self.peek(0), Some(' ' | '\t' | '\r' | '\n')) {
+							self.next();
+						}
+					} else {
+						self.read_escape(&mut output)?;
+					}
+				},
+				Some(char) => {
+					output.push(char);
+					self.next();
+				},
+				None => return Err(self.error("string is not terminated"))
+			}
+		}
+	}
+
+	/// Read an escape sequence after its backslash.
+	fn read_escape(&mut self, output:&mut String) -> Result<(), Box<dyn Error>> {
+		let escaped:char = match self.next() {
+			Some('b') => '\u{8}',
+			Some('t') => '\t',
+			Some('n') => '\n',
+			Some('f') => '\u{c}',
+			Some('r') => '\r',
+			Some('e') => '\u{1b}',
+			Some('"') => '"',
+			Some('\\') => '\\',
+			Some(size @ ('u' | 'U')) => {
+				let length:usize = if size == 'u' { 4 } else { 8 };
+				let digits:String = (0..length).filter_map(|offset| self.peek(offset)).collect();
+				self.index += length;
+				u32::from_str_radix(&digits, 16).ok().filter(|_| digits.len() == length && digits.chars().all(|char| char.is_ascii_hexdigit())).and_then(char::from_u32).ok_or_else(|| self.error(&format!("invalid unicode escape '\\{size}{digits}'")))?
+			},
+			Some(char) => return Err(self.error(&format!("invalid escape sequence '\\{char}'"))),
+			None => return Err(self.error("string is not terminated"))
+		};
+		output.push(escaped);
+		Ok(())
+	}
+
+	/// Read an array. Arrays can span multiple lines and have a trailing comma.
+	fn read_array(&mut self) -> Result<Json, Box<dyn Error>> {
+		self.next();
+		let mut items:Vec<Json> = Vec::new();
+		loop {
+			self.skip_blank();
+			if self.peek(0) == Some(']') {
+				self.next();
+				return Ok(Json::Array(items));
+			}
+			items.push(self.read_value()?);
+			self.skip_blank();
+			match self.peek(0) {
+				Some(',') => { self.next(); },
+				_ => { self.expect(']')?; return Ok(Json::Array(items)); }
+			}
+		}
+	}
+
+	/// Read an inline table.
+	fn read_inline_table(&mut self) -> Result<Json, Box<dyn Error>> {
+		self.next();
+		let mut table:Json = Json::Dict(Vec::new());
+		self.skip_blank();
+		if self.peek(0) == Some('}') {
+			self.next();
+			return Ok(table);
+		}
+		loop {
+			self.skip_blank();
+			let keys:Vec<String> = self.read_key()?;
+			self.expect('=')?;
+			self.skip_whitespace();
+			let value:Json = self.read_value()?;
+			self.insert(&mut table, &keys, value)?;
+			self.skip_blank();
+			match self.peek(0) {
+				Some(',') => { self.next(); },
+				_ => { self.expect('}')?; return Ok(table); }
+			}
+		}
+	}
+
+	/// Read a bare value, like a number, bool or date.
+	fn read_scalar(&mut self) -> Result<Json, Box<dyn Error>> {
+		let is_end = |char:Option<char>| matches!(char, None | Some(' ' | '\t' | '\n' | '\r' | ',' | ']' | '}' | '#'));
+		let mut token:String = String::new();
+		while !is_end(self.peek(0)) {
+			token.push(self.next().unwrap());
+		}
+
+		// A date and a time can be separated by a space.
+		if is_date(&token) && token.len() == 10 && self.peek(0) == Some(' ') && self.peek(1).map(|char| char.is_ascii_digit()).unwrap_or(false) {
+			token.push(self.next().unwrap());
+			while !is_end(self.peek(0)) {
+				token.push(self.next().unwrap());
+			}
+		}
+		match token.as_str() {
+			"true" => return Ok(Json::Bool(true)),
+			"false" => return Ok(Json::Bool(false)),
+			"inf" | "+inf" => return Ok(Json::Float(f64::INFINITY)),
+			"-inf" => return Ok(Json::Float(f64::NEG_INFINITY)),
+			"nan" | "+nan" | "-nan" => return Ok(Json::Float(f64::NAN)),
+			_ if is_date(&token) => return Ok(Json::String(token)),
+			_ => {}
+		}
+		let invalid = || self.error(&format!("invalid value '{token}'"));
+		if token.starts_with('_') || token.ends_with('_') || token.contains("__") {
+			return Err(invalid());
+		}
+		let number:String = token.replace('_', "");
+		for (prefix, radix) in [("0x", 16), ("0o", 8), ("0b", 2)] {
+			if let Some(digits) = number.strip_prefix(prefix) {
+				return i64::from_str_radix(digits, radix).map(Json::Integer).map_err(|_| invalid());
+			}
+		}
+		let digits:&str = number.strip_prefix(['+', '-']).unwrap_or(&number);
+		let integer_digits:&str = digits.split(['.', 'e', 'E']).next().unwrap_or_default();
+		if integer_digits.len() > 1 && integer_digits.starts_with('0') {
+			return Err(self.error(&format!("number '{token}' has leading zeros")));
+		}
+		if let Some((integer_digits, fraction_digits)) = digits.split_once('.') {
+			if !integer_digits.ends_with(|char:char| char.is_ascii_digit()) || !fraction_digits.starts_with(|char:char| char.is_ascii_digit()) {
+				return Err(invalid());
+			}
+		}
+		if !digits.is_empty() && digits.chars().all(|char| char.is_ascii_digit()) {
+			return number.parse::<i64>().map(Json::Integer).map_err(|_| self.error(&format!("integer '{token}' does not fit in 64 bits")));
+		}
+		if digits.starts_with(|char:char| char.is_ascii_digit()) && digits.chars().all(|char| char.is_ascii_digit() || ".eE+-".contains(char)) {
+			return number.parse::<f64>().map(Json::Float).map_err(|_| invalid());
+		}
+		Err(invalid())
+	}
+
+	/// Validate that a path does not lead into an inline table or static array, those can not be extended after their definition.
+	fn validate_open(&self, static_values:&HashSet<Vec<String>>, path:&[String]) -> Result<(), Box<dyn Error>> {
+		match (1..=path.len()).find(|length| static_values.contains(&path[..*length])) {
+			Some(length) => Err(self.error(&format!("key '{}' is already defined as value", path[length - 1]))),
+			None => Ok(())
+		}
+	}
+
+	/// Get the entries of the table at a path, creating missing tables. Arrays of tables refer to their last table.
+	fn table_mut<'a>(&self, root:&'a mut Json, path:&[String]) -> Result<&'a mut Vec<(Json, Json)>, Box<dyn Error>> {
+		let mut current:&mut Json = root;
+		for key in path {
+			let Json::Dict(entries) = current else { unreachable!() };
+			let index:usize = match entries.iter().position(|(existing, _)| existing.as_str() == Some(key)) {
+				Some(index) => index,
+				None => {
+					entries.push((Json::String(key.clone()), Json::Dict(Vec::new())));
+					entries.len() - 1
+				}
+			};
+			let value:&mut Json = &mut entries[index].1;
+			let is_table_array:bool = matches!(value, Json::Array(items) if matches!(items.last(), Some(Json::Dict(_))));
+			if !is_table_array && !matches!(value, Json::Dict(_)) {
+				return Err(self.error(&format!("key '{key}' is already defined as value")));
+			}
+			current = match value {
+				Json::Array(items) => items.last_mut().unwrap(),
+				table => table
+			};
+		}
+		match current {
+			Json::Dict(entries) => Ok(entries),
+			_ => unreachable!()
+		}
+	}
+
+	/// Insert a value at a path, creating missing tables.
+	fn insert(&self, root:&mut Json, path:&[String], value:Json) -> Result<(), Box<dyn Error>> {
+		let (last, parent) = path.split_last().unwrap();
+		let entries:&mut Vec<(Json, Json)> = self.table_mut(root, parent)?;
+		if entries.iter().any(|(key, _)| key.as_str() == Some(last)) {
+			return Err(self.error(&format!("key '{last}' is defined twice")));
+		}
+		entries.push((Json::String(last.clone()), value));
+		Ok(())
+	}
+}
+
+
+
+/// Whether or not a token starts like a date or time, like '1979-05-27' or '07:32:00'.
+fn is_date(token:&str) -> bool {
+	let digits = |range:std::ops::Range<usize>| token.get(range).map(|digits| digits.chars().all(|char| char.is_ascii_digit())).unwrap_or(false);
+	(digits(0..4) && token[4..].starts_with('-')) || (digits(0..2) && token[2..].starts_with(':'))
+}
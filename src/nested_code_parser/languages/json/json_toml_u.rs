@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ json, Json };
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_from_toml() {
+		let contents:&str = r#"
+			# Service settings.
+			title = "api" # trailing comment
+			"quoted key" = 'C:\path'
+			numbers = [ 1_000, 0xff, 0o17, 0b11, -2,
+				1.5, 1e3, inf, ]
+			created = 1979-05-27 07:32:00Z
+			site.name = "docs"
+			multi = """one \
+			two"""
+			literal = '''a "b"'''
+			point = { x = 1, y.z = 2 }
+
+			[server]
+			host = "localhost\tlocal\u00e9"
+			enabled = true
+
+			[server.tls]
+			cert = "x.pem"
+
+			[[targets]]
+			name = "a"
+
+			[[targets]]
+			name = "b"
+			[targets.limits]
+			cpu = 2
+		"#;
+		let mut json:Json = Json::from_toml(contents).unwrap();
+		assert_eq!(json["numbers"], json!([1000, 255, 15, 3, -2, 1.5, 1000.0, f64::INFINITY]));
+		assert_eq!(json["multi"], json!("one two"));
+		json["numbers"] = Json::Null;
+		assert_eq!(json, json!({
+			"title": "api",
+			"quoted key": "C:\\path",
+			"numbers": null,
+			"created": "1979-05-27 07:32:00Z",
+			"site": { "name": "docs" },
+			"multi": "one two",
+			"literal": "a \"b\"",
+			"point": { "x": 1, "y": { "z": 2 } },
+			"server": { "host": "localhost\tlocalé", "enabled": true, "tls": { "cert": "x.pem" } },
+			"targets": [{ "name": "a" }, { "name": "b", "limits": { "cpu": 2 } }]
+		}));
+	}
+
+	#[test]
+	fn test_from_toml_array_sub_tables() {
+		let contents:&str = "[[fruit]]\nname = \"apple\"\n[fruit.physical]\ncolor = \"red\"\n\n[[fruit]]\nname = \"banana\"\n[fruit.physical]\ncolor = \"yellow\"\n";
+		assert_eq!(Json::from_toml(contents).unwrap(), json!({
+			"fruit": [
+				{ "name": "apple", "physical": { "color": "red" } },
+				{ "name": "banana", "physical": { "color": "yellow" } }
+			]
+		}));
+	}
+
+	#[test]
+	fn test_from_toml_errors() {
+		let error = |contents:&str| Json::from_toml(contents).unwrap_err().to_string();
+		assert_eq!(error("a = 1\na = 2"), "Could not parse toml at line 2, key 'a' is defined twice.");
+		assert_eq!(error("[a]\n[a]"), "Could not parse toml at line 2, table 'a' is defined twice.");
+		assert_eq!(error("[[a]]\n[a.b]\n[a.b]"), "Could not parse toml at line 3, table 'a.b' is defined twice.");
+		assert_eq!(error("a = 1\n[a.b]"), "Could not parse toml at line 2, key 'a' is already defined as value.");
+		assert_eq!(error("a = \"text"), "Could not parse toml at line 1, string is not terminated.");
+		assert_eq!(error("a = 1 2"), "Could not parse toml at line 1, expected the end of the line, found '2'.");
+		assert_eq!(error("a = yes"), "Could not parse toml at line 1, invalid value 'yes'.");
+		assert_eq!(error("a = 9223372036854775808"), "Could not parse toml at line 1, integer '9223372036854775808' does not fit in 64 bits.");
+		assert_eq!(error("a = [1, 2"), "Could not parse toml at line 1, expected ']', found the end of the contents.");
+		assert_eq!(error("= 1"), "Could not parse toml at line 1, expected a key.");
+		assert_eq!(error("a = \"\\u+041\""), "Could not parse toml at line 1, invalid unicode escape '\\u+041'.");
+		assert_eq!(error("a = \"\\U0000004\""), "Could not parse toml at line 1, invalid unicode escape '\\U0000004\"'.");
+
+		// Tables can not be redefined or extended after their definition is closed.
+		assert_eq!(error("[[a]]\n[a]"), "Could not parse toml at line 2, table 'a' is already defined as array of tables.");
+		assert_eq!(error("a = [{x = 1}]\n[[a]]"), "Could not parse toml at line 2, key 'a' is already defined as value.");
+		assert_eq!(error("a = {b = 1}\n[a]"), "Could not parse toml at line 2, key 'a' is already defined as value.");
+		assert_eq!(error("a = {b = 1}\n[a.c]"), "Could not parse toml at line 2, key 'a' is already defined as value.");
+		assert_eq!(error("a = {b = 1}\na.c = 2"), "Could not parse toml at line 2, key 'a' is already defined as value.");
+		assert_eq!(error("a = []\n[[a]]"), "Could not parse toml at line 2, key 'a' is already defined as value.");
+		assert_eq!(error("a.b = 1\n[a]"), "Could not parse toml at line 2, table 'a' is defined twice.");
+		assert_eq!(error("[x]\na.b.c = 1\n[x.a.b]"), "Could not parse toml at line 3, table 'x.a.b' is defined twice.");
+
+		// Numbers can not have leading zeros and need digits on both sides of the decimal point.
+		assert_eq!(error("a = 01"), "Could not parse toml at line 1, number '01' has leading zeros.");
+		assert_eq!(error("a = -01.5"), "Could not parse toml at line 1, number '-01.5' has leading zeros.");
+		assert_eq!(error("a = 00e1"), "Could not parse toml at line 1, number '00e1' has leading zeros.");
+		assert_eq!(error("a = 1.e5"), "Could not parse toml at line 1, invalid value '1.e5'.");
+		assert_eq!(error("a = 1."), "Could not parse toml at line 1, invalid value '1.'.");
+		assert_eq!(error("a = +.5"), "Could not parse toml at line 1, invalid value '+.5'.");
+	}
+
+	#[test]
+	fn test_from_toml_closed_definitions() {
+		let json:Json = Json::from_toml("[a.b]\nc = 1\n[a]\nd = 2\n[x]\ny.z = 3\n[x.y.w]\nv = 4\n[[t]]\nu = {}\n[[t]]\n[t.u]\nn = [0, -0, 0.5, 0e1, 1_000]").unwrap();
+		assert_eq!(json.to_string(), r#"{"a":{"b":{"c":1},"d":2},"x":{"y":{"z":3,"w":{"v":4}}},"t":[{"u":{}},{"u":{"n":[0,0,0.5,0.0,1000]}}]}"#);
+	}
+
+	#[test]
+	fn test_to_toml() {
+		let json:Json = json!({
+			"server": { "host": "localhost", "tls": { "cert": "x.pem" }, "port": 8080 },
+			"title": "api \"v2\"",
+			"ports": [80, 443],
+			"matrix": [[1, 2], [{ "a": true }]],
+			"targets": [{ "name": "a" }, { "name": "b", "limits": { "cpu": 2.5 } }],
+			"empty": {},
+			"my key": f64::NAN
+		});
+		let contents:String = json.to_toml().unwrap();
+		assert_eq!(contents, concat!(
+			"title = \"api \\\"v2\\\"\"\nports = [80, 443]\nmatrix = [[1, 2], [{ a = true }]]\n\"my key\" = nan\n",
+			"\n[server]\nhost = \"localhost\"\nport = 8080\n\n[server.tls]\ncert = \"x.pem\"\n",
+			"\n[[targets]]\nname = \"a\"\n\n[[targets]]\nname = \"b\"\n\n[targets.limits]\ncpu = 2.5\n",
+			"\n[empty]\n"
+		));
+		let mut parsed:Json = Json::from_toml(&contents).unwrap();
+		assert!(parsed["my key"].as_f64().unwrap().is_nan());
+		parsed["my key"] = Json::Null;
+		assert_eq!(parsed, json!({
+			"title": "api \"v2\"",
+			"ports": [80, 443],
+			"matrix": [[1, 2], [{ "a": true }]],
+			"my key": null,
+			"server": { "host": "localhost", "port": 8080, "tls": { "cert": "x.pem" } },
+			"targets": [{ "name": "a" }, { "name": "b", "limits": { "cpu": 2.5 } }],
+			"empty": {}
+		}));
+	}
+
+	#[test]
+	fn test_to_toml_errors() {
+		assert_eq!(json!([1]).to_toml().unwrap_err().to_string(), "Could not convert json at '' to toml, the root should be a dict.");
+		assert_eq!(json!({ "a": { "b": [1, null] } }).to_toml().unwrap_err().to_string(), "Could not convert json at '/a/b/1' to toml, toml does not support null.");
+		let big:Json = Json::parse_lossless("{\"a\": 18446744073709551615}", crate::JsonFlavor::Strict).unwrap();
+		assert_eq!(big.to_toml().unwrap_err().to_string(), "Could not convert json at '/a' to toml, toml integers should fit in 64 bits.");
+	}
+}
@@ -0,0 +1,211 @@
+use super::{ json_convert::collect_entries, Json };
+use std::{ collections::{ BTreeMap, HashMap }, error::Error, fmt::{ self, Display, Formatter } };
+#[cfg(feature = "derive")]
+pub use omni_parser_derive::{ FromJson, ToJson };
+
+
+
+/// Convert a value to json. Can be derived for structs and enums with the 'derive' feature.
+pub trait ToJson {
+	fn to_json(&self) -> Json;
+}
+
+/// Create a value from json. Can be derived for structs and enums with the 'derive' feature.
+pub trait FromJson:Sized {
+	fn from_json(json:&Json) -> Result<Self, FromJsonError>;
+
+	/// Get the value to use when a dict does not have the field for this value. Returns None when the field is required.
+	fn from_missing() -> Option<Self> {
+		None
+	}
+}
+
+
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FromJsonError {
+	pub path:String,
+	pub message:String
+}
+impl FromJsonError {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new error at the current location.
+	pub fn new(message:&str) -> FromJsonError {
+		FromJsonError { path: String::new(), message: message.to_string() }
+	}
+
+	/// Create a new error for json that is not of the expected kind.
+	pub fn expected(expected:&str, json:&Json) -> FromJsonError {
+		let found:String = match json {
+			Json::Dict(_) => "a dict".to_string(),
+			Json::Array(_) => "an array".to_string(),
+			json => json.to_string()
+		};
+		FromJsonError::new(&format!("expected {expected}, found {found}"))
+	}
+
+	/// Create a new error for a required field that is missing.
+	pub fn missing_field(name:&str) -> FromJsonError {
+		FromJsonError::new(&format!("missing field '{name}'"))
+	}
+
+
+
+	/* MODIFICATION METHODS */
+
+	/// Return self located in a child of the current location, used to build the path while returning from nested values.
+	pub fn at<T:ToString>(mut self, token:T) -> Self {
+		self.path = Json::build_pointer(&[token.to_string()]) + &self.path;
+		self
+	}
+}
+impl Display for FromJsonError {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(f, "Could not convert json at '{}', {}.", self.path, self.message)
+	}
+}
+impl Error for FromJsonError {}
+
+
+
+// Scalars use the From and TryFrom conversions, only mapping the error.
+macro_rules! typed_scalar {
+	($($type:ty => $name:expr),*) => {
+		$(
+			impl ToJson for $type {
+				fn to_json(&self) -> Json {
+					Json::from(*self)
+				}
+			}
+			impl FromJson for $type {
+				fn from_json(json:&Json) -> Result<$type, FromJsonError> {
+					<$type>::try_from(json.clone()).map_err(|_| FromJsonError::expected($name, json))
+				}
+			}
+		)*
+	};
+}
+typed_scalar!(
+	i8 => "i8", i16 => "i16", i32 => "i32", i64 => "i64", i128 => "i128", isize => "isize",
+	u8 => "u8", u16 => "u16", u32 => "u32", u64 => "u64", u128 => "u128", usize => "usize",
+	f32 => "f32", f64 => "f64", bool => "bool"
+);
+
+
+
+impl ToJson for Json {
+	fn to_json(&self) -> Json {
+		self.clone()
+	}
+}
+impl FromJson for Json {
+	fn from_json(json:&Json) -> Result<Json, FromJsonError> {
+		Ok(json.clone())
+	}
+}
+impl ToJson for str {
+	fn to_json(&self) -> Json {
+		Json::String(self.to_string())
+	}
+}
+impl ToJson for String {
+	fn to_json(&self) -> Json {
+		Json::String(self.clone())
+	}
+}
+impl FromJson for String {
+	fn from_json(json:&Json) -> Result<String, FromJsonError> {
+		String::try_from(json.clone()).map_err(|_| FromJsonError::expected("string", json))
+	}
+}
+impl ToJson for char {
+	fn to_json(&self) -> Json {
+		Json::String(self.to_string())
+	}
+}
+impl FromJson for char {
+	fn from_json(json:&Json) -> Result<char, FromJsonError> {
+		let mut chars = json.as_str().unwrap_or_default().chars();
+		match (chars.next(), chars.next()) {
+			(Some(char), None) => Ok(char),
+			_ => Err(FromJsonError::expected("a single character string", json))
+		}
+	}
+}
+impl<T:ToJson + ?Sized> ToJson for &T {
+	fn to_json(&self) -> Json {
+		(**self).to_json()
+	}
+}
+impl<T:ToJson + ?Sized> ToJson for Box<T> {
+	fn to_json(&self) -> Json {
+		(**self).to_json()
+	}
+}
+impl<T:FromJson> FromJson for Box<T> {
+	fn from_json(json:&Json) -> Result<Box<T>, FromJsonError> {
+		T::from_json(json).map(Box::new)
+	}
+}
+impl<T:ToJson> ToJson for Option<T> {
+	fn to_json(&self) -> Json {
+		self.as_ref().map(|value| value.to_json()).unwrap_or(Json::Null)
+	}
+}
+impl<T:FromJson> FromJson for Option<T> {
+	fn from_json(json:&Json) -> Result<Option<T>, FromJsonError> {
+		match json {
+			Json::Null => Ok(None),
+			json => T::from_json(json).map(Some)
+		}
+	}
+	fn from_missing() -> Option<Option<T>> {
+		Some(None)
+	}
+}
+impl<T:ToJson> ToJson for [T] {
+	fn to_json(&self) -> Json {
+		Json::Array(self.iter().map(|item| item.to_json()).collect())
+	}
+}
+impl<T:ToJson> ToJson for Vec<T> {
+	fn to_json(&self) -> Json {
+		self.as_slice().to_json()
+	}
+}
+impl<T:FromJson> FromJson for Vec<T> {
+	fn from_json(json:&Json) -> Result<Vec<T>, FromJsonError> {
+		let items:&Vec<Json> = json.as_array().ok_or_else(|| FromJsonError::expected("array", json))?;
+		items.iter().enumerate().map(|(index, item)| T::from_json(item).map_err(|error| error.at(index))).collect()
+	}
+}
+impl<T:ToJson> ToJson for HashMap<String, T> {
+	fn to_json(&self) -> Json {
+		self.iter().collect::<BTreeMap<&String, &T>>().to_json()
+	}
+}
+impl<T:FromJson> FromJson for HashMap<String, T> {
+	fn from_json(json:&Json) -> Result<HashMap<String, T>, FromJsonError> {
+		typed_entries(json)
+	}
+}
+impl<K:AsRef<str>, T:ToJson> ToJson for BTreeMap<K, T> {
+	fn to_json(&self) -> Json {
+		Json::Dict(self.iter().map(|(key, value)| (Json::String(key.as_ref().to_string()), value.to_json())).collect())
+	}
+}
+impl<T:FromJson> FromJson for BTreeMap<String, T> {
+	fn from_json(json:&Json) -> Result<BTreeMap<String, T>, FromJsonError> {
+		typed_entries(json)
+	}
+}
+
+
+
+/// Collect the converted entries of a dict into a map, mapping the errors of the shared dict conversion.
+fn typed_entries<T:FromJson, U:FromIterator<(String, T)>>(json:&Json) -> Result<U, FromJsonError> {
+	let entries:&Vec<(Json, Json)> = json.as_object().ok_or_else(|| FromJsonError::expected("dict", json))?;
+	collect_entries(entries.iter().map(|(key, value)| (key, value)), |key| FromJsonError::new(&format!("key {key} is not a string")), |key, value| T::from_json(value).map_err(|error| error.at(key)))
+}
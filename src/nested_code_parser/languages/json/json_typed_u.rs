@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ json, FromJson, FromJsonError, Json, ToJson };
+	use std::collections::{ BTreeMap, HashMap };
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_to_json() {
+		assert_eq!(7u8.to_json(), Json::Integer(7));
+		assert_eq!(1.5f32.to_json(), Json::Float(1.5));
+		assert_eq!("text".to_json(), Json::String("text".to_string()));
+		assert_eq!(Some(vec![true, false]).to_json(), json!([true, false]));
+		assert_eq!(None::<String>.to_json(), Json::Null);
+		let map:HashMap<String, Vec<i32>> = HashMap::from([("b".to_string(), vec![2]), ("a".to_string(), vec![1])]);
+		assert_eq!(map.to_json().to_string(), r#"{"a":[1],"b":[2]}"#);
+	}
+
+	#[test]
+	fn test_from_json() {
+		assert_eq!(u16::from_json(&json!(8080)).unwrap(), 8080);
+		assert_eq!(f64::from_json(&json!(2)).unwrap(), 2.0);
+		assert_eq!(String::from_json(&json!("api")).unwrap(), "api");
+		assert_eq!(char::from_json(&json!("c")).unwrap(), 'c');
+		assert_eq!(Option::<bool>::from_json(&Json::Null).unwrap(), None);
+		assert_eq!(Option::<bool>::from_missing(), Some(None));
+		assert_eq!(bool::from_missing(), None);
+		assert_eq!(Vec::<Box<i32>>::from_json(&json!([1, 2])).unwrap(), vec![Box::new(1), Box::new(2)]);
+		let map:BTreeMap<String, Vec<u8>> = BTreeMap::from_json(&json!({ "x": [1], "y": [] })).unwrap();
+		assert_eq!(map, BTreeMap::from([("x".to_string(), vec![1]), ("y".to_string(), Vec::new())]));
+	}
+
+	#[test]
+	fn test_errors() {
+		let error:FromJsonError = Vec::<u8>::from_json(&json!([1, 300])).unwrap_err();
+		assert_eq!(error.path, "/1");
+		assert_eq!(error.to_string(), "Could not convert json at '/1', expected u8, found 300.");
+		let error:FromJsonError = HashMap::<String, Vec<bool>>::from_json(&json!({ "a/b": [true, "no"] })).unwrap_err();
+		assert_eq!(error.to_string(), "Could not convert json at '/a~1b/1', expected bool, found \"no\".");
+		assert_eq!(String::from_json(&json!({})).unwrap_err().message, "expected string, found a dict");
+		assert_eq!(char::from_json(&json!("ab")).unwrap_err().message, "expected a single character string, found \"ab\"");
+	}
+}
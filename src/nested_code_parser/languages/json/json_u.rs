@@ -0,0 +1,274 @@
+
+
+
+
+#[cfg(test)]
+mod test {
+	use crate::{ assert_snapshot, json_parser, DuplicateKeys, Json, JsonError, JsonFlavor, JsonObject, JsonReader };
+	use std::{ env, fs };
+
+
+
+	const TEST_SUITE_DIR:&str = "src/nested_code_parser/languages/json/test_suite";
+
+
+
+	#[test]
+	fn test_bool() {
+		assert_eq!(Json::new(r#"true"#).unwrap(), Json::Bool(true));
+		assert_eq!(Json::new(r#"True"#).unwrap(), Json::Bool(true));
+		assert_eq!(Json::new(r#"TRUE"#).unwrap(), Json::Bool(true));
+		assert_eq!(Json::new(r#"false"#).unwrap(), Json::Bool(false));
+		assert_eq!(Json::new(r#"False"#).unwrap(), Json::Bool(false));
+		assert_eq!(Json::new(r#"FALSE"#).unwrap(), Json::Bool(false));
+		assert_eq!(Json::new(r#"[false,true]"#).unwrap(), Json::Array(vec![Json::Bool(false), Json::Bool(true)]));
+	}
+
+	#[test]
+	fn test_null() {
+		assert_eq!(Json::new(r#"null"#).unwrap(), Json::Null);
+		assert_eq!(Json::new(r#"NULL"#).unwrap(), Json::Null);
+		assert_eq!(Json::new_strict(r#"null"#).unwrap(), Json::Null);
+		assert!(Json::new_strict(r#"Null"#).is_err());
+	}
+
+	#[test]
+	fn test_float() {
+		assert_eq!(Json::new(r#"000.8"#).unwrap(), Json::Float(0.8));
+		assert_eq!(Json::new(r#"0.8"#).unwrap(), Json::Float(0.8));
+		assert_eq!(Json::new(r#"10.8"#).unwrap(), Json::Float(10.8));
+		assert_eq!(Json::new(r#"-000.8"#).unwrap(), Json::Float(-0.8));
+		assert_eq!(Json::new(r#"-0.8"#).unwrap(), Json::Float(-0.8));
+		assert_eq!(Json::new(r#"-10.8"#).unwrap(), Json::Float(-10.8));
+		assert_eq!(Json::new(r#"1e5"#).unwrap(), Json::Float(1e5));
+		assert_eq!(Json::new(r#"2.5E-3"#).unwrap(), Json::Float(2.5E-3));
+		assert_eq!(Json::new_strict(r#"-1.5e+2"#).unwrap(), Json::Float(-150.0));
+		assert_eq!(Json::new(r#"100000000000000000000"#).unwrap(), Json::Float(1e20));
+	}
+
+	#[test]
+	fn test_integer() {
+		assert_eq!(Json::new(r#"0008"#).unwrap(), Json::Integer(8));
+		assert_eq!(Json::new(r#"8"#).unwrap(), Json::Integer(8));
+		assert_eq!(Json::new(r#"108"#).unwrap(), Json::Integer(108));
+		assert_eq!(Json::new(r#"-0008"#).unwrap(), Json::Integer(-8));
+		assert_eq!(Json::new(r#"-8"#).unwrap(), Json::Integer(-8));
+		assert_eq!(Json::new(r#"-108"#).unwrap(), Json::Integer(-108));
+	}
+
+	#[test]
+	fn test_string() {
+		assert_eq!(
+			Json::new(r#""test_str""#).unwrap(),
+			Json::String("test_str".to_string())
+		);
+	}
+
+	#[test]
+	fn test_string_escapes() {
+		assert_eq!(Json::new(r#""a\"b\\c\/d\n\t""#).unwrap(), Json::String("a\"b\\c/d\n\t".to_string()));
+		assert_eq!(Json::new(r#""caf\u00e9 \uD834\uDD1E""#).unwrap(), Json::String("café 𝄞".to_string()));
+		assert_eq!(Json::new(r#""\uD800 \x""#).unwrap(), Json::String("\u{FFFD} \\x".to_string()));
+		assert_eq!(Json::new(r#""\x41\v\0\'""#).unwrap(), Json::String(r#"\x41\v\0\'"#.to_string()));
+		assert_eq!(Json::new_json5(r#""\x41\v\0\'""#).unwrap(), Json::String("A\u{b}\0'".to_string()));
+		assert_eq!(Json::new(r#"'a\nb'"#).unwrap(), Json::String("a\\nb".to_string()));
+		assert_eq!(Json::new_strict(r#""line\u000abreak""#).unwrap(), Json::String("line\nbreak".to_string()));
+	}
+
+	#[test]
+	fn test_string_serialization() {
+		for value in ["plain", "quote \" and \\", "new\nline\ttab\u{1}\u{8}", "café 𝄞", ""] {
+			let serialized:String = Json::String(value.to_string()).to_string();
+			assert_eq!(Json::new_strict(&serialized).unwrap(), Json::String(value.to_string()), "{serialized}");
+		}
+		assert_eq!(Json::escape_string("a\"b\n\u{1f}"), r#""a\"b\n\u001f""#);
+	}
+
+	#[test]
+	fn test_array() {
+		assert_eq!(
+			Json::new(r#"["a", "b", "c"]"#).unwrap(),
+			Json::Array(vec![
+				Json::String("a".to_string()),
+				Json::String("b".to_string()),
+				Json::String("c".to_string())
+			])
+		);
+	}
+
+	#[test]
+	fn test_dict() {
+		assert_eq!(
+			Json::new(r#"{ "a": "A", "b": 2.5, c: "C" }"#).unwrap(),
+			Json::Dict(vec![
+				(Json::String("a".to_string()), Json::String("A".to_string())),
+				(Json::String("b".to_string()), Json::Float(2.5)),
+				(Json::String("c".to_string()), Json::String("C".to_string()))
+			])
+		);
+	}
+
+	#[test]
+	fn test_parse_tree_snapshot() {
+		assert_snapshot(
+			&json_parser().parse("{\n\t\"a\": [1, -2.5, true],\n\t\"b\": { \"c\": \"d\\\"e\" }\n}"),
+			"src/nested_code_parser/snapshots/json_grammar.snap"
+		);
+	}
+
+	#[test]
+	fn test_reformat() {
+		assert_eq!(
+			Json::reformat(r#"{"a":[1,2 , "x, y"],"b" :{}, "c":{"d":true}}"#),
+			"{\n\t\"a\": [\n\t\t1,\n\t\t2,\n\t\t\"x, y\"\n\t],\n\t\"b\": {},\n\t\"c\": {\n\t\t\"d\": true\n\t}\n}"
+		);
+		assert_eq!(Json::new(&Json::reformat(r#"{"a":[1,2],"b":"c"}"#)).unwrap(), Json::new(r#"{"a":[1,2],"b":"c"}"#).unwrap());
+	}
+
+	#[test]
+	fn test_strict_rejects_lenient_input() {
+		for contents in [r#"True"#, r#"'a'"#, r#"`a`"#, r#"{a: 1}"#, r#"0008"#, r#"  1  x"#] {
+			assert!(Json::new_strict(contents).is_err(), "{contents}");
+			assert!(Json::new(contents).is_ok() || contents.ends_with('x'), "{contents}");
+		}
+		assert_eq!(Json::parse(r#"{"a": [1, null]}"#, JsonFlavor::Strict).unwrap(), Json::new(r#"{"a": [1, null]}"#).unwrap());
+	}
+
+	#[test]
+	fn test_conformance_suite() {
+		let mut directories:Vec<String> = vec![TEST_SUITE_DIR.to_string()];
+		directories.extend(env::var("JSON_TEST_SUITE_DIR"));
+		for directory in directories {
+			let mut checked_files:usize = 0;
+			for entry in fs::read_dir(&directory).unwrap() {
+				let path = entry.unwrap().path();
+				let file_name:String = path.file_name().unwrap().to_string_lossy().to_string();
+				if path.extension().map(|extension| extension != "json").unwrap_or(true) {
+					continue;
+				}
+				let contents:Option<String> = String::from_utf8(fs::read(&path).unwrap()).ok();
+
+				// Lenient mode should never panic.
+				if let Some(contents) = &contents {
+					let _ = Json::new(contents);
+				}
+
+				// Strict mode should follow the expectation in the file prefix. Contents that are not UTF-8 can not be parsed, so count as rejected.
+				let accepted:bool = contents.as_ref().map(|contents| Json::new_strict(contents).is_ok()).unwrap_or(false);
+				match &file_name[..2] {
+					"y_" => {
+						assert!(accepted, "{file_name} should be accepted");
+						assert!(Json::parse(contents.as_ref().unwrap(), JsonFlavor::Json5).is_ok(), "{file_name} should be accepted as JSON5");
+					},
+					"n_" => assert!(!accepted, "{file_name} should be rejected"),
+					_ => {
+						let expected:bool = implementation_defined_result(&file_name, contents.is_some()).unwrap_or_else(|| panic!("{file_name} has no chosen behavior"));
+						assert_eq!(accepted, expected, "{file_name} should be {}", if expected { "accepted" } else { "rejected" });
+					}
+				}
+				checked_files += 1;
+			}
+			assert!(checked_files > 0);
+		}
+	}
+
+	/// Get whether or not strict mode accepts an implementation defined 'i_' file of the conformance suite.
+	fn implementation_defined_result(file_name:&str, is_utf8:bool) -> Option<bool> {
+		match file_name {
+
+			// Json is parsed from str, so other encodings, invalid UTF-8 and encoded surrogates are rejected.
+			_ if !is_utf8 => Some(false),
+
+			// Numbers outside the range of i64 are stored as float, rounding to infinity or zero if needed.
+			name if name.starts_with("i_number_") => Some(true),
+
+			// Lone and invalid surrogate escapes decode to the replacement character.
+			name if name.starts_with("i_string_") || name.starts_with("i_object_key_") => Some(true),
+
+			// A byte order mark is not json white-space.
+			name if name.to_lowercase().contains("bom") => Some(false),
+
+			// Dicts and arrays can be nested up to 512 levels deep, see `test_nesting_depth`.
+			name if name.contains("_nested_") => Some(true),
+			_ => None
+		}
+	}
+
+	#[test]
+	fn test_nesting_depth() {
+		for flavor in [JsonFlavor::Lenient, JsonFlavor::Strict, JsonFlavor::Json5] {
+			assert!(Json::parse(&("[".repeat(512) + &"]".repeat(512)), flavor).is_ok());
+			assert!(Json::parse(&("{\"a\":".repeat(512) + "1" + &"}".repeat(512)), flavor).is_ok());
+			let error:JsonError = Json::parse(&"[".repeat(100000), flavor).unwrap_err();
+			assert!(matches!(error, JsonError::NestingTooDeep(_)), "{error}");
+			assert_eq!(error.position().offset, 512);
+			assert!(matches!(Json::parse(&"[{\"a\":".repeat(300), flavor), Err(JsonError::NestingTooDeep(_))));
+		}
+
+		// Brackets in strings and comments and closing tags of other containers do not change the depth.
+		assert!(Json::new_strict(&format!("[\"{}\\\"{}\"]", "[".repeat(1000), "{".repeat(1000))).is_ok());
+		assert!(Json::new_json5(&format!("[// {}\n/* {} */ '{}']", "[".repeat(1000), "{".repeat(1000), "[".repeat(1000))).is_ok());
+		assert!(matches!(Json::new(&"{]".repeat(1000)), Err(JsonError::NestingTooDeep(_))));
+	}
+
+	#[test]
+	fn test_json5() {
+		let contents:&str = "// VS Code style settings\n{\n\t/* block\n\t   comment */\n\tunquoted: 'single \\'quoted\\'',\n\t\"editor.fontSize\": 14, // trailing comment\n\thex: -0x1F,\n\tnumbers: [.5, 5., +1, Infinity, -Infinity,],\n\tmulti: 'line \\\n continued',\n\tnull: null,\n\t$id_1: \"\\x41\\v\",\n}\n";
+		let json:Json = Json::new_json5(contents).unwrap();
+		assert_eq!(json["unquoted"].as_str(), Some("single 'quoted'"));
+		assert_eq!(json["editor.fontSize"].as_i64(), Some(14));
+		assert_eq!(json["hex"].as_i64(), Some(-31));
+		assert_eq!(Json::new_json5("0xFFFFFFFFFFFFFFFF").unwrap().as_u64(), Some(u64::MAX));
+		assert_eq!(Json::new_json5("-0x8000000000000000").unwrap(), Json::Integer(i64::MIN));
+		assert_eq!(Json::new_json5("0x1000000000000000000000000000000000").unwrap(), Json::Float(2f64.powi(132)));
+		assert_eq!(json["numbers"], Json::Array(vec![Json::Float(0.5), Json::Float(5.0), Json::Integer(1), Json::Float(f64::INFINITY), Json::Float(f64::NEG_INFINITY)]));
+		assert_eq!(json["multi"].as_str(), Some("line  continued"));
+		assert!(json.get("null").unwrap().is_null());
+		assert_eq!(json["$id_1"].as_str(), Some("A\u{b}"));
+		assert!(Json::new_json5("NaN").unwrap().as_f64().unwrap().is_nan());
+		assert_eq!(Json::new_json5("{nullable: 1, truth: 2}").unwrap().to_string(), r#"{"nullable":1,"truth":2}"#);
+	}
+
+	#[test]
+	fn test_json5_rejects_invalid_input() {
+		for contents in ["[1,,]", "{a: 1,,}", "{1: 2}", "{a: undefined}", "[1 /* unterminated", "{-Infinity: 1}", "[01]", "[-00.5]", "01e2"] {
+			assert!(Json::new_json5(contents).is_err(), "{contents}");
+		}
+		for contents in ["// comment\n1", "[1,]", "{a: 1}", "{'a': 1}"] {
+			assert!(Json::new_strict(contents).is_err(), "{contents}");
+		}
+		for flavor in [JsonFlavor::Lenient, JsonFlavor::Strict, JsonFlavor::Json5] {
+			assert!(matches!(Json::parse("{} garbage", flavor), Err(JsonError::TrailingContents(_))));
+			assert!(matches!(Json::parse("[1] [2]", flavor), Err(JsonError::TrailingContents(_))));
+		}
+		assert_eq!(Json::new_json5("[0, -0.5, 0e1, 10]").unwrap().to_string(), "[0,-0.5,0.0,10]");
+	}
+
+	#[test]
+	fn test_duplicate_keys() {
+		let contents:&str = r#"{"a": 1, "b": 2, "a": 3}"#;
+		let read = |policy:DuplicateKeys| JsonReader::new(JsonFlavor::Strict).duplicate_keys(policy).read(contents);
+		assert_eq!(read(DuplicateKeys::KeepAll).unwrap().to_string(), r#"{"a":1,"b":2,"a":3}"#);
+		assert_eq!(read(DuplicateKeys::FirstWins).unwrap().to_string(), r#"{"a":1,"b":2}"#);
+		assert_eq!(read(DuplicateKeys::LastWins).unwrap().to_string(), r#"{"a":3,"b":2}"#);
+		let error:JsonError = read(DuplicateKeys::Error).unwrap_err();
+		assert!(matches!(error, JsonError::DuplicateKey(_)));
+		assert_eq!(error.column(), 18);
+		assert!(JsonReader::new(JsonFlavor::Json5).duplicate_keys(DuplicateKeys::Error).read("{a: 1, 'a': 2}").is_err());
+		assert!(JsonReader::new(JsonFlavor::Strict).duplicate_keys(DuplicateKeys::Error).read(r#"{"a": {"a": 1}}"#).is_ok());
+	}
+
+	#[test]
+	fn test_read_object() {
+		let object:JsonObject = JsonReader::new(JsonFlavor::Strict).read_object(r#"{"b": 1, "a": {"c": true}, "b": 2}"#).unwrap();
+		assert_eq!(object.keys().collect::<Vec<&str>>(), vec!["b", "a"]);
+		assert_eq!(object["b"], Json::Integer(2));
+		assert_eq!(object["a"]["c"], Json::Bool(true));
+		let object:JsonObject = JsonReader::new(JsonFlavor::Strict).duplicate_keys(DuplicateKeys::FirstWins).read_object(r#"{"b": 1, "b": 2}"#).unwrap();
+		assert_eq!(object["b"], Json::Integer(1));
+		assert!(matches!(JsonReader::new(JsonFlavor::Strict).read_object("[1]"), Err(JsonError::UnexpectedToken(_))));
+		assert!(matches!(JsonReader::new(JsonFlavor::Lenient).read_object("{a: {1: 2}}"), Err(JsonError::InvalidKey(position)) if position.column == 6));
+		assert!(JsonReader::new(JsonFlavor::Json5).read_object("{a: {b: 2}}").is_ok());
+		assert!(matches!(Json::new_strict("{1: 2}"), Err(JsonError::InvalidKey(_))));
+	}
+}
@@ -0,0 +1,225 @@
+use super::Json;
+use std::{ error::Error, fmt, io };
+
+
+
+const DEFAULT_INDENT:&str = "\t";
+const NON_FINITE_ERROR:&str = "Could not write canonical json, NaN and infinite numbers can not be represented.";
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum KeyOrder { Source, Sorted, Canonical }
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct JsonWriter {
+	indent:Option<String>,
+	key_order:KeyOrder
+}
+impl JsonWriter {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a writer that emits JSON without any white-space. NaN and infinite numbers are written as null.
+	pub fn compact() -> JsonWriter {
+		JsonWriter {
+			indent: None,
+			key_order: KeyOrder::Source
+		}
+	}
+
+	/// Create a writer that emits JSON with one entry per line and nested dicts and arrays indented.
+	pub fn pretty() -> JsonWriter {
+		JsonWriter {
+			indent: Some(DEFAULT_INDENT.to_string()),
+			key_order: KeyOrder::Source
+		}
+	}
+
+	/// Create a writer that emits RFC 8785 canonical JSON. Output is compact, keys are sorted by their UTF-16 code units and numbers are written the way ECMAScript does, so equal values always produce the same bytes. NaN and infinite numbers fail to write, as they have no canonical form.
+	pub fn canonical() -> JsonWriter {
+		JsonWriter {
+			indent: None,
+			key_order: KeyOrder::Canonical
+		}
+	}
+
+	/// Return a version of self that indents using the given string. Turns a compact writer into a pretty one.
+	pub fn indent_with(mut self, indent:&str) -> Self {
+		self.indent = Some(indent.to_string());
+		self
+	}
+
+	/// Return a version of self that writes the entries of dicts sorted by key.
+	pub fn sort_keys(mut self) -> Self {
+		if self.key_order == KeyOrder::Source {
+			self.key_order = KeyOrder::Sorted;
+		}
+		self
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Write a json value to a string.
+	pub fn to_string(&self, json:&Json) -> Result<String, Box<dyn Error>> {
+		let mut output:String = String::new();
+		self.write_fmt(json, &mut output).map_err(|_| NON_FINITE_ERROR)?;
+		Ok(output)
+	}
+
+	/// Write a json value to a formatter or string.
+	pub fn write_fmt<T:fmt::Write>(&self, json:&Json, target:&mut T) -> fmt::Result {
+		self.write_value(json, target, 0)
+	}
+
+	/// Write a json value to a file, socket or other byte stream.
+	pub fn write_io<T:io::Write>(&self, json:&Json, target:&mut T) -> io::Result<()> {
+		let mut adapter:IoAdapter<T> = IoAdapter { target, error: None };
+		match self.write_fmt(json, &mut adapter) {
+			Ok(_) => Ok(()),
+			Err(_) => Err(adapter.error.unwrap_or(io::Error::new(io::ErrorKind::InvalidData, NON_FINITE_ERROR)))
+		}
+	}
+
+	/// Write a single json value.
+	fn write_value<T:fmt::Write>(&self, json:&Json, target:&mut T, depth:usize) -> fmt::Result {
+		match json {
+			Json::Dict(entries) => {
+				let mut entries:Vec<(String, &Json)> = entries.iter().map(|(key, value)| Ok((self.key_to_string(key)?, value))).collect::<Result<Vec<(String, &Json)>, fmt::Error>>()?;
+				match self.key_order {
+					KeyOrder::Source => {},
+					KeyOrder::Sorted => entries.sort_by(|(left, _), (right, _)| left.cmp(right)),
+					KeyOrder::Canonical => entries.sort_by(|(left, _), (right, _)| left.encode_utf16().cmp(right.encode_utf16()))
+				}
+				self.write_collection(target, depth, '{', '}', &entries, |target, (key, value)| {
+					target.write_str(&Json::escape_string(key))?;
+					target.write_str(if self.indent.is_some() { ": " } else { ":" })?;
+					self.write_value(value, target, depth + 1)
+				})
+			},
+			Json::Array(items) => self.write_collection(target, depth, '[', ']', items, |target, item| self.write_value(item, target, depth + 1)),
+			Json::String(value) => target.write_str(&Json::escape_string(value)),
+			Json::Integer(value) if self.key_order == KeyOrder::Canonical => target.write_str(&ecmascript_number(*value as f64)),
+			Json::Integer(value) => write!(target, "{value}"),
+			Json::Float(value) if !value.is_finite() && self.key_order == KeyOrder::Canonical => Err(fmt::Error),
+			Json::Float(value) if !value.is_finite() => target.write_str("null"),
+			Json::Float(value) if self.key_order == KeyOrder::Canonical => target.write_str(&ecmascript_number(*value)),
+			Json::Float(value) => write!(target, "{value:?}"),
+			Json::Number(value) if self.key_order == KeyOrder::Canonical && !value.as_f64().is_finite() => Err(fmt::Error),
+			Json::Number(value) if self.key_order == KeyOrder::Canonical => target.write_str(&ecmascript_number(value.as_f64())),
+			Json::Number(value) => target.write_str(value.as_str()),
+			Json::Bool(value) => write!(target, "{value}"),
+			Json::Null => target.write_str("null")
+		}
+	}
+
+	/// Write the items of a dict or array between brackets.
+	fn write_collection<T:fmt::Write, U, V:Fn(&mut T, &U) -> fmt::Result>(&self, target:&mut T, depth:usize, open:char, close:char, items:&[U], write_item:V) -> fmt::Result {
+		target.write_char(open)?;
+		for (index, item) in items.iter().enumerate() {
+			if index > 0 {
+				target.write_char(',')?;
+			}
+			self.write_newline(target, depth + 1)?;
+			write_item(target, item)?;
+		}
+		if !items.is_empty() {
+			self.write_newline(target, depth)?;
+		}
+		target.write_char(close)
+	}
+
+	/// Write a newline and indentation when writing pretty json.
+	fn write_newline<T:fmt::Write>(&self, target:&mut T, depth:usize) -> fmt::Result {
+		if let Some(indent) = &self.indent {
+			target.write_char('\n')?;
+			target.write_str(&indent.repeat(depth))?;
+		}
+		Ok(())
+	}
+
+	/// Get the string to use as key. JSON keys are always strings, other keys are written as the string of their compact json.
+	fn key_to_string(&self, key:&Json) -> Result<String, fmt::Error> {
+		match key {
+			Json::String(key) => Ok(key.clone()),
+			key => {
+				let mut output:String = String::new();
+				JsonWriter { indent: None, key_order: self.key_order }.write_fmt(key, &mut output)?;
+				Ok(output)
+			}
+		}
+	}
+}
+impl Default for JsonWriter {
+	fn default() -> Self {
+		JsonWriter::compact()
+	}
+}
+
+
+
+impl Json {
+
+	/* SERIALIZATION METHODS */
+
+	/// Write the json with one entry per line and nested dicts and arrays indented. NaN and infinite numbers are written as null.
+	pub fn to_pretty_string(&self) -> String {
+		JsonWriter::pretty().to_string(self).expect("Could not write pretty json")
+	}
+
+	/// Write the json as RFC 8785 canonical JSON, suitable for signing and hashing. Fails on NaN and infinite numbers.
+	pub fn to_canonical_string(&self) -> Result<String, Box<dyn Error>> {
+		JsonWriter::canonical().to_string(self)
+	}
+}
+
+
+
+/// Format a number the way ECMAScript's Number.prototype.toString does, as required by RFC 8785.
+fn ecmascript_number(value:f64) -> String {
+	if value == 0.0 {
+		return "0".to_string();
+	}
+
+	// Get the shortest round-trip digits and the decimal exponent.
+	let scientific:String = format!("{:e}", value.abs());
+	let (mantissa, exponent) = scientific.split_once('e').unwrap();
+	let digits:String = mantissa.replace('.', "");
+	let digit_count:i32 = digits.len() as i32;
+	let point_position:i32 = exponent.parse::<i32>().unwrap() + 1;
+
+	// Place the decimal point.
+	let sign:&str = if value < 0.0 { "-" } else { "" };
+	let body:String = if digit_count <= point_position && point_position <= 21 {
+		digits + &"0".repeat((point_position - digit_count) as usize)
+	} else if 0 < point_position && point_position <= 21 {
+		format!("{}.{}", &digits[..point_position as usize], &digits[point_position as usize..])
+	} else if -6 < point_position && point_position <= 0 {
+		format!("0.{}{}", "0".repeat(-point_position as usize), digits)
+	} else {
+		let exponent_sign:&str = if point_position > 0 { "+" } else { "-" };
+		let fraction:String = if digit_count > 1 { format!(".{}", &digits[1..]) } else { String::new() };
+		format!("{}{}e{}{}", &digits[..1], fraction, exponent_sign, (point_position - 1).abs())
+	};
+	sign.to_string() + &body
+}
+
+
+
+/// Lets a byte stream be used as formatting target, keeping the io error that stopped writing.
+struct IoAdapter<'a, T:io::Write> {
+	target:&'a mut T,
+	error:Option<io::Error>
+}
+impl<'a, T:io::Write> fmt::Write for IoAdapter<'a, T> {
+	fn write_str(&mut self, contents:&str) -> fmt::Result {
+		self.target.write_all(contents.as_bytes()).map_err(|error| {
+			self.error = Some(error);
+			fmt::Error
+		})
+	}
+}
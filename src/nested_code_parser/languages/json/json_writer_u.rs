@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Json, JsonFlavor, JsonWriter };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn example_json() -> Json {
+		Json::new(r#"{"b": [1, 2.0, "x\ny"], "a": {"c": null, "d": true}, "e": [], "f": {}}"#).unwrap()
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_compact() {
+		assert_eq!(example_json().to_string(), r#"{"b":[1,2.0,"x\ny"],"a":{"c":null,"d":true},"e":[],"f":{}}"#);
+		assert_eq!(Json::Float(1e20).to_string(), "1e20");
+		assert_eq!(Json::Float(f64::NAN).to_string(), "null");
+	}
+
+	#[test]
+	fn test_pretty() {
+		assert_eq!(
+			example_json().to_pretty_string(),
+			"{\n\t\"b\": [\n\t\t1,\n\t\t2.0,\n\t\t\"x\\ny\"\n\t],\n\t\"a\": {\n\t\t\"c\": null,\n\t\t\"d\": true\n\t},\n\t\"e\": [],\n\t\"f\": {}\n}"
+		);
+		assert_eq!(JsonWriter::compact().indent_with("  ").to_string(&Json::Array(vec![Json::Null])).unwrap(), "[\n  null\n]");
+	}
+
+	#[test]
+	fn test_sort_keys() {
+		assert_eq!(JsonWriter::compact().sort_keys().to_string(&example_json()).unwrap(), r#"{"a":{"c":null,"d":true},"b":[1,2.0,"x\ny"],"e":[],"f":{}}"#);
+	}
+
+	#[test]
+	fn test_round_trip() {
+		for writer in [JsonWriter::compact(), JsonWriter::pretty(), JsonWriter::compact().sort_keys()] {
+			let written:Json = Json::new_strict(&writer.to_string(&example_json()).unwrap()).unwrap();
+			assert_eq!(writer.to_string(&written).unwrap(), writer.to_string(&example_json()).unwrap());
+		}
+		let float_json:Json = Json::Array(vec![Json::Float(1.0), Json::Float(-0.1), Json::Float(1e-7), Json::Float(123456789.125)]);
+		assert_eq!(Json::new_strict(&float_json.to_string()).unwrap(), float_json);
+	}
+
+	#[test]
+	fn test_canonical() {
+		// Key order follows UTF-16 code units, so the astral character sorts before the private use one.
+		let json:Json = Json::new(r#"{"\uE000": 1, "😀": 2, "b": 3, "a": {"z": 1, "y": 2}}"#).unwrap();
+		assert_eq!(json.to_canonical_string().unwrap(), "{\"a\":{\"y\":2,\"z\":1},\"b\":3,\"😀\":2,\"\u{E000}\":1}");
+
+		let numbers:Json = Json::Array(vec![
+			Json::Float(1.0), Json::Float(-0.0), Json::Float(1e21), Json::Float(1e20), Json::Float(1e-7), Json::Float(0.000001),
+			Json::Float(123.456), Json::Float(-1.5e-10), Json::Float(4.5e300), Json::Integer(9007199254740993)
+		]);
+		assert_eq!(numbers.to_canonical_string().unwrap(), "[1,0,1e+21,100000000000000000000,1e-7,0.000001,123.456,-1.5e-10,4.5e+300,9007199254740992]");
+
+		// Non-finite numbers have no canonical form.
+		let non_finite:Json = Json::Array(vec![Json::Float(f64::NAN)]);
+		assert_eq!(non_finite.to_string(), "[null]");
+		assert_eq!(non_finite.to_canonical_string().unwrap_err().to_string(), "Could not write canonical json, NaN and infinite numbers can not be represented.");
+		assert!(Json::parse_lossless("1e999", JsonFlavor::Strict).unwrap().to_canonical_string().is_err());
+		assert!(JsonWriter::canonical().write_io(&Json::Float(f64::INFINITY), &mut Vec::new()).is_err());
+	}
+
+	#[test]
+	fn test_write_io() {
+		let mut output:Vec<u8> = Vec::new();
+		JsonWriter::compact().write_io(&example_json(), &mut output).unwrap();
+		assert_eq!(String::from_utf8(output).unwrap(), example_json().to_string());
+	}
+}
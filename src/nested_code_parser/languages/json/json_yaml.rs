@@ -0,0 +1,618 @@
+use super::{ Json, JsonFlavor, JsonNumber };
+use std::error::Error;
+
+
+
+const INDENT_SIZE:usize = 2;
+const INDICATOR_CHARS:&str = "-?:,[]{}#&*!|>'\"%@`";
+const AMBIGUOUS_WORDS:[&str; 6] = ["yes", "no", "on", "off", "y", "n"];
+
+
+
+impl Json {
+
+	/* YAML METHODS */
+
+	/// Parse YAML contents. Supports block and flow collections, plain, quoted and block scalars and comments. Plain scalars are typed by the YAML 1.2 core schema, keys are always strings. Anchors, aliases, tags, complex keys and multiple documents are not supported and return an error.
+	pub fn from_yaml(contents:&str) -> Result<Json, Box<dyn Error>> {
+		YamlReader::new(contents).read()
+	}
+
+	/// Convert json to YAML contents in block style. Strings that would be read as another type are quoted.
+	pub fn to_yaml(&self) -> String {
+		let mut output:String = String::new();
+		match self {
+			Json::Dict(entries) if !entries.is_empty() => write_block(self, 0, &mut output),
+			Json::Array(items) if !items.is_empty() => write_block(self, 0, &mut output),
+			json => output = yaml_scalar(json) + "\n"
+		}
+		output
+	}
+}
+
+
+
+/// Write a non-empty dict or array in block style at an indent.
+fn write_block(json:&Json, indent:usize, output:&mut String) {
+	let write_child = |value:&Json, output:&mut String, is_item:bool| {
+		if value.is_empty() || !matches!(value, Json::Dict(_) | Json::Array(_)) {
+			*output += &format!(" {}\n", yaml_scalar(value));
+		} else if is_item {
+
+			// Items start their first line after the dash.
+			let mut child:String = String::new();
+			write_block(value, indent + INDENT_SIZE, &mut child);
+			*output += &format!(" {}", &child[indent + INDENT_SIZE..]);
+		} else {
+			output.push('\n');
+			write_block(value, indent + INDENT_SIZE, output);
+		}
+	};
+	match json {
+		Json::Dict(entries) => for (key, value) in entries {
+			*output += &format!("{}{}:", " ".repeat(indent), yaml_string(&Json::key_string(key)));
+			write_child(value, output, false);
+		},
+		Json::Array(items) => for item in items {
+			*output += &format!("{}-", " ".repeat(indent));
+			write_child(item, output, true);
+		},
+		_ => {}
+	}
+}
+
+/// Get a scalar or empty collection written inline.
+fn yaml_scalar(json:&Json) -> String {
+	match json {
+		Json::Dict(_) => "{}".to_string(),
+		Json::Array(_) => "[]".to_string(),
+		Json::String(value) => yaml_string(value),
+		Json::Float(value) if value.is_nan() => ".nan".to_string(),
+		Json::Float(value) if value.is_infinite() => if *value > 0.0 { ".inf" } else { "-.inf" }.to_string(),
+		json => json.to_string()
+	}
+}
+
+/// Get a string as plain scalar if it would be read back as the same string, or as double quoted scalar otherwise.
+fn yaml_string(value:&str) -> String {
+	let is_plain:bool =
+		!value.is_empty() && value.trim() == value &&
+		!value.starts_with(|char:char| INDICATOR_CHARS.contains(char)) &&
+		!value.contains([',', '[', ']', '{', '}']) && !value.contains(": ") && !value.contains(" #") && !value.ends_with(':') &&
+		!value.chars().any(|char| char.is_control()) &&
+		!AMBIGUOUS_WORDS.contains(&value.to_lowercase().as_str()) &&
+		matches!(resolve_plain(value), Json::String(_));
+	if is_plain { value.to_string() } else { Json::escape_string(value) }
+}
+
+/// Get the value of a plain scalar by the YAML 1.2 core schema.
+fn resolve_plain(value:&str) -> Json {
+	match value {
+		"" | "~" | "null" | "Null" | "NULL" => return Json::Null,
+		"true" | "True" | "TRUE" => return Json::Bool(true),
+		"false" | "False" | "FALSE" => return Json::Bool(false),
+		".inf" | ".Inf" | ".INF" | "+.inf" | "+.Inf" | "+.INF" => return Json::Float(f64::INFINITY),
+		"-.inf" | "-.Inf" | "-.INF" => return Json::Float(f64::NEG_INFINITY),
+		".nan" | ".NaN" | ".NAN" => return Json::Float(f64::NAN),
+		_ => {}
+	}
+	let unsigned:&str = value.strip_prefix(['+', '-']).unwrap_or(value);
+	if !unsigned.is_empty() && unsigned.bytes().all(|byte| byte.is_ascii_digit()) {
+		return match value.parse::<i64>() {
+			Ok(value) => Json::Integer(value),
+			Err(_) => JsonNumber::new(value.trim_start_matches('+')).map(Json::Number).unwrap_or_else(|_| Json::String(value.to_string()))
+		};
+	}
+	for (prefix, radix) in [("0x", 16), ("0o", 8)] {
+		if let Some(value) = value.strip_prefix(prefix).and_then(|digits| i64::from_str_radix(digits, radix).ok()) {
+			return Json::Integer(value);
+		}
+	}
+	if value.bytes().any(|byte| byte.is_ascii_digit()) && value.chars().all(|char| char.is_ascii_digit() || ".eE+-".contains(char)) {
+		if let Ok(value) = value.parse::<f64>() {
+			return Json::Float(value);
+		}
+	}
+	Json::String(value.to_string())
+}
+
+/// Get a line without its comment. A comment starts with '#' at the start of the line or after white-space, outside of quotes.
+fn strip_comment(text:&str) -> &str {
+	let mut quote:Option<char> = None;
+	let mut previous:char = ' ';
+	let mut escaped:bool = false;
+	for (index, char) in text.char_indices() {
+		match quote {
+			Some('"') if escaped => escaped = false,
+			Some('"') if char == '\\' => escaped = true,
+			Some(quote_char) if char == quote_char => quote = None,
+			Some(_) => {},
+			None if char == '#' && previous.is_whitespace() => return &text[..index],
+			None if (char == '"' || char == '\'') && (previous.is_whitespace() || "[{,:".contains(previous)) => quote = Some(char),
+			None => {}
+		}
+		previous = char;
+	}
+	text
+}
+
+/// Whether or not all quotes and brackets of an inline value are closed.
+fn is_complete(text:&str) -> bool {
+	let mut quote:Option<char> = None;
+	let mut previous:char = ' ';
+	let mut escaped:bool = false;
+	let mut depth:usize = 0;
+	for char in text.chars() {
+		match quote {
+			Some('"') if escaped => escaped = false,
+			Some('"') if char == '\\' => escaped = true,
+			Some(quote_char) if char == quote_char => quote = None,
+			Some(_) => {},
+			None if (char == '"' || char == '\'') && (previous.is_whitespace() || "[{,:".contains(previous)) => quote = Some(char),
+			None if char == '[' || char == '{' => depth += 1,
+			None if char == ']' || char == '}' => depth = depth.saturating_sub(1),
+			None => {}
+		}
+		previous = char;
+	}
+	quote.is_none() && depth == 0
+}
+
+/// Whether or not a line is a block sequence item.
+fn is_sequence_item(text:&str) -> bool {
+	text == "-" || text.starts_with("- ") || text.starts_with("-\t")
+}
+
+/// Get the index of the colon that separates the key of a block mapping entry from its value.
+fn key_separator(text:&str) -> Option<usize> {
+	let is_separator = |index:usize| text[index..].starts_with(':') && text[index + 1..].chars().next().map(|char| char == ' ' || char == '\t').unwrap_or(true);
+	match text.chars().next()? {
+		quote @ ('"' | '\'') => {
+			let mut escaped:bool = false;
+			let end:usize = text.char_indices().skip(1).find(|(_, char)| {
+				let is_end:bool = *char == quote && !escaped;
+				escaped = quote == '"' && *char == '\\' && !escaped;
+				is_end
+			})?.0;
+			let separator:usize = end + 1 + text[end + 1..].len() - text[end + 1..].trim_start().len();
+			Some(separator).filter(|separator| is_separator(*separator))
+		},
+		'[' | '{' | '#' | '&' | '*' | '!' | '|' | '>' => None,
+		_ if is_sequence_item(text) => None,
+		_ => text.char_indices().map(|(index, _)| index).find(|index| is_separator(*index))
+	}
+}
+
+
+
+/// A line of YAML contents.
+struct YamlLine {
+	number:usize,
+	indent:usize,
+	text:String
+}
+impl YamlLine {
+
+	/// Get the contents of the line without indentation and comment.
+	fn contents(&self) -> &str {
+		strip_comment(&self.text).trim()
+	}
+
+	/// Whether or not the line has no contents.
+	fn is_blank(&self) -> bool {
+		self.contents().is_empty()
+	}
+}
+
+
+
+/// Reads YAML contents into json.
+struct YamlReader {
+	lines:Vec<YamlLine>,
+	index:usize
+}
+impl YamlReader {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new reader.
+	fn new(contents:&str) -> YamlReader {
+		let lines:Vec<YamlLine> = contents.lines().enumerate().map(|(index, line)| {
+			let text:&str = line.trim_start_matches(' ');
+			YamlLine { number: index + 1, indent: line.len() - text.len(), text: text.to_string() }
+		}).collect();
+		YamlReader { lines, index: 0 }
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Read the document.
+	fn read(mut self) -> Result<Json, Box<dyn Error>> {
+
+		// Limit the lines to the first document.
+		self.skip_blank();
+		if let Some(line) = self.lines.get(self.index).filter(|line| line.text.starts_with('%')) {
+			return Err(Self::error(line.number, "directives are not supported"));
+		}
+		if self.lines.get(self.index).map(|line| line.indent == 0 && line.contents() == "---").unwrap_or(false) {
+			self.index += 1;
+		}
+		if let Some(end) = self.lines.iter().skip(self.index).position(|line| line.indent == 0 && (line.contents() == "---" || line.contents() == "...")) {
+			let end:usize = self.index + end;
+			if let Some(line) = self.lines.iter().skip(end + 1).find(|line| !line.is_blank() && line.contents() != "---" && line.contents() != "...") {
+				return Err(Self::error(line.number, "multiple documents are not supported"));
+			}
+			self.lines.truncate(end);
+		}
+
+		// Read the root node.
+		let json:Json = self.read_node(None)?;
+		self.skip_blank();
+		match self.lines.get(self.index) {
+			Some(line) => Err(Self::error(line.number, "unexpected indentation")),
+			None => Ok(json)
+		}
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Create an error at a line.
+	fn error(line_number:usize, reason:&str) -> Box<dyn Error> {
+		format!("Could not parse yaml at line {line_number}, {reason}.").into()
+	}
+
+	/// Skip lines without contents.
+	fn skip_blank(&mut self) {
+		while self.lines.get(self.index).map(|line| line.is_blank()).unwrap_or(false) {
+			self.index += 1;
+		}
+	}
+
+	/// Get the next line with contents if it is indented deeper than the parent.
+	fn next_child(&mut self, parent_indent:Option<usize>) -> Result<Option<&YamlLine>, Box<dyn Error>> {
+		self.skip_blank();
+		match self.lines.get(self.index).filter(|line| parent_indent.map(|parent_indent| line.indent > parent_indent).unwrap_or(true)) {
+			Some(line) if line.text.starts_with('\t') => Err(Self::error(line.number, "tabs can not be used for indentation")),
+			line => Ok(line)
+		}
+	}
+
+	/// Read the node in the next lines indented deeper than the parent. Returns null if there is none.
+	fn read_node(&mut self, parent_indent:Option<usize>) -> Result<Json, Box<dyn Error>> {
+		let Some(line) = self.next_child(parent_indent)? else {
+			return Ok(Json::Null);
+		};
+		let (indent, number, contents) = (line.indent, line.number, line.contents().to_string());
+		if is_sequence_item(&contents) {
+			self.read_sequence(indent)
+		} else if key_separator(&contents).is_some() || contents == "?" || contents.starts_with("? ") {
+			self.read_mapping(indent)
+		} else {
+			self.index += 1;
+			self.read_inline(&contents, parent_indent, number)
+		}
+	}
+
+	/// Read a block sequence at an indent.
+	fn read_sequence(&mut self, indent:usize) -> Result<Json, Box<dyn Error>> {
+		let mut items:Vec<Json> = Vec::new();
+		while let Some(line) = self.next_child(indent.checked_sub(1))? {
+			if line.indent > indent {
+				return Err(Self::error(line.number, "unexpected indentation"));
+			}
+			let contents:String = line.contents().to_string();
+			if !is_sequence_item(&contents) {
+				break;
+			}
+			let number:usize = line.number;
+			let rest:&str = contents[1..].trim_start();
+			if rest.is_empty() {
+				self.index += 1;
+				items.push(self.read_node(Some(indent))?);
+			} else if is_sequence_item(rest) || key_separator(rest).is_some() {
+
+				// Continue reading the item as block collection, starting at the column of its contents.
+				let line:&mut YamlLine = &mut self.lines[self.index];
+				let offset:usize = line.text.len() - line.text[1..].trim_start().len();
+				line.indent += offset;
+				line.text = line.text[offset..].to_string();
+				items.push(self.read_node(Some(indent))?);
+			} else if rest.starts_with(['|', '>']) {
+				self.index += 1;
+				items.push(self.read_block_scalar(rest, indent, number)?);
+			} else {
+				self.index += 1;
+				items.push(self.read_inline(rest, Some(indent), number)?);
+			}
+		}
+		Ok(Json::Array(items))
+	}
+
+	/// Read a block mapping at an indent.
+	fn read_mapping(&mut self, indent:usize) -> Result<Json, Box<dyn Error>> {
+		let mut entries:Vec<(Json, Json)> = Vec::new();
+		while let Some(line) = self.next_child(indent.checked_sub(1))? {
+			if line.indent > indent {
+				return Err(Self::error(line.number, "unexpected indentation"));
+			}
+			let contents:String = line.contents().to_string();
+			let number:usize = line.number;
+			if contents == "?" || contents.starts_with("? ") {
+				return Err(Self::error(number, "complex keys are not supported"));
+			}
+			let Some(separator) = key_separator(&contents) else {
+				break;
+			};
+
+			// Read key.
+			let key:&str = contents[..separator].trim_end();
+			let key:String = match key.starts_with(['"', '\'']) {
+				true => FlowReader::new(key, number).read()?.as_str().unwrap().to_string(),
+				false => key.to_string()
+			};
+			if entries.iter().any(|(existing, _)| existing.as_str() == Some(&key)) {
+				return Err(Self::error(number, &format!("key '{key}' is defined twice")));
+			}
+
+			// Read value.
+			self.index += 1;
+			let rest:&str = contents[separator + 1..].trim();
+			let value:Json = if !rest.is_empty() {
+				match rest.starts_with(['|', '>']) {
+					true => self.read_block_scalar(rest, indent, number)?,
+					false => self.read_inline(rest, Some(indent), number)?
+				}
+			} else {
+				match self.next_child(indent.checked_sub(1))? {
+					Some(line) if line.indent == indent && is_sequence_item(line.contents()) => self.read_sequence(indent)?,
+					_ => self.read_node(Some(indent))?
+				}
+			};
+			entries.push((Json::String(key), value));
+		}
+		Ok(Json::Dict(entries))
+	}
+
+	/// Read an inline value, including its continuation lines when it is a multi-line plain scalar or an unterminated quoted scalar or flow collection.
+	fn read_inline(&mut self, text:&str, parent_indent:Option<usize>, number:usize) -> Result<Json, Box<dyn Error>> {
+		let mut text:String = text.to_string();
+		loop {
+			let is_plain:bool = !text.starts_with(['"', '\'', '[', '{']);
+			if is_complete(&text) && !is_plain {
+				break;
+			}
+			let Some(line) = self.next_child(parent_indent)? else {
+				break;
+			};
+			let contents:&str = line.contents();
+			if is_plain && (is_sequence_item(contents) || key_separator(contents).is_some()) {
+				break;
+			}
+			text += " ";
+			text += if is_plain { contents } else { line.text.trim() };
+			self.index += 1;
+		}
+		if !is_complete(&text) {
+			return Err(Self::error(number, "value is not terminated"));
+		}
+		if text.starts_with(['&', '*', '!']) {
+			return Err(Self::error(number, "anchors, aliases and tags are not supported"));
+		}
+		if text.starts_with(['"', '\'', '[', '{']) {
+			return FlowReader::new(&text, number).read();
+		}
+		if key_separator(&text).is_some() {
+			return Err(Self::error(number, "mapping values are not allowed here"));
+		}
+		Ok(resolve_plain(&text))
+	}
+
+	/// Read a literal ('|') or folded ('>') block scalar from the lines indented deeper than the parent.
+	fn read_block_scalar(&mut self, header:&str, parent_indent:usize, number:usize) -> Result<Json, Box<dyn Error>> {
+		let is_folded:bool = header.starts_with('>');
+		let mut chomping:char = ' ';
+		let mut explicit_indent:Option<usize> = None;
+		for char in header[1..].chars() {
+			match char {
+				'-' | '+' if chomping == ' ' => chomping = char,
+				'1'..='9' if explicit_indent.is_none() => explicit_indent = char.to_digit(10).map(|digit| parent_indent + digit as usize),
+				_ => return Err(Self::error(number, &format!("invalid block scalar header '{header}'")))
+			}
+		}
+
+		// Collect the lines, keeping white-space beyond the indent of the contents.
+		let mut lines:Vec<String> = Vec::new();
+		let mut content_indent:Option<usize> = explicit_indent;
+		while let Some(line) = self.lines.get(self.index) {
+			if line.text.trim().is_empty() {
+				lines.push(String::new());
+			} else {
+				let indent:usize = *content_indent.get_or_insert(line.indent);
+				if line.indent <= parent_indent || line.indent < indent {
+					break;
+				}
+				lines.push(" ".repeat(line.indent - indent) + &line.text);
+			}
+			self.index += 1;
+		}
+		let trailing_blank_count:usize = lines.iter().rev().take_while(|line| line.is_empty()).count();
+		lines.truncate(lines.len() - trailing_blank_count);
+
+		// Join the lines.
+		let mut output:String = String::new();
+		for (index, line) in lines.iter().enumerate() {
+			if index > 0 {
+				let previous:&str = &lines[index - 1];
+				if !is_folded || line.is_empty() || line.starts_with([' ', '\t']) || previous.starts_with([' ', '\t']) {
+					output.push('\n');
+				} else if !previous.is_empty() {
+					output.push(' ');
+				}
+			}
+			output += line;
+		}
+		if !lines.is_empty() {
+			match chomping {
+				'-' => {},
+				'+' => output += &"\n".repeat(trailing_blank_count + 1),
+				_ => output.push('\n')
+			}
+		}
+		Ok(Json::String(output))
+	}
+}
+
+
+
+/// Reads an inline quoted scalar or flow collection into json.
+struct FlowReader {
+	chars:Vec<char>,
+	index:usize,
+	line_number:usize
+}
+impl FlowReader {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new reader.
+	fn new(text:&str, line_number:usize) -> FlowReader {
+		FlowReader { chars: text.chars().collect(), index: 0, line_number }
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Read the value, which should be the only contents.
+	fn read(mut self) -> Result<Json, Box<dyn Error>> {
+		let json:Json = self.read_value()?;
+		self.skip_whitespace();
+		match self.peek() {
+			None => Ok(json),
+			Some(char) => Err(self.error(&format!("unexpected '{char}' after value")))
+		}
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Create an error at the line of the value.
+	fn error(&self, reason:&str) -> Box<dyn Error> {
+		YamlReader::error(self.line_number, reason)
+	}
+
+	/// Get the character at the cursor.
+	fn peek(&self) -> Option<char> {
+		self.chars.get(self.index).copied()
+	}
+
+	/// Skip white-space.
+	fn skip_whitespace(&mut self) {
+		while self.peek().map(|char| char.is_whitespace()).unwrap_or(false) {
+			self.index += 1;
+		}
+	}
+
+	/// Consume the character at the cursor if it is one of the expected characters.
+	fn expect(&mut self, expected:&[char]) -> Result<char, Box<dyn Error>> {
+		self.skip_whitespace();
+		match self.peek() {
+			Some(char) if expected.contains(&char) => { self.index += 1; Ok(char) },
+			Some(char) => Err(self.error(&format!("expected {}, found '{char}'", expected.iter().map(|char| format!("'{char}'")).collect::<Vec<String>>().join(" or ")))),
+			None => Err(self.error("value is not terminated"))
+		}
+	}
+
+	/// Read a value.
+	fn read_value(&mut self) -> Result<Json, Box<dyn Error>> {
+		self.skip_whitespace();
+		match self.peek() {
+			Some('[') => {
+				self.index += 1;
+				let mut items:Vec<Json> = Vec::new();
+				loop {
+					self.skip_whitespace();
+					if self.peek() == Some(']') {
+						self.index += 1;
+						return Ok(Json::Array(items));
+					}
+					items.push(self.read_value()?);
+					if self.expect(&[',', ']'])? == ']' {
+						return Ok(Json::Array(items));
+					}
+				}
+			},
+			Some('{') => {
+				self.index += 1;
+				let mut entries:Vec<(Json, Json)> = Vec::new();
+				loop {
+					self.skip_whitespace();
+					if self.peek() == Some('}') {
+						self.index += 1;
+						return Ok(Json::Dict(entries));
+					}
+					let key:String = match self.read_value()? {
+						Json::String(key) => key,
+						Json::Null => String::new(),
+						key => key.to_string()
+					};
+					self.skip_whitespace();
+					let value:Json = match self.peek() == Some(':') {
+						true => { self.index += 1; self.read_value()? },
+						false => Json::Null
+					};
+					entries.push((Json::String(key), value));
+					if self.expect(&[',', '}'])? == '}' {
+						return Ok(Json::Dict(entries));
+					}
+				}
+			},
+			Some('"') => {
+				self.index += 1;
+				let start:usize = self.index;
+				while self.peek() != Some('"') {
+					match self.peek() {
+						Some('\\') => self.index += 2,
+						Some(_) => self.index += 1,
+						None => return Err(self.error("string is not terminated"))
+					}
+				}
+				self.index += 1;
+
+				// YAML double-quoted strings share the JSON5 escapes like '\x', '\v' and '\0'.
+				Ok(Json::String(Json::decode_string(&self.chars[start..self.index - 1].iter().collect::<String>(), JsonFlavor::Json5)))
+			},
+			Some('\'') => {
+				self.index += 1;
+				let mut output:String = String::new();
+				loop {
+					match self.peek() {
+						Some('\'') if self.chars.get(self.index + 1) == Some(&'\'') => { output.push('\''); self.index += 2; },
+						Some('\'') => { self.index += 1; return Ok(Json::String(output)); },
+						Some(char) => { output.push(char); self.index += 1; },
+						None => return Err(self.error("string is not terminated"))
+					}
+				}
+			},
+			Some('&' | '*' | '!') => Err(self.error("anchors, aliases and tags are not supported")),
+			_ => {
+
+				// Plain scalars end at flow indicators, or at a colon that separates a key from its value.
+				let start:usize = self.index;
+				while let Some(char) = self.peek() {
+					let next:Option<char> = self.chars.get(self.index + 1).copied();
+					if matches!(char, ',' | '[' | ']' | '{' | '}') || (char == ':' && next.map(|next| next.is_whitespace() || ",[]{}".contains(next)).unwrap_or(true)) {
+						break;
+					}
+					self.index += 1;
+				}
+				Ok(resolve_plain(self.chars[start..self.index].iter().collect::<String>().trim()))
+			}
+		}
+	}
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ json, Json };
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_from_yaml() {
+		let contents:&str = "
+# Service settings.
+---
+name: api # trailing comment
+version: 2
+ratio: .5
+enabled: true
+missing: ~
+zip: \"01234\"
+quote: 'it''s'
+url: http://example.com/#anchor
+tags: [web, 'internal', { tier: 1 }]
+servers:
+  - host: a.local
+    port: 8080
+  - host: b.local
+    ports:
+    - 80
+    - - 443
+      - 8443
+plain: this text
+  continues here
+empty_list: []
+description: |
+  line one
+    indented
+
+  line three
+folded: >-
+  one
+  two
+
+  three
+nested:
+  deeper:
+    key: value
+";
+		assert_eq!(Json::from_yaml(contents).unwrap(), json!({
+			"name": "api",
+			"version": 2,
+			"ratio": 0.5,
+			"enabled": true,
+			"missing": null,
+			"zip": "01234",
+			"quote": "it's",
+			"url": "http://example.com/#anchor",
+			"tags": ["web", "internal", { "tier": 1 }],
+			"servers": [{ "host": "a.local", "port": 8080 }, { "host": "b.local", "ports": [80, [443, 8443]] }],
+			"plain": "this text continues here",
+			"empty_list": [],
+			"description": "line one\n  indented\n\nline three\n",
+			"folded": "one two\nthree",
+			"nested": { "deeper": { "key": "value" } }
+		}));
+		assert_eq!(Json::from_yaml("- a\n- [1, 2]\n-\n  b: c\n").unwrap(), json!(["a", [1, 2], { "b": "c" }]));
+		assert_eq!(Json::from_yaml("plain").unwrap(), json!("plain"));
+		assert_eq!(Json::from_yaml("").unwrap(), Json::Null);
+		assert_eq!(Json::from_yaml("a: 1\n...\n").unwrap(), json!({ "a": 1 }));
+	}
+
+	#[test]
+	fn test_from_yaml_errors() {
+		let error = |contents:&str| Json::from_yaml(contents).unwrap_err().to_string();
+		assert_eq!(error("a: 1\n  b: 2"), "Could not parse yaml at line 2, unexpected indentation.");
+		assert_eq!(error("a: 1\na: 2"), "Could not parse yaml at line 2, key 'a' is defined twice.");
+		assert_eq!(error("a: &anchor 1"), "Could not parse yaml at line 1, anchors, aliases and tags are not supported.");
+		assert_eq!(error("a: [1, 2"), "Could not parse yaml at line 1, value is not terminated.");
+		assert_eq!(error("a: b: c"), "Could not parse yaml at line 1, mapping values are not allowed here.");
+		assert_eq!(error("? a\n: b"), "Could not parse yaml at line 1, complex keys are not supported.");
+		assert_eq!(error("a: 1\n---\nb: 2"), "Could not parse yaml at line 3, multiple documents are not supported.");
+		assert_eq!(error("a:\n\t- b"), "Could not parse yaml at line 2, tabs can not be used for indentation.");
+	}
+
+	#[test]
+	fn test_to_yaml() {
+		let json:Json = json!({
+			"name": "api",
+			"version": "2",
+			"flags": ["yes", "", " padded", "a: b", "line\nbreak"],
+			"servers": [{ "host": "a.local", "ports": [80, 443] }, [1, [2]]],
+			"empty": {},
+			"ratio": 0.5,
+			"nothing": null
+		});
+		let contents:String = json.to_yaml();
+		assert_eq!(contents, concat!(
+			"name: api\nversion: \"2\"\n",
+			"flags:\n  - \"yes\"\n  - \"\"\n  - \" padded\"\n  - \"a: b\"\n  - \"line\\nbreak\"\n",
+			"servers:\n  - host: a.local\n    ports:\n      - 80\n      - 443\n  - - 1\n    - - 2\n",
+			"empty: {}\nratio: 0.5\nnothing: null\n"
+		));
+		assert_eq!(Json::from_yaml(&contents).unwrap(), json);
+		assert_eq!(json!(1).to_yaml(), "1\n");
+		assert_eq!(json!([]).to_yaml(), "[]\n");
+	}
+}
@@ -0,0 +1,60 @@
+// auto-export
+mod json;
+mod json_access;
+mod json_access_u;
+mod json_convert;
+mod json_convert_u;
+mod json_diff;
+mod json_diff_u;
+mod json_document;
+mod json_document_u;
+mod json_error;
+mod json_error_u;
+mod json_file;
+mod json_file_u;
+mod json_ini;
+mod json_ini_u;
+mod json_lines;
+mod json_lines_u;
+mod json_macro;
+mod json_macro_u;
+mod json_number;
+mod json_number_u;
+mod json_object;
+mod json_object_u;
+mod json_patch;
+mod json_patch_u;
+mod json_path;
+mod json_path_u;
+mod json_pointer;
+mod json_pointer_u;
+mod json_schema;
+mod json_schema_u;
+mod json_serde;
+mod json_serde_u;
+mod json_stream;
+mod json_stream_u;
+mod json_toml;
+mod json_toml_u;
+mod json_typed;
+mod json_typed_u;
+mod json_u;
+mod json_writer;
+mod json_writer_u;
+mod json_yaml;
+mod json_yaml_u;
+pub use json::*;
+pub use json_access::*;
+pub use json_diff::*;
+pub use json_document::*;
+pub use json_error::*;
+pub use json_file::*;
+pub use json_lines::*;
+pub use json_number::*;
+pub use json_object::*;
+pub use json_path::*;
+pub use json_schema::*;
+pub use json_serde::*;
+pub use json_stream::*;
+pub use json_typed::*;
+pub use json_writer::*;
\ No newline at end of file
@@ -1,4 +1,3 @@
 // auto-export
 mod json;
-mod json_u;
 pub use json::*;
\ No newline at end of file
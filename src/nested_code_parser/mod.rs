@@ -1,11 +1,22 @@
 // auto-export
 mod nested_code;
+mod nested_code_export;
+mod nested_code_export_u;
+mod nested_code_formatter;
+mod nested_code_formatter_u;
+mod nested_code_iterators;
+mod nested_code_iterators_u;
 mod nested_code_parser;
 mod nested_code_parser_u;
+mod nested_code_snapshot;
+mod nested_code_snapshot_u;
 mod nested_code_u;
 mod segment_identification;
 mod languages;
 pub use nested_code::*;
+pub use nested_code_formatter::*;
+pub use nested_code_iterators::*;
 pub use nested_code_parser::*;
+pub use nested_code_snapshot::*;
 pub use segment_identification::*;
 pub use languages::*;
\ No newline at end of file
@@ -1,3 +1,4 @@
+use super::{ NestedSegmentAncestorIterator, NestedSegmentBreadthFirstIterator, NestedSegmentCodeIterator, NestedSegmentIterator, NestedSegmentPostOrderIterator, NestedSegmentPreOrderIterator };
 use std::{ fmt::{ self, Debug }, ops::{ Index, IndexMut } };
 
 
@@ -71,7 +72,7 @@ impl NestedSegment {
 	/* PROPERTY GETTER METHODS */
 
 	/// Get the ID of the segment.
-	fn id(&self) -> u64 {
+	pub fn id(&self) -> u64 {
 		match self {
 			NestedSegment::Code(id, _) => *id,
 			NestedSegment::Contents(id, _) => *id,
@@ -112,6 +113,15 @@ impl NestedSegment {
 		}
 	}
 
+	/// Get the length in bytes of the source text of the segment, without building the string.
+	pub fn len(&self) -> usize {
+		match self {
+			NestedSegment::Code(_, code) => code.open_tag.len() + code.sub_segments.iter().map(|sub_segment| sub_segment.len()).sum::<usize>() + code.close_tag.len(),
+			NestedSegment::Contents(_, contents) => contents.len(),
+			NestedSegment::WhiteSpace(_, contents) => contents.len()
+		}
+	}
+
 	/// Get the segments' sub-segments.
 	pub fn sub_segments(&self) -> &[NestedSegment] {
 		match self {
@@ -325,9 +335,33 @@ impl NestedSegment {
 		self.sub_segments().iter().map(|sub_segment| sub_segment.to_string()).collect::<Vec<String>>().join("")
 	}
 
+
+
+	/* ITERATOR METHODS */
+
 	/// Build a segments iterator.
 	pub fn iter(&self) -> NestedSegmentIterator {
-		NestedSegmentIterator(NestedSegmentRef::new(self, vec![]))
+		NestedSegmentIterator::new(self)
+	}
+
+	/// Build an iterator that visits each segment before its sub-segments.
+	pub fn iter_pre_order(&self) -> NestedSegmentPreOrderIterator<'_> {
+		NestedSegmentPreOrderIterator::new(self)
+	}
+
+	/// Build an iterator that visits each segment after its sub-segments.
+	pub fn iter_post_order(&self) -> NestedSegmentPostOrderIterator<'_> {
+		NestedSegmentPostOrderIterator::new(self)
+	}
+
+	/// Build an iterator that visits all segments at a depth before moving on to the next depth.
+	pub fn iter_breadth_first(&self) -> NestedSegmentBreadthFirstIterator<'_> {
+		NestedSegmentBreadthFirstIterator::new(self)
+	}
+
+	/// Build a pre-order iterator that only visits code segments.
+	pub fn iter_code(&self) -> NestedSegmentCodeIterator<'_> {
+		NestedSegmentCodeIterator::new(self)
 	}
 }
 impl ToString for NestedSegment {
@@ -404,8 +438,20 @@ impl<'a> NestedSegmentRef<'a> {
 
 
 
+	/// Get the path of IDs from the source to the targeted element.
+	pub fn path(&self) -> &[u64] {
+		&self.target_path
+	}
+
+
+
 	/* WALKING METHODS */
 
+	/// Get an iterator over the ancestors of self, starting at the direct parent and ending at the source.
+	pub fn ancestors(&self) -> NestedSegmentAncestorIterator<'a> {
+		NestedSegmentAncestorIterator::new(self.source, &self.target_path)
+	}
+
 	/// Get a reference to the first child of self.
 	pub fn child(&self) -> Option<NestedSegmentRef<'a>> {
 		self.child_by_index(0)
@@ -517,44 +563,4 @@ impl<'a> NestedSegmentRef<'a> {
 		}
 		None
 	}
-}
-
-
-
-pub struct NestedSegmentIterator<'a>(NestedSegmentRef<'a>);
-impl<'a> Iterator for NestedSegmentIterator<'a> {
-	type Item = &'a NestedSegment;
-
-	fn next(&mut self) -> Option<Self::Item> {
-		let target_segment:Option<&NestedSegment> = self.0.get();
-
-		// Try to move the cursor to the next segment.
-		if target_segment.is_some() {
-			let mut found_next:bool = false;
-			if let Some(child_ref) = self.0.child() {
-				self.0 = child_ref;
-				found_next = true;
-			} else if let Some(sibling_ref) = self.0.next_sibling() {
-				self.0 = sibling_ref;
-				found_next = true;
-			} else {
-				while let Some(parent_ref) = self.0.parent() {
-					if let Some(parent_sibling_ref) = parent_ref.next_sibling() {
-						self.0 = parent_sibling_ref;
-						found_next = true;
-					} else {
-						self.0 = parent_ref;
-					}
-				}
-			}
-
-			// If no next cursor found, set cursor to impossible ID.
-			if !found_next {
-				self.0.target_path = vec![NestedSegment::new_id()];
-			}
-		}
-
-		// Nothing found.
-		target_segment
-	}
 }
\ No newline at end of file
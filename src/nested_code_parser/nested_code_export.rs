@@ -0,0 +1,136 @@
+use super::{ Json, NestedSegment };
+use std::{ error::Error, ops::Range };
+
+
+
+const TYPE_KEY:&str = "type";
+const OPEN_TAG_KEY:&str = "open_tag";
+const CLOSE_TAG_KEY:&str = "close_tag";
+const TEXT_KEY:&str = "text";
+const SPAN_KEY:&str = "span";
+const CHILDREN_KEY:&str = "children";
+
+
+
+impl NestedSegment {
+
+	/* EXPORT METHODS */
+
+	/// Export the tree as JSON. Code segments contain their type name, tags, byte span and children. Contents and white-space contain their type name, text and byte span.
+	pub fn to_json_tree(&self) -> String {
+		self.walk_with_spans(0, &mut |segment, span, children| {
+			let span:String = format!("[{}, {}]", span.start, span.end);
+			match segment {
+				NestedSegment::Code(_, code) => format!(
+					"{{\"{TYPE_KEY}\": {}, \"{OPEN_TAG_KEY}\": {}, \"{CLOSE_TAG_KEY}\": {}, \"{SPAN_KEY}\": {span}, \"{CHILDREN_KEY}\": [{}]}}",
+					Json::escape_string(&code.type_name), Json::escape_string(&code.open_tag), Json::escape_string(&code.close_tag), children.join(", ")
+				),
+				_ => format!("{{\"{TYPE_KEY}\": {}, \"{TEXT_KEY}\": {}, \"{SPAN_KEY}\": {span}}}", Json::escape_string(segment.type_name()), Json::escape_string(&segment.to_string()))
+			}
+		}).1
+	}
+
+	/// Export the tree as an S-expression. Code segments are listed with their children, contents and white-space with their quoted text.
+	pub fn to_s_expression(&self) -> String {
+		self.walk_with_spans(0, &mut |segment, _, children| {
+			match segment {
+				NestedSegment::Code(_, code) if children.is_empty() => format!("({})", code.type_name),
+				NestedSegment::Code(_, code) => format!("({} {})", code.type_name, children.join(" ")),
+				_ => format!("({} {})", segment.type_name(), Json::escape_string(&segment.to_string()))
+			}
+		}).1
+	}
+
+	/// Export the tree as a Graphviz DOT graph. Every segment becomes a node labeled with its type name and byte span, contents and white-space nodes also show their text.
+	pub fn to_dot_graph(&self) -> String {
+		let mut nodes:Vec<String> = Vec::new();
+		let mut edges:Vec<String> = Vec::new();
+		self._to_dot_graph(0, &mut nodes, &mut edges);
+		format!("digraph NestedSegment {{\n{}\n{}\n}}", nodes.join("\n"), edges.join("\n"))
+	}
+	fn _to_dot_graph(&self, offset:usize, nodes:&mut Vec<String>, edges:&mut Vec<String>) -> usize {
+		let node_name:String = format!("node{}", self.id());
+		let mut cursor:usize = offset;
+		match self {
+			NestedSegment::Code(_, code) => {
+				cursor += code.open_tag.len();
+				for child in &code.sub_segments {
+					edges.push(format!("\t{node_name} -> node{};", child.id()));
+					cursor = child._to_dot_graph(cursor, nodes, edges);
+				}
+				cursor += code.close_tag.len();
+				nodes.push(format!("\t{node_name} [label={}];", Json::escape_string(&format!("{} [{}..{}]", code.type_name, offset, cursor))));
+			},
+			_ => {
+				let text:String = self.to_string();
+				cursor += text.len();
+				nodes.push(format!("\t{node_name} [label={}, shape=box];", Json::escape_string(&format!("{} {:?} [{}..{}]", self.type_name(), text, offset, cursor))));
+			}
+		}
+		cursor
+	}
+
+	/// Walk the tree bottom-up, building a string for each segment from the segment, its byte span and the strings built for its children. Returns the end of the span and the built string.
+	fn walk_with_spans<T>(&self, offset:usize, builder:&mut T) -> (usize, String) where T:FnMut(&NestedSegment, Range<usize>, Vec<String>) -> String {
+		let mut cursor:usize = offset;
+		let mut children:Vec<String> = Vec::new();
+		match self {
+			NestedSegment::Code(_, code) => {
+				cursor += code.open_tag.len();
+				for child in &code.sub_segments {
+					let (child_end, child_output) = child.walk_with_spans(cursor, builder);
+					cursor = child_end;
+					children.push(child_output);
+				}
+				cursor += code.close_tag.len();
+			},
+			NestedSegment::Contents(_, text) | NestedSegment::WhiteSpace(_, text) => {
+				cursor += text.len();
+			}
+		}
+		(cursor, builder(self, offset..cursor, children))
+	}
+
+
+
+	/* IMPORT METHODS */
+
+	/// Rebuild a tree from the JSON created by `to_json_tree`. Spans are not required, as they follow from the text.
+	pub fn from_json_tree(contents:&str) -> Result<NestedSegment, Box<dyn Error>> {
+		Self::json_node_to_segment(&Json::new(contents)?)
+	}
+
+	/// Turn a single exported JSON node into a segment.
+	fn json_node_to_segment(node:&Json) -> Result<NestedSegment, Box<dyn Error>> {
+		let entries:&Vec<(Json, Json)> = match node {
+			Json::Dict(entries) => entries,
+			_ => return Err(format!("Could not import segment, expected a dictionary but got '{node}'.").into())
+		};
+		let get_entry = |key:&str| -> Option<&Json> {
+			entries.iter().find(|(entry_key, _)| matches!(entry_key, Json::String(name) if name == key)).map(|(_, value)| value)
+		};
+		let get_string = |key:&str| -> Result<Option<String>, Box<dyn Error>> {
+			match get_entry(key) {
+				Some(Json::String(value)) => Ok(Some(value.clone())),
+				Some(value) => Err(format!("Could not import segment, expected '{key}' to be a string but got '{value}'.").into()),
+				None => Ok(None)
+			}
+		};
+
+		// Contents and white-space.
+		if let Some(text) = get_string(TEXT_KEY)? {
+			return Ok(NestedSegment::new_contents(&text));
+		}
+
+		// Code.
+		let type_name:String = get_string(TYPE_KEY)?.ok_or(format!("Could not import segment, missing '{TYPE_KEY}'."))?;
+		let open_tag:String = get_string(OPEN_TAG_KEY)?.unwrap_or_default();
+		let close_tag:String = get_string(CLOSE_TAG_KEY)?.unwrap_or_default();
+		let children:Vec<NestedSegment> = match get_entry(CHILDREN_KEY) {
+			Some(Json::Array(children)) => children.iter().map(Self::json_node_to_segment).collect::<Result<Vec<NestedSegment>, Box<dyn Error>>>()?,
+			Some(value) => return Err(format!("Could not import segment, expected '{CHILDREN_KEY}' to be an array but got '{value}'.").into()),
+			None => Vec::new()
+		};
+		Ok(NestedSegment::new_code(&type_name, &open_tag, children, &close_tag))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ json_parser, NestedCodeParser, NestedSegment };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn example_parser() -> NestedCodeParser {
+		NestedCodeParser::new(vec![
+			&("scope", true, "{", "}"),
+			&("string", false, "\"", None, "\"", Some("\\"))
+		])
+	}
+
+	/// Compare two trees on structure and text, ignoring segment IDs.
+	fn assert_same_tree(left:&NestedSegment, right:&NestedSegment) {
+		assert_eq!(left.type_name(), right.type_name());
+		assert_eq!(left.to_string(), right.to_string());
+		assert_eq!(left.sub_segments().len(), right.sub_segments().len());
+		for (left_child, right_child) in left.sub_segments().iter().zip(right.sub_segments()) {
+			assert_same_tree(left_child, right_child);
+		}
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_json_tree() {
+		let tree:NestedSegment = example_parser().parse("a {\"b\"}");
+		assert_eq!(
+			tree.to_json_tree(),
+			r#"{"type": "ROOT", "open_tag": "", "close_tag": "", "span": [0, 7], "children": [{"type": "contents", "text": "a ", "span": [0, 2]}, {"type": "scope", "open_tag": "{", "close_tag": "}", "span": [2, 7], "children": [{"type": "string", "open_tag": "\"", "close_tag": "\"", "span": [3, 6], "children": [{"type": "contents", "text": "b", "span": [4, 5]}]}]}]}"#
+		);
+	}
+
+	#[test]
+	fn test_json_tree_escapes() {
+		let tree:NestedSegment = NestedSegment::new_code("quote\"d", "\\", vec![NestedSegment::new_contents("\n\t\u{1}")], "");
+		assert_eq!(tree.to_json_tree(), r#"{"type": "quote\"d", "open_tag": "\\", "close_tag": "", "span": [0, 4], "children": [{"type": "contents", "text": "\n\t\u0001", "span": [1, 4]}]}"#);
+	}
+
+	#[test]
+	fn test_json_tree_import() {
+		let source:&str = "x { \"quoted \\\" }\" {\n\té{}} \"unterminated";
+		let tree:NestedSegment = example_parser().parse(source);
+		let imported:NestedSegment = NestedSegment::from_json_tree(&tree.to_json_tree()).unwrap();
+		assert_same_tree(&tree, &imported);
+		assert_eq!(imported.to_string(), source);
+		assert_eq!(imported.to_json_tree(), tree.to_json_tree());
+	}
+
+	#[test]
+	fn test_json_tree_import_json_grammar() {
+		let source:&str = r#"{ "a": [1, 2.5, true], "b": { "c": "d" } }"#;
+		let tree:NestedSegment = json_parser().parse(source);
+		let imported:NestedSegment = NestedSegment::from_json_tree(&tree.to_json_tree()).unwrap();
+		assert_same_tree(&tree, &imported);
+	}
+
+	#[test]
+	fn test_json_tree_import_invalid() {
+		assert!(NestedSegment::from_json_tree("[1, 2]").is_err());
+		assert!(NestedSegment::from_json_tree(r#"{"open_tag": "{"}"#).is_err());
+		assert!(NestedSegment::from_json_tree(r#"{"type": "scope", "children": "none"}"#).is_err());
+		assert!(NestedSegment::from_json_tree(r#"{"type": 5, "children": []}"#).is_err());
+	}
+
+	#[test]
+	fn test_s_expression() {
+		let tree:NestedSegment = example_parser().parse("a {\"b\"} {}");
+		assert_eq!(tree.to_s_expression(), r#"(ROOT (contents "a ") (scope (string (contents "b"))) (whitespace " ") (scope))"#);
+	}
+
+	#[test]
+	fn test_dot_graph() {
+		let tree:NestedSegment = example_parser().parse("a {}");
+		let root_id:u64 = tree.id();
+		let contents_id:u64 = tree[0].id();
+		let scope_id:u64 = tree[1].id();
+		assert_eq!(
+			tree.to_dot_graph(),
+			format!(
+				"digraph NestedSegment {{\n\tnode{contents_id} [label=\"contents \\\"a \\\" [0..2]\", shape=box];\n\tnode{scope_id} [label=\"scope [2..4]\"];\n\tnode{root_id} [label=\"ROOT [0..4]\"];\n\tnode{root_id} -> node{contents_id};\n\tnode{root_id} -> node{scope_id};\n}}"
+			)
+		);
+	}
+}
\ No newline at end of file
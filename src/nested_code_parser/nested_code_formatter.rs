@@ -0,0 +1,226 @@
+use super::{ NestedCodeParser, NestedSegment };
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum FormatSpacing { Keep, Nothing, Space, Newline }
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct FormatRule {
+	pub before:FormatSpacing,
+	pub after:FormatSpacing,
+	pub indent:bool,
+	pub verbatim:bool
+}
+impl FormatRule {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new rule that keeps the layout of the segment as it is, only normalizing white-space.
+	pub fn new() -> FormatRule {
+		FormatRule {
+			before: FormatSpacing::Keep,
+			after: FormatSpacing::Keep,
+			indent: false,
+			verbatim: false
+		}
+	}
+
+	/// Return a version of self with the given spacing before the segment.
+	pub fn before(mut self, spacing:FormatSpacing) -> Self {
+		self.before = spacing;
+		self
+	}
+
+	/// Return a version of self with the given spacing after the segment.
+	pub fn after(mut self, spacing:FormatSpacing) -> Self {
+		self.after = spacing;
+		self
+	}
+
+	/// Return a version of self that puts the sub-segments on their own indented lines.
+	pub fn indent(mut self) -> Self {
+		self.indent = true;
+		self
+	}
+
+	/// Return a version of self that emits the segment exactly as it was parsed.
+	pub fn verbatim(mut self) -> Self {
+		self.verbatim = true;
+		self
+	}
+}
+impl Default for FormatRule {
+	fn default() -> Self {
+		FormatRule::new()
+	}
+}
+
+
+
+pub struct NestedCodeFormatter {
+	rules:Vec<(String, FormatRule)>,
+	indent:String,
+	max_blank_lines:usize
+}
+impl NestedCodeFormatter {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new formatter for trees created by the given parser. Segments the parser does not sub-parse, like strings and comments, are emitted verbatim.
+	pub fn new(parser:&NestedCodeParser) -> NestedCodeFormatter {
+		NestedCodeFormatter {
+			rules: parser.identification.iter().filter(|identification| !identification.allow_sub_parse).map(|identification| (identification.name.clone(), FormatRule::new().verbatim())).collect(),
+			indent: "\t".to_string(),
+			max_blank_lines: 1
+		}
+	}
+
+	/// Return a version of self that uses the given layout rule for all segments of the given type. Replaces the existing rule for the type.
+	pub fn rule(mut self, type_name:&str, rule:FormatRule) -> Self {
+		self.rules.retain(|(name, _)| name != type_name);
+		self.rules.push((type_name.to_string(), rule));
+		self
+	}
+
+	/// Return a version of self that indents using the given string.
+	pub fn indent_with(mut self, indent:&str) -> Self {
+		self.indent = indent.to_string();
+		self
+	}
+
+	/// Return a version of self that keeps at most the given amount of consecutive blank lines from the source.
+	pub fn max_blank_lines(mut self, max_blank_lines:usize) -> Self {
+		self.max_blank_lines = max_blank_lines;
+		self
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Re-emit the tree with normalized white-space according to the layout rules.
+	pub fn format(&self, segment:&NestedSegment) -> String {
+		let mut pieces:Vec<FormatPiece> = Vec::new();
+		self.collect_pieces(segment, &mut pieces);
+		self.render(&pieces)
+	}
+
+	/// Get the rule for a type of segment.
+	fn rule_for(&self, type_name:&str) -> Option<&FormatRule> {
+		self.rules.iter().find(|(name, _)| name == type_name).map(|(_, rule)| rule)
+	}
+
+	/// Turn a segment into a flat list of pieces to render.
+	fn collect_pieces<'a>(&self, segment:&'a NestedSegment, pieces:&mut Vec<FormatPiece<'a>>) {
+		let default_rule:FormatRule = FormatRule::new();
+		let rule:&FormatRule = self.rule_for(segment.type_name()).unwrap_or(&default_rule);
+		pieces.push(FormatPiece::Spacing(rule.before));
+		match segment {
+			_ if rule.verbatim => {
+				let mut text:String = segment.to_string();
+				let trailing_gap:String = text.split_off(text.trim_end().len());
+				pieces.push(FormatPiece::Verbatim(text));
+				pieces.push(FormatPiece::Gap(trailing_gap));
+			},
+			NestedSegment::Code(_, code) => {
+				Self::collect_text_pieces(&code.open_tag, pieces);
+				if rule.indent {
+					if code.sub_segments.iter().all(|child| child.is_whitespace()) {
+						pieces.push(FormatPiece::Spacing(FormatSpacing::Nothing));
+					} else {
+						pieces.push(FormatPiece::Indent(1));
+						pieces.push(FormatPiece::Spacing(FormatSpacing::Newline));
+						for child in &code.sub_segments {
+							self.collect_pieces(child, pieces);
+						}
+						pieces.push(FormatPiece::Indent(-1));
+						pieces.push(FormatPiece::Spacing(FormatSpacing::Newline));
+					}
+				} else {
+					for child in &code.sub_segments {
+						self.collect_pieces(child, pieces);
+					}
+				}
+				Self::collect_text_pieces(&code.close_tag, pieces);
+			},
+			NestedSegment::Contents(_, contents) | NestedSegment::WhiteSpace(_, contents) => {
+				Self::collect_text_pieces(contents, pieces);
+			}
+		}
+		pieces.push(FormatPiece::Spacing(rule.after));
+	}
+
+	/// Split text into the white-space around it and its lines, which are trimmed.
+	fn collect_text_pieces<'a>(text:&'a str, pieces:&mut Vec<FormatPiece<'a>>) {
+		let core:&str = text.trim();
+		if core.is_empty() {
+			pieces.push(FormatPiece::Gap(text.to_string()));
+			return;
+		}
+		let core_start:usize = text.len() - text.trim_start().len();
+		pieces.push(FormatPiece::Gap(text[..core_start].to_string()));
+		for (index, line) in core.split('\n').enumerate() {
+			if index > 0 {
+				pieces.push(FormatPiece::Gap("\n".to_string()));
+			}
+			pieces.push(FormatPiece::Text(line.trim()));
+		}
+		pieces.push(FormatPiece::Gap(text[core_start + core.len()..].to_string()));
+	}
+
+	/// Render a list of pieces to a string.
+	fn render(&self, pieces:&[FormatPiece]) -> String {
+		let mut output:String = String::new();
+		let mut depth:usize = 0;
+		let mut pending_gap:String = String::new();
+		let mut pending_spacing:FormatSpacing = FormatSpacing::Keep;
+		for piece in pieces {
+			let text:&str = match piece {
+				FormatPiece::Spacing(spacing) => { pending_spacing = pending_spacing.max(*spacing); continue; },
+				FormatPiece::Gap(gap) => { pending_gap.push_str(gap); continue; },
+				FormatPiece::Indent(change) => { depth = depth.saturating_add_signed(*change); continue; },
+				FormatPiece::Text(text) => text,
+				FormatPiece::Verbatim(text) => text
+			};
+			if text.is_empty() {
+				continue;
+			}
+
+			// Resolve spacing between the previous text and this one.
+			if !output.is_empty() {
+				let newline_count:usize = pending_gap.matches('\n').count();
+				let spacing:FormatSpacing = match pending_spacing {
+					FormatSpacing::Keep if newline_count > 0 => FormatSpacing::Newline,
+					FormatSpacing::Keep if !pending_gap.is_empty() => FormatSpacing::Space,
+					FormatSpacing::Keep => FormatSpacing::Nothing,
+					spacing => spacing
+				};
+				match spacing {
+					FormatSpacing::Newline => {
+						output.push_str(&"\n".repeat(newline_count.clamp(1, self.max_blank_lines + 1)));
+						output.push_str(&self.indent.repeat(depth));
+					},
+					FormatSpacing::Space => output.push(' '),
+					_ => {}
+				}
+			}
+			output.push_str(text);
+			pending_gap.clear();
+			pending_spacing = FormatSpacing::Keep;
+		}
+		output
+	}
+}
+
+
+
+enum FormatPiece<'a> {
+	Spacing(FormatSpacing),
+	Gap(String),
+	Indent(isize),
+	Text(&'a str),
+	Verbatim(String)
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ FormatRule, FormatSpacing, NestedCodeFormatter, NestedCodeParser };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn example_parser() -> NestedCodeParser {
+		NestedCodeParser::new(vec![
+			&("comment", false, "//", "\n"),
+			&("scope", true, "{", "}"),
+			&("string", false, "\"", None, "\"", Some("\\")),
+			&("statement_end", false, ";", "")
+		])
+	}
+
+	fn example_formatter(parser:&NestedCodeParser) -> NestedCodeFormatter {
+		NestedCodeFormatter::new(parser)
+			.rule("scope", FormatRule::new().before(FormatSpacing::Space).indent())
+			.rule("statement_end", FormatRule::new().before(FormatSpacing::Nothing).after(FormatSpacing::Newline))
+	}
+
+	fn format(contents:&str) -> String {
+		let parser:NestedCodeParser = example_parser();
+		example_formatter(&parser).format(&parser.parse(contents))
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_indentation() {
+		assert_eq!(format("fn main(){let a = 1 ;let b = 2;}"), "fn main() {\n\tlet a = 1;\n\tlet b = 2;\n}");
+		assert_eq!(format("if a {if b {c;}}"), "if a {\n\tif b {\n\t\tc;\n\t}\n}");
+	}
+
+	#[test]
+	fn test_normalizes_white_space() {
+		assert_eq!(format("  a   {\n\n\n\n      b;\n  c;\n\t\t}  "), "a {\n\n\tb;\n\tc;\n}");
+		assert_eq!(format("x  y\n\n\n\nz"), "x  y\n\nz");
+	}
+
+	#[test]
+	fn test_empty_scope() {
+		assert_eq!(format("a {   }"), "a {}");
+		assert_eq!(format("a {\n}"), "a {}");
+	}
+
+	#[test]
+	fn test_verbatim_segments_untouched() {
+		assert_eq!(format("a{   \"  b  {c;}  \" ;}"), "a {\n\t\"  b  {c;}  \";\n}");
+		assert_eq!(format("a {  //   keep   {this}\nb;}"), "a {\n\t//   keep   {this}\n\tb;\n}");
+	}
+
+	#[test]
+	fn test_custom_indent_and_blank_lines() {
+		let parser:NestedCodeParser = example_parser();
+		let formatter:NestedCodeFormatter = example_formatter(&parser).indent_with("  ").max_blank_lines(0);
+		assert_eq!(formatter.format(&parser.parse("a {\n\n\nb;\n\n\nc;}")), "a {\n  b;\n  c;\n}");
+	}
+
+	#[test]
+	fn test_rule_replaces_existing_rule() {
+		let parser:NestedCodeParser = example_parser();
+		let formatter:NestedCodeFormatter = example_formatter(&parser).rule("string", FormatRule::new());
+		assert_eq!(formatter.format(&parser.parse("a \"  b  \";")), "a \" b \";");
+	}
+
+	#[test]
+	fn test_formatting_is_idempotent() {
+		for source in ["fn main(){let a = 1 ;let b = 2;}", "a {\n\n\n  b;\n c { d; } }", "if a {if b {c;}} // done\n"] {
+			let once:String = format(source);
+			assert_eq!(format(&once), once);
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,177 @@
+use super::{ NestedSegment, NestedSegmentCode };
+use std::collections::VecDeque;
+
+
+
+pub struct NestedSegmentIterator<'a> {
+	stack:Vec<&'a NestedSegment>
+}
+impl<'a> NestedSegmentIterator<'a> {
+
+	/// Create a new iterator starting at the given segment.
+	pub(super) fn new(source:&'a NestedSegment) -> NestedSegmentIterator<'a> {
+		NestedSegmentIterator {
+			stack: vec![source]
+		}
+	}
+}
+impl<'a> Iterator for NestedSegmentIterator<'a> {
+	type Item = &'a NestedSegment;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let segment:&NestedSegment = self.stack.pop()?;
+		self.stack.extend(segment.sub_segments().iter().rev());
+		Some(segment)
+	}
+}
+
+
+
+pub struct NestedSegmentPreOrderIterator<'a> {
+	stack:Vec<(usize, Vec<u64>, &'a NestedSegment)>
+}
+impl<'a> NestedSegmentPreOrderIterator<'a> {
+
+	/// Create a new iterator starting at the given segment.
+	pub(super) fn new(source:&'a NestedSegment) -> NestedSegmentPreOrderIterator<'a> {
+		NestedSegmentPreOrderIterator {
+			stack: vec![(0, Vec::new(), source)]
+		}
+	}
+}
+impl<'a> Iterator for NestedSegmentPreOrderIterator<'a> {
+	type Item = (usize, Vec<u64>, &'a NestedSegment);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (depth, path, segment) = self.stack.pop()?;
+		for child in segment.sub_segments().iter().rev() {
+			self.stack.push((depth + 1, [path.as_slice(), &[child.id()]].concat(), child));
+		}
+		Some((depth, path, segment))
+	}
+}
+
+
+
+pub struct NestedSegmentPostOrderIterator<'a> {
+	stack:Vec<(usize, Vec<u64>, &'a NestedSegment, bool)>
+}
+impl<'a> NestedSegmentPostOrderIterator<'a> {
+
+	/// Create a new iterator starting at the given segment.
+	pub(super) fn new(source:&'a NestedSegment) -> NestedSegmentPostOrderIterator<'a> {
+		NestedSegmentPostOrderIterator {
+			stack: vec![(0, Vec::new(), source, false)]
+		}
+	}
+}
+impl<'a> Iterator for NestedSegmentPostOrderIterator<'a> {
+	type Item = (usize, Vec<u64>, &'a NestedSegment);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let (depth, path, segment, children_visited) = self.stack.pop()?;
+
+			// Children are done, the segment itself is next.
+			if children_visited || segment.sub_segments().is_empty() {
+				return Some((depth, path, segment));
+			}
+
+			// Revisit the segment once all children are returned.
+			let children:&[NestedSegment] = segment.sub_segments();
+			self.stack.push((depth, path.clone(), segment, true));
+			for child in children.iter().rev() {
+				self.stack.push((depth + 1, [path.as_slice(), &[child.id()]].concat(), child, false));
+			}
+		}
+	}
+}
+
+
+
+pub struct NestedSegmentBreadthFirstIterator<'a> {
+	queue:VecDeque<(usize, Vec<u64>, &'a NestedSegment)>
+}
+impl<'a> NestedSegmentBreadthFirstIterator<'a> {
+
+	/// Create a new iterator starting at the given segment.
+	pub(super) fn new(source:&'a NestedSegment) -> NestedSegmentBreadthFirstIterator<'a> {
+		NestedSegmentBreadthFirstIterator {
+			queue: VecDeque::from([(0, Vec::new(), source)])
+		}
+	}
+}
+impl<'a> Iterator for NestedSegmentBreadthFirstIterator<'a> {
+	type Item = (usize, Vec<u64>, &'a NestedSegment);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (depth, path, segment) = self.queue.pop_front()?;
+		for child in segment.sub_segments() {
+			self.queue.push_back((depth + 1, [path.as_slice(), &[child.id()]].concat(), child));
+		}
+		Some((depth, path, segment))
+	}
+}
+
+
+
+pub struct NestedSegmentCodeIterator<'a>(NestedSegmentPreOrderIterator<'a>);
+impl<'a> NestedSegmentCodeIterator<'a> {
+
+	/// Create a new iterator starting at the given segment.
+	pub(super) fn new(source:&'a NestedSegment) -> NestedSegmentCodeIterator<'a> {
+		NestedSegmentCodeIterator(NestedSegmentPreOrderIterator::new(source))
+	}
+}
+impl<'a> Iterator for NestedSegmentCodeIterator<'a> {
+	type Item = (usize, Vec<u64>, &'a NestedSegmentCode);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		for (depth, path, segment) in self.0.by_ref() {
+			if let NestedSegment::Code(_, code) = segment {
+				return Some((depth, path, code));
+			}
+		}
+		None
+	}
+}
+
+
+
+pub struct NestedSegmentAncestorIterator<'a> {
+	ancestors:Vec<&'a NestedSegment>,
+	path:Vec<u64>
+}
+impl<'a> NestedSegmentAncestorIterator<'a> {
+
+	/// Create a new iterator over the ancestors of the segment at the end of the path. Resolves the path only once.
+	pub(super) fn new(source:&'a NestedSegment, path:&[u64]) -> NestedSegmentAncestorIterator<'a> {
+		let mut ancestors:Vec<&'a NestedSegment> = Vec::with_capacity(path.len());
+		let mut segment:&'a NestedSegment = source;
+		for target_id in path {
+			match segment.sub_segments().iter().find(|child| child.id() == *target_id) {
+				Some(child) => {
+					ancestors.push(segment);
+					segment = child;
+				},
+				None => {
+					ancestors.clear();
+					break;
+				}
+			}
+		}
+		NestedSegmentAncestorIterator {
+			path: path[..ancestors.len()].to_vec(),
+			ancestors
+		}
+	}
+}
+impl<'a> Iterator for NestedSegmentAncestorIterator<'a> {
+	type Item = (usize, Vec<u64>, &'a NestedSegment);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let segment:&NestedSegment = self.ancestors.pop()?;
+		self.path.pop();
+		Some((self.ancestors.len(), self.path.clone(), segment))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ NestedCodeParser, NestedSegment, NestedSegmentRef };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	/// Build the tree `<a>x<b>y</b><c><d>z</d></c></a>`.
+	fn example_tree() -> NestedSegment {
+		NestedSegment::new_code("a", "<a>", vec![
+			NestedSegment::new_contents("x"),
+			NestedSegment::new_code("b", "<b>", vec![NestedSegment::new_contents("y")], "</b>"),
+			NestedSegment::new_code("c", "<c>", vec![
+				NestedSegment::new_code("d", "<d>", vec![NestedSegment::new_contents("z")], "</d>")
+			], "</c>")
+		], "</a>")
+	}
+
+	/// Get a short name for a segment, the type name for code and the contents otherwise.
+	fn short_name(segment:&NestedSegment) -> String {
+		if segment.is_code() { segment.type_name().to_string() } else { segment.to_string() }
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_iter() {
+		let tree:NestedSegment = example_tree();
+		assert_eq!(tree.iter().map(short_name).collect::<Vec<String>>(), vec!["a", "x", "b", "y", "c", "d", "z"]);
+	}
+
+	#[test]
+	fn test_iter_does_not_escape_sub_tree() {
+		let tree:NestedSegment = example_tree();
+		assert_eq!(tree[1].iter().map(short_name).collect::<Vec<String>>(), vec!["b", "y"]);
+		assert_eq!(tree[2].iter().map(short_name).collect::<Vec<String>>(), vec!["c", "d", "z"]);
+	}
+
+	#[test]
+	fn test_iter_matches_flat() {
+		let parser:NestedCodeParser = NestedCodeParser::new(vec![&("scope", true, "{", "}"), &("string", false, "\"", "\"")]);
+		let tree:NestedSegment = parser.parse("a { b { \"c\" } d } { e } \"f\"");
+		assert_eq!(
+			tree.iter().map(|segment| segment.id()).collect::<Vec<u64>>(),
+			tree.flat().iter().map(|(_, segment)| segment.id()).collect::<Vec<u64>>()
+		);
+		assert_eq!(
+			tree.iter_pre_order().map(|(depth, _, segment)| (depth, segment.id())).collect::<Vec<(usize, u64)>>(),
+			tree.flat().iter().map(|(depth, segment)| (*depth, segment.id())).collect::<Vec<(usize, u64)>>()
+		);
+	}
+
+	#[test]
+	fn test_pre_order() {
+		let tree:NestedSegment = example_tree();
+		assert_eq!(
+			tree.iter_pre_order().map(|(depth, _, segment)| (depth, short_name(segment))).collect::<Vec<(usize, String)>>(),
+			vec![(0, "a".to_string()), (1, "x".to_string()), (1, "b".to_string()), (2, "y".to_string()), (1, "c".to_string()), (2, "d".to_string()), (3, "z".to_string())]
+		);
+	}
+
+	#[test]
+	fn test_post_order() {
+		let tree:NestedSegment = example_tree();
+		assert_eq!(
+			tree.iter_post_order().map(|(depth, _, segment)| (depth, short_name(segment))).collect::<Vec<(usize, String)>>(),
+			vec![(1, "x".to_string()), (2, "y".to_string()), (1, "b".to_string()), (3, "z".to_string()), (2, "d".to_string()), (1, "c".to_string()), (0, "a".to_string())]
+		);
+	}
+
+	#[test]
+	fn test_breadth_first() {
+		let tree:NestedSegment = example_tree();
+		assert_eq!(
+			tree.iter_breadth_first().map(|(depth, _, segment)| (depth, short_name(segment))).collect::<Vec<(usize, String)>>(),
+			vec![(0, "a".to_string()), (1, "x".to_string()), (1, "b".to_string()), (1, "c".to_string()), (2, "y".to_string()), (2, "d".to_string()), (3, "z".to_string())]
+		);
+	}
+
+	#[test]
+	fn test_code_only() {
+		let tree:NestedSegment = example_tree();
+		assert_eq!(
+			tree.iter_code().map(|(depth, _, code)| (depth, code.type_name.clone())).collect::<Vec<(usize, String)>>(),
+			vec![(0, "a".to_string()), (1, "b".to_string()), (1, "c".to_string()), (2, "d".to_string())]
+		);
+	}
+
+	#[test]
+	fn test_single_segment() {
+		let tree:NestedSegment = NestedSegment::new_contents("only");
+		assert_eq!(tree.iter().count(), 1);
+		assert_eq!(tree.iter_pre_order().count(), 1);
+		assert_eq!(tree.iter_post_order().count(), 1);
+		assert_eq!(tree.iter_breadth_first().count(), 1);
+		assert_eq!(tree.iter_code().count(), 0);
+	}
+
+	#[test]
+	fn test_paths_resolve_to_segment() {
+		let tree:NestedSegment = example_tree();
+		for (depth, path, segment) in tree.iter_pre_order().chain(tree.iter_post_order()).chain(tree.iter_breadth_first()) {
+			assert_eq!(path.len(), depth);
+			assert_eq!(NestedSegmentRef::new(&tree, path).get().unwrap().id(), segment.id());
+		}
+		for (depth, path, code) in tree.iter_code() {
+			assert_eq!(path.len(), depth);
+			assert_eq!(NestedSegmentRef::new(&tree, path).get().unwrap().type_name(), code.type_name);
+		}
+	}
+
+	#[test]
+	fn test_ancestors() {
+		let tree:NestedSegment = example_tree();
+		let target:NestedSegmentRef = tree.find(|segment| segment.to_string() == "z").unwrap();
+		let ancestors:Vec<(usize, Vec<u64>, &NestedSegment)> = target.ancestors().collect();
+
+		assert_eq!(ancestors.iter().map(|(depth, _, segment)| (*depth, short_name(segment))).collect::<Vec<(usize, String)>>(), vec![(2, "d".to_string()), (1, "c".to_string()), (0, "a".to_string())]);
+		for (depth, path, segment) in ancestors {
+			assert_eq!(path.len(), depth);
+			assert_eq!(path[..], target.path()[..depth]);
+			assert_eq!(NestedSegmentRef::new(&tree, path).get().unwrap().id(), segment.id());
+		}
+	}
+
+	#[test]
+	fn test_ancestors_of_root_and_invalid_path() {
+		let tree:NestedSegment = example_tree();
+		assert_eq!(NestedSegmentRef::new(&tree, vec![]).ancestors().count(), 0);
+		assert_eq!(NestedSegmentRef::new(&tree, vec![tree[2].id(), tree[1].id()]).ancestors().count(), 0);
+	}
+
+	#[test]
+	fn test_deep_tree_iteration() {
+		let mut tree:NestedSegment = NestedSegment::new_contents("leaf");
+		for _ in 0..1000 {
+			tree = NestedSegment::new_code("wrap", "(", vec![tree], ")");
+		}
+		assert_eq!(tree.iter().count(), 1001);
+		assert_eq!(tree.iter_pre_order().last().unwrap().0, 1000);
+		assert_eq!(tree.iter_post_order().next().unwrap().0, 1000);
+		assert_eq!(tree.iter_breadth_first().count(), 1001);
+		assert_eq!(tree.iter_code().count(), 1000);
+	}
+}
\ No newline at end of file
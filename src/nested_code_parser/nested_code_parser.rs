@@ -8,7 +8,7 @@ pub const ROOT_NAME:&str = "ROOT";
 
 
 pub struct NestedCodeParser {
-	identification:Vec<SegmentIdentification>,
+	pub(super) identification:Vec<SegmentIdentification>,
 	ignore_white_space_segments:bool
 }
 impl NestedCodeParser {
@@ -24,6 +24,7 @@ impl NestedCodeParser {
 	}
 
 	/// Return a version of self that will not add segments that are only white-space.
+	/// Parsing is then no longer lossless: the dropped white-space segments are not part of the tree, so calling `to_string` on a result returns the input without them.
 	pub fn ignore_white_space_segments(mut self) -> Self {
 		self.ignore_white_space_segments = true;
 		self
@@ -33,7 +34,7 @@ impl NestedCodeParser {
 
 	/* USAGE METHODS */
 
-	/// Parse some code.
+	/// Parse some code. Calling `to_string` on the result returns the exact input for parsers that keep white-space segments, see `ignore_white_space_segments` for parsers that do not.
 	pub fn parse<'b>(&self, contents:&'b str) -> NestedSegment {
 		let mut parser:InnerNestedCodeParser<'_, 'b> = InnerNestedCodeParser::new(self, contents);
 		parser.parse(None)
@@ -84,30 +85,38 @@ impl<'a, 'b> InnerNestedCodeParser<'a, 'b> {
 				}
 			}
 
-			// Try to match opening tag.
+			// Try to match opening tag. Empty opening tags are ignored, as they would recurse infinitely.
 			let allow_recurse:bool = scope_terminator.as_ref().map(|(_, identification)| identification.allow_sub_parse).unwrap_or(true);
+			let mut matched_open:bool = false;
 			if allow_recurse {
 				for identification_set in &self.origin.identification {
-					if let Some(match_length) = self.cursor_matches_tag(&identification_set.matching_method_open) {
+					if let Some(match_length) = self.cursor_matches_tag(&identification_set.matching_method_open).filter(|match_length| *match_length > 0) {
 						if let Some(from_unmatched) = self.code_from_unmatched() {
 							children.push(from_unmatched);
 						}
 						let start:usize = self.cursor;
 						self.cursor += match_length;
 						self.unmatched_cursor = self.cursor;
-						children.push(self.parse(Some((start..self.cursor, &identification_set))));
-						self.cursor -= 1; // The cursor loop is not broken, so the cursor will be incremented in the end of the loop.
+						children.push(self.parse(Some((start..self.cursor, identification_set))));
+						matched_open = true;
 						break;
 					}
 				}
 			}
 
-			self.cursor += 1;
+			// Move the cursor to the next character.
+			if !matched_open {
+				self.cursor += self.contents[self.cursor..].chars().next().map(|char| char.len_utf8()).unwrap_or(1);
+			}
 		}
 
-		// If target end not found, consider end of string the end of the tag.
+		// If target end not found, consider end of string the end of the tag. The unmatched code becomes the last child and the closing tag stays empty.
 		if let Some((open_tag_location, target_identification)) = scope_terminator {
-			return NestedSegment::new_code(&target_identification.name, &self.contents[open_tag_location.clone()], children, &self.contents[self.unmatched_cursor..self.cursor]);
+			if let Some(from_unmatched) = self.code_from_unmatched() {
+				children.push(from_unmatched);
+			}
+			self.unmatched_cursor = self.cursor;
+			return NestedSegment::new_code(&target_identification.name, &self.contents[open_tag_location.clone()], children, &self.contents[self.cursor..self.cursor]);
 		}
 		
 		// No active expected end meant this is the root element.
@@ -130,22 +139,24 @@ impl<'a, 'b> InnerNestedCodeParser<'a, 'b> {
 	}
 
 	/// Checks wether or not the contents at the cursor match the given tag. Returns the length of the match in contents or None.
+	/// Matches that do not start at the cursor or do not end on a character boundary within the contents are discarded.
 	fn cursor_matches_tag(&self, matching_method:&MatchMethod) -> Option<usize> {
-		match matching_method {
+		let remaining_contents:&str = &self.contents[self.cursor..];
+		let match_length:Option<usize> = match matching_method {
 			MatchMethod::CharCompare(tag, escape) => self.cursor_matches_str_literal(tag, escape),
-			MatchMethod::Method(method)  => method(&self.contents[self.cursor..]),
-			MatchMethod::Regex(regex) => regex.find(&self.contents[self.cursor..]).map(|regex_match| regex_match.len())
-		}
+			MatchMethod::Method(method)  => method(remaining_contents),
+			MatchMethod::Regex(regex) => regex.find(remaining_contents).filter(|regex_match| regex_match.start() == 0).map(|regex_match| regex_match.len())
+		};
+		match_length.filter(|match_length| remaining_contents.is_char_boundary(*match_length))
 	}
 
 	/// Check if a specific tag matches a specific place in contents by simply checking if the strings are the same. Returns the length of the match.
 	fn cursor_matches_str_literal(&self, tag:&str, escape:&Option<String>) -> Option<usize> {
-		let tag_end:usize = self.cursor + tag.len();
-		if self.contents.len() >= tag_end && &self.contents[self.cursor..tag_end] == tag {
+		if self.contents[self.cursor..].starts_with(tag) {
 			if let Some(escape) = escape {
 				let mut escaped:bool = false;
 				let mut cursor:usize = self.cursor;
-				while cursor >= escape.len() && &self.contents[cursor - escape.len()..cursor] == escape {
+				while !escape.is_empty() && self.contents[..cursor].ends_with(escape.as_str()) {
 					escaped = !escaped;
 					cursor -= escape.len();
 				}
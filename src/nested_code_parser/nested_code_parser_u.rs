@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-	use crate::{ NestedCodeParser, NestedSegment, ROOT_NAME, nested_code_parser::{ CONTENTS_NAME, WHITESPACE_NAME } };
+	use crate::{ assert_snapshot, json_parser, NestedCodeParser, NestedSegment, ROOT_NAME, nested_code_parser::{ CONTENTS_NAME, WHITESPACE_NAME } };
 
 	/* HELPER FUNCTIONS */
 
@@ -27,6 +27,24 @@ mod tests {
 		])
 	}
 
+	/// Generate pseudo-random inputs built from the given fragments. Deterministic, so failures can be reproduced.
+	fn fuzz_inputs(fragments:&[&str], count:usize, max_fragments:usize) -> Vec<String> {
+		let mut state:u64 = 0x2545F4914F6CDD1D;
+		let mut next_random = move || { state ^= state << 13; state ^= state >> 7; state ^= state << 17; state as usize };
+		(0..count).map(|_| {
+			let fragment_count:usize = next_random() % (max_fragments + 1);
+			(0..fragment_count).map(|_| fragments[next_random() % fragments.len()]).collect::<Vec<&str>>().join("")
+		}).collect()
+	}
+
+	/// Assert that the parser reproduces every input exactly.
+	fn assert_round_trips(parser:&NestedCodeParser, inputs:&[String]) {
+		for input in inputs {
+			assert_eq!(&parser.parse(input).to_string(), input, "Parsed tree does not reproduce input {:?}", input);
+		}
+	}
+
+
 
 	/* TESTS */
 
@@ -84,6 +102,20 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_ignore_white_space_segments_is_lossy() {
+		let parser:NestedCodeParser = example_parser().ignore_white_space_segments();
+		assert_eq!(parser.parse("{ a }\n{\n}").to_string(), "{ a }{}");
+		assert_eq!(parser.parse("a { }\n\n{}").to_string(), "a {}{}");
+		assert_round_trips(&parser, &["{a}{b}".to_string(), "a b".to_string()]);
+	}
+
+	#[test]
+	fn test_nesting_structure_snapshot() {
+		assert_snapshot(&example_parser().parse(EXAMPLE_TEXT), "src/nested_code_parser/snapshots/example_grammar.snap");
+		assert_snapshot(&example_parser().ignore_white_space_segments().parse(EXAMPLE_TEXT), "src/nested_code_parser/snapshots/example_grammar_ignore_white_space.snap");
+	}
+
 	#[test]
 	fn test_double_escape() {
 		let parser:NestedCodeParser = example_parser();
@@ -123,4 +155,43 @@ mod tests {
 		let validation:NestedSegment = NestedSegment::from_flat(flat).unwrap();
 		assert_eq!(result, validation);
 	}
+
+	#[test]
+	fn test_round_trip_example() {
+		assert_round_trips(&example_parser(), &[EXAMPLE_TEXT.to_string()]);
+	}
+
+	#[test]
+	fn test_round_trip_unterminated() {
+		let parser:NestedCodeParser = example_parser();
+		assert_round_trips(&parser, &["{ unterminated".to_string(), "a \"unterminated string".to_string(), "{ { \"x".to_string(), "// comment without newline".to_string(), "{".to_string()]);
+
+		let result:NestedSegment = parser.parse("a { b");
+		assert_eq!(result[1].type_name(), "scope");
+		assert_eq!(result[1].sub_contents_to_string(), " b");
+		if let NestedSegment::Code(_, code) = &result[1] {
+			assert_eq!(code.close_tag, "");
+		}
+	}
+
+	#[test]
+	fn test_round_trip_multi_byte_characters() {
+		let parser:NestedCodeParser = example_parser();
+		assert_round_trips(&parser, &["é { ü \"ñ\\\"€\" } 😀".to_string(), "if é {\u{0301}}".to_string()]);
+		assert_eq!(parser.parse("é { ü }")[1].type_name(), "scope");
+	}
+
+	#[test]
+	fn test_fuzz_round_trip_test_grammars() {
+		let inputs:Vec<String> = fuzz_inputs(&["{", "}", "if ", " ", "\n", "\t", "//", "\"", "\\", "println!(", ");", "(", "P", "a", "é", "😀", "x1"], 2000, 24);
+		assert_round_trips(&example_parser(), &inputs);
+		assert_round_trips(&NestedCodeParser::new(vec![&("comment", false, "//", Some("P"), "\n", Some("\\"))]), &inputs);
+		assert_round_trips(&NestedCodeParser::new(vec![&("comment", "^//.+\n"), &("scope", true, "{", "}")]), &inputs);
+	}
+
+	#[test]
+	fn test_fuzz_round_trip_json_grammar() {
+		let inputs:Vec<String> = fuzz_inputs(&["{", "}", "[", "]", ":", ",", " ", "\n", "\"", "'", "`", "\\", "-", "0", "12", ".", "5", "true", "False", "null", "a", "é", "😀"], 2000, 32);
+		assert_round_trips(json_parser(), &inputs);
+	}
 }
\ No newline at end of file
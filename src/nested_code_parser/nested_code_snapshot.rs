@@ -0,0 +1,67 @@
+use super::NestedSegment;
+use file_ref::FileRef;
+use std::{ error::Error, path::Path };
+
+
+
+pub const UPDATE_SNAPSHOTS_VARIABLE:&str = "UPDATE_SNAPSHOTS";
+const SNAPSHOT_PADDING:&str = "\t";
+
+
+
+impl NestedSegment {
+
+	/* SNAPSHOT METHODS */
+
+	/// Render the tree as a stable textual snapshot. Every segment gets a line, indented by its depth. Code segments show their type name and tags, contents and white-space show their text.
+	pub fn to_snapshot(&self) -> String {
+		self.iter_pre_order().map(|(depth, _, segment)| {
+			let description:String = match segment {
+				NestedSegment::Code(_, code) => format!("{} {:?} {:?}", code.type_name, code.open_tag, code.close_tag),
+				_ => format!("{} {:?}", segment.type_name(), segment.to_string())
+			};
+			SNAPSHOT_PADDING.repeat(depth) + &description
+		}).collect::<Vec<String>>().join("\n") + "\n"
+	}
+}
+
+
+
+/// Compare the snapshot of a tree to the snapshot stored in a file. When the 'UPDATE_SNAPSHOTS' environment variable is set, the file is (re)written instead.
+pub fn check_snapshot(segment:&NestedSegment, snapshot_path:&str) -> Result<(), Box<dyn Error>> {
+	_check_snapshot(segment, snapshot_path, std::env::var_os(UPDATE_SNAPSHOTS_VARIABLE).is_some())
+}
+pub(super) fn _check_snapshot(segment:&NestedSegment, snapshot_path:&str, update:bool) -> Result<(), Box<dyn Error>> {
+	let snapshot:String = segment.to_snapshot();
+
+	// Update the stored snapshot.
+	if update {
+		if let Some(parent_dir) = Path::new(snapshot_path).parent().filter(|parent_dir| !parent_dir.as_os_str().is_empty()) {
+			std::fs::create_dir_all(parent_dir)?;
+		}
+		return FileRef::new(snapshot_path).write(&snapshot);
+	}
+
+	// Compare to the stored snapshot.
+	let stored_snapshot:String = FileRef::new(snapshot_path).read().map_err(|_| format!("Could not read snapshot '{snapshot_path}'. Run the tests with '{UPDATE_SNAPSHOTS_VARIABLE}=1' to create it."))?;
+	let stored_snapshot:String = stored_snapshot.replace("\r\n", "\n");
+	if stored_snapshot == snapshot {
+		return Ok(());
+	}
+	let stored_lines:Vec<&str> = stored_snapshot.lines().collect();
+	let new_lines:Vec<&str> = snapshot.lines().collect();
+	let first_difference:usize = stored_lines.iter().zip(&new_lines).position(|(stored, new)| stored != new).unwrap_or(stored_lines.len().min(new_lines.len()));
+	Err(format!(
+		"Snapshot '{snapshot_path}' does not match at line {}.\n\nstored:\n{}\n\nnew:\n{}\n\nRun the tests with '{UPDATE_SNAPSHOTS_VARIABLE}=1' to accept the new snapshot.",
+		first_difference + 1,
+		stored_lines.get(first_difference).unwrap_or(&"<end of snapshot>"),
+		new_lines.get(first_difference).unwrap_or(&"<end of snapshot>")
+	).into())
+}
+
+/// Assert that the snapshot of a tree matches the snapshot stored in a file. When the 'UPDATE_SNAPSHOTS' environment variable is set, the file is (re)written instead.
+pub fn assert_snapshot(segment:&NestedSegment, snapshot_path:&str) {
+	if let Err(error) = check_snapshot(segment, snapshot_path) {
+		panic!("{error}");
+	}
+}
\ No newline at end of file
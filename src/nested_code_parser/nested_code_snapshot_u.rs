@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ NestedCodeParser, NestedSegment, nested_code_parser::nested_code_snapshot::_check_snapshot };
+
+
+
+	/* HELPER FUNCTIONS */
+
+	fn example_tree() -> NestedSegment {
+		NestedCodeParser::new(vec![
+			&("scope", true, "{", "}"),
+			&("string", false, "\"", None, "\"", Some("\\"))
+		]).parse("a {\n\t\"b\\\"\"\n}")
+	}
+
+
+
+	/* TESTS */
+
+	#[test]
+	fn test_to_snapshot() {
+		assert_eq!(
+			example_tree().to_snapshot(),
+			"ROOT \"\" \"\"\n\tcontents \"a \"\n\tscope \"{\" \"}\"\n\t\twhitespace \"\\n\\t\"\n\t\tstring \"\\\"\" \"\\\"\"\n\t\t\tcontents \"b\\\\\\\"\"\n\t\twhitespace \"\\n\"\n"
+		);
+	}
+
+	#[test]
+	fn test_snapshot_update_and_compare() {
+		let snapshot_file:&str = "test_snapshot_update_and_compare/tree.snap";
+		let tree:NestedSegment = example_tree();
+
+		assert!(_check_snapshot(&tree, snapshot_file, false).is_err());
+		_check_snapshot(&tree, snapshot_file, true).unwrap();
+		assert_eq!(std::fs::read_to_string(snapshot_file).unwrap(), tree.to_snapshot());
+		_check_snapshot(&tree, snapshot_file, false).unwrap();
+
+		std::fs::remove_dir_all("test_snapshot_update_and_compare").unwrap();
+	}
+
+	#[test]
+	fn test_snapshot_mismatch() {
+		let snapshot_file:&str = "test_snapshot_mismatch.snap";
+		_check_snapshot(&example_tree(), snapshot_file, true).unwrap();
+
+		let changed_tree:NestedSegment = NestedCodeParser::new(vec![&("scope", true, "{", "}")]).parse("a {\n\t\"b\\\"\"\n}");
+		let error:String = _check_snapshot(&changed_tree, snapshot_file, false).unwrap_err().to_string();
+		assert!(error.contains("does not match at line 4"));
+		assert!(error.contains("stored:\n\t\twhitespace \"\\n\\t\""));
+		assert!(error.contains("new:\n\t\tcontents \"\\n\\t\\\"b\\\\\\\"\\\"\\n\""));
+
+		std::fs::remove_file(snapshot_file).unwrap();
+	}
+}
\ No newline at end of file